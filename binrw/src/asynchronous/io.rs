@@ -0,0 +1,101 @@
+//! Minimal future-returning helpers over the [`futures_io`] traits.
+//!
+//! These exist so that binrw only has to depend on the trait definitions in
+//! `futures-io` instead of pulling in all of `futures-util` for its extension
+//! traits.
+
+use core::{future::poll_fn, pin::Pin};
+use futures_io::{AsyncRead, AsyncSeek, AsyncWrite};
+use std::io::{self, ErrorKind, SeekFrom};
+
+/// Reads exactly enough bytes to fill `buf`.
+pub async fn read_exact<R>(reader: &mut R, mut buf: &mut [u8]) -> io::Result<()>
+where
+    R: AsyncRead + Unpin + ?Sized,
+{
+    while !buf.is_empty() {
+        match poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, buf)).await {
+            Ok(0) => {
+                return Err(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "failed to fill whole buffer",
+                ))
+            }
+            Ok(n) => buf = &mut core::mem::take(&mut buf)[n..],
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads up to `limit` bytes, appending them to `buf`, and returns the number
+/// of bytes which were read.
+pub async fn read_to_limit<R>(reader: &mut R, buf: &mut Vec<u8>, limit: usize) -> io::Result<usize>
+where
+    R: AsyncRead + Unpin + ?Sized,
+{
+    const CHUNK_SIZE: usize = 0x1000;
+
+    let start = buf.len();
+    while buf.len() - start < limit {
+        let filled = buf.len();
+        let chunk = CHUNK_SIZE.min(limit - (filled - start));
+        buf.resize(filled + chunk, 0);
+        match poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, &mut buf[filled..])).await {
+            Ok(n) => {
+                buf.truncate(filled + n);
+                if n == 0 {
+                    break;
+                }
+            }
+            Err(e) => {
+                buf.truncate(filled);
+                if e.kind() != ErrorKind::Interrupted {
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    Ok(buf.len() - start)
+}
+
+/// Seeks to an offset, in bytes, in the stream.
+pub async fn seek<S>(stream: &mut S, pos: SeekFrom) -> io::Result<u64>
+where
+    S: AsyncSeek + Unpin + ?Sized,
+{
+    poll_fn(|cx| Pin::new(&mut *stream).poll_seek(cx, pos)).await
+}
+
+/// Returns the current seek position from the start of the stream.
+pub async fn stream_position<S>(stream: &mut S) -> io::Result<u64>
+where
+    S: AsyncSeek + Unpin + ?Sized,
+{
+    seek(stream, SeekFrom::Current(0)).await
+}
+
+/// Writes an entire buffer into the writer.
+pub async fn write_all<W>(writer: &mut W, mut buf: &[u8]) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    while !buf.is_empty() {
+        match poll_fn(|cx| Pin::new(&mut *writer).poll_write(cx, buf)).await {
+            Ok(0) => {
+                return Err(io::Error::new(
+                    ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ))
+            }
+            Ok(n) => buf = &buf[n..],
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
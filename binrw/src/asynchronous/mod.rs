@@ -0,0 +1,46 @@
+//! Asynchronous counterparts to [`BinRead`](crate::BinRead) and
+//! [`BinWrite`](crate::BinWrite).
+//!
+//! This module is only available when the `async` feature is enabled. It
+//! operates on the [`AsyncRead`], [`AsyncSeek`], and [`AsyncWrite`] traits
+//! from the `futures-io` crate, which are also re-exported here.
+//!
+//! Types opt into generating async implementations with the top-level
+//! [`async`](crate::attribute::read#async) directive. All other directives work the
+//! same way as they do in the blocking implementations, with the exception of
+//! `parse_with` and `write_with`, since a blocking function cannot be called
+//! from an async reader or writer.
+//!
+//! # Examples
+//!
+//! ```
+//! use binrw::{binrw, AsyncBinReaderExt, AsyncBinWriterExt};
+//! # use futures::{executor::block_on, io::Cursor};
+//!
+//! #[binrw]
+//! #[brw(async, big, magic = b"PKT")]
+//! #[derive(Debug, PartialEq)]
+//! struct Packet {
+//!     len: u16,
+//!     #[br(count = len)]
+//!     data: Vec<u8>,
+//! }
+//!
+//! # block_on(async {
+//! let mut stream = Cursor::new(b"PKT\0\x03abc".to_vec());
+//! let packet: Packet = stream.read_be().await.unwrap();
+//! assert_eq!(packet, Packet { len: 3, data: b"abc".to_vec() });
+//!
+//! let mut out = Cursor::new(Vec::new());
+//! out.write_be(&packet).await.unwrap();
+//! assert_eq!(out.into_inner(), b"PKT\0\x03abc");
+//! # });
+//! ```
+
+pub(crate) mod io;
+mod read;
+mod write;
+
+pub use futures_io::{AsyncRead, AsyncSeek, AsyncWrite};
+pub use read::{AsyncBinRead, AsyncBinReaderExt};
+pub use write::{AsyncBinWrite, AsyncBinWriterExt};
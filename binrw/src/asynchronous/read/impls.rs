@@ -0,0 +1,776 @@
+use super::AsyncBinRead;
+use crate::{
+    asynchronous::{io, AsyncRead, AsyncSeek},
+    bulk,
+    encoding::Encoding,
+    file_ptr::IntoSeekFrom,
+    io::SeekFrom,
+    prefixed::{counts_bytes, prefix_len, LengthPrefix, PrefixedArgs},
+    strings::{split_fixed, RawString},
+    varint::{self, ZigZagInt, MAX_LEN},
+    BinResult, BoolArgs, EncodedString, Endian, Error, FilePtr, FixedString, FixedWideString,
+    NullString, NullWideString, PosValue, PrefixedString, PrefixedVec, ReadOptions, SLeb128,
+    ULeb128, VecArgs, ZigZag,
+};
+use core::any::Any;
+use core::cmp::Reverse;
+use core::convert::TryInto;
+use core::num::{NonZeroU16, NonZeroU8, Wrapping};
+
+macro_rules! binread_impl {
+    ($($type_name:ty),*$(,)?) => {
+        $(
+            impl AsyncBinRead for $type_name {
+                async fn read_options_async<R>(reader: &mut R, options: &ReadOptions<'_>, _: Self::Args) -> BinResult<Self>
+                where
+                    R: AsyncRead + AsyncSeek + Unpin,
+                {
+                    let mut val = [0; core::mem::size_of::<$type_name>()];
+                    let pos = io::stream_position(reader).await?;
+
+                    if let Err(e) = io::read_exact(reader, &mut val).await {
+                        io::seek(reader, SeekFrom::Start(pos)).await?;
+                        return Err(e.into());
+                    }
+
+                    Ok(match options.endian() {
+                        Endian::Big => {
+                            <$type_name>::from_be_bytes(val)
+                        }
+                        Endian::Little => {
+                            <$type_name>::from_le_bytes(val)
+                        }
+                        Endian::Native => {
+                            <$type_name>::from_ne_bytes(val)
+                        }
+                    })
+                }
+            }
+        )*
+    }
+}
+
+binread_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl AsyncBinRead for char {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let pos = io::stream_position(reader).await?;
+        let value = <u32>::read_options_async(reader, options, ()).await?;
+        core::char::from_u32(value).ok_or_else(|| Error::AssertFail {
+            pos,
+            message: format!("invalid char value 0x{:x}", value),
+        })
+    }
+}
+
+impl AsyncBinRead for bool {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let pos = io::stream_position(reader).await?;
+        let low_byte = args.low_byte(options.endian(), pos)?;
+        let mut buf = [0; BoolArgs::MAX_SIZE];
+        let bytes = &mut buf[..args.size];
+        io::read_exact(reader, bytes).await?;
+        args.decode(bytes, low_byte, pos)
+    }
+}
+
+macro_rules! binread_nonzero_impl {
+    ($($type_name:ident($inner:ty)),*$(,)?) => {
+        $(
+            impl AsyncBinRead for core::num::$type_name {
+                async fn read_options_async<R>(
+                    reader: &mut R,
+                    options: &ReadOptions<'_>,
+                    _: Self::Args,
+                ) -> BinResult<Self>
+                where
+                    R: AsyncRead + AsyncSeek + Unpin,
+                {
+                    let pos = io::stream_position(reader).await?;
+                    let value = <$inner>::read_options_async(reader, options, ()).await?;
+                    Self::new(value).ok_or_else(|| Error::AssertFail {
+                        pos,
+                        message: concat!("unexpected zero value for ", stringify!($type_name))
+                            .into(),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+// `NonZeroU8` and `NonZeroU16` are left out so that `Vec<NonZeroU8>` and
+// `Vec<NonZeroU16>` keep their null-terminated impls below
+binread_nonzero_impl!(
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+    NonZeroU128(u128),
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroI128(i128),
+);
+
+impl AsyncBinRead for core::net::Ipv4Addr {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        <[u8; 4]>::read_options_async(reader, options, ())
+            .await
+            .map(Self::from)
+    }
+}
+
+impl AsyncBinRead for core::net::Ipv6Addr {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        <[u8; 16]>::read_options_async(reader, options, ())
+            .await
+            .map(Self::from)
+    }
+}
+
+/// Reads the bytes of a LEB128 value, stopping after its last byte or after
+/// one byte more than the longest valid encoding, whichever comes first.
+async fn read_leb128<R>(reader: &mut R) -> BinResult<([u8; MAX_LEN + 1], usize)>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let mut buf = [0; MAX_LEN + 1];
+    let mut len = 0;
+    while len < buf.len() {
+        io::read_exact(reader, &mut buf[len..=len]).await?;
+        len += 1;
+        if buf[len - 1] & 0x80 == 0 {
+            break;
+        }
+    }
+
+    Ok((buf, len))
+}
+
+impl AsyncBinRead for ULeb128 {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let pos = io::stream_position(reader).await?;
+        let (buf, len) = read_leb128(reader).await?;
+        varint::read_unsigned(&mut &buf[..len], pos).map(Self)
+    }
+}
+
+impl AsyncBinRead for SLeb128 {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let pos = io::stream_position(reader).await?;
+        let (buf, len) = read_leb128(reader).await?;
+        varint::read_signed(&mut &buf[..len], pos).map(Self)
+    }
+}
+
+impl<T: ZigZagInt> AsyncBinRead for ZigZag<T> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let pos = io::stream_position(reader).await?;
+        let (buf, len) = read_leb128(reader).await?;
+        varint::read_unsigned(&mut &buf[..len], pos).and_then(|value| Self::decode(value, pos))
+    }
+}
+
+fn not_enough_bytes<T>(_: T) -> Error {
+    Error::Io(crate::io::Error::new(
+        crate::io::ErrorKind::UnexpectedEof,
+        "not enough bytes in reader",
+    ))
+}
+
+impl<B: AsyncBinRead> AsyncBinRead for Vec<B> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let mut list = Self::with_capacity(args.count);
+
+        if let Some(bytes) = <dyn Any>::downcast_mut::<Vec<u8>>(&mut list) {
+            let byte_count = io::read_to_limit(reader, bytes, args.count).await?;
+
+            if byte_count == args.count {
+                Ok(list)
+            } else {
+                Err(not_enough_bytes(()))
+            }
+        } else {
+            for _ in 0..args.count {
+                list.push(B::read_options_async(reader, options, args.inner.clone()).await?);
+            }
+            Ok(list)
+        }
+    }
+
+    async fn after_parse_async<R>(
+        &mut self,
+        reader: &mut R,
+        ro: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        for val in self.iter_mut() {
+            val.after_parse_async(reader, ro, args.inner.clone())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<B: AsyncBinRead, const N: usize> AsyncBinRead for [B; N] {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let list = <Vec<B>>::read_options_async(
+            reader,
+            options,
+            VecArgs {
+                count: N,
+                inner: args,
+            },
+        )
+        .await?;
+
+        Ok(list
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("read exactly N elements")))
+    }
+
+    async fn after_parse_async<R>(
+        &mut self,
+        reader: &mut R,
+        ro: &ReadOptions<'_>,
+        args: B::Args,
+    ) -> BinResult<()>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        for val in self.iter_mut() {
+            val.after_parse_async(reader, ro, args.clone()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads a length prefix of type `L` followed by the list of `T` it describes.
+///
+/// When the prefix counts bytes, a list of values which are not primitive
+/// numbers is read until its end is reached, and returns a
+/// [`SizeExceeded`](Error::SizeExceeded) error if the last value continues past
+/// it.
+async fn read_prefixed<L, T, R>(
+    reader: &mut R,
+    options: &ReadOptions<'_>,
+    args: PrefixedArgs<T::Args>,
+) -> BinResult<Vec<T>>
+where
+    L: LengthPrefix + AsyncBinRead,
+    T: AsyncBinRead,
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let pos = io::stream_position(reader).await?;
+    let count_bytes = counts_bytes::<T, _>(&args, pos)?;
+    let prefix = L::read_options_async(reader, options, ()).await?;
+    let start = io::stream_position(reader).await?;
+    let len = prefix_len(&prefix, start - pos, args.includes_prefix, pos)?;
+
+    if !count_bytes {
+        return <Vec<T>>::read_options_async(
+            reader,
+            options,
+            VecArgs {
+                count: len,
+                inner: args.inner,
+            },
+        )
+        .await;
+    }
+
+    let size_exceeded = Error::SizeExceeded {
+        pos: start,
+        size: len as u64,
+    };
+    match bulk::primitive_size::<T>() {
+        Some(size) => {
+            let list = <Vec<T>>::read_options_async(
+                reader,
+                options,
+                VecArgs {
+                    count: len / size,
+                    inner: args.inner,
+                },
+            )
+            .await?;
+
+            if len % size == 0 {
+                Ok(list)
+            } else {
+                Err(size_exceeded)
+            }
+        }
+        None => {
+            let end = start.saturating_add(len as u64);
+            let mut list = Vec::new();
+            let mut pos = start;
+            while pos < end {
+                list.push(T::read_options_async(reader, options, args.inner.clone()).await?);
+                pos = io::stream_position(reader).await?;
+            }
+
+            if pos == end {
+                Ok(list)
+            } else {
+                Err(size_exceeded)
+            }
+        }
+    }
+}
+
+impl<L, T> AsyncBinRead for PrefixedVec<L, T>
+where
+    L: LengthPrefix + AsyncBinRead,
+    T: AsyncBinRead,
+{
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        read_prefixed::<L, T, R>(reader, options, args)
+            .await
+            .map(Self::new)
+    }
+}
+
+macro_rules! binread_tuple_impl {
+    ($type1:ident $(, $types:ident)*) => {
+        #[allow(non_camel_case_types)]
+        impl<$type1: AsyncBinRead<Args=()>, $($types: AsyncBinRead<Args=()>),*> AsyncBinRead for ($type1, $($types),*) {
+            async fn read_options_async<R>(reader: &mut R, options: &ReadOptions<'_>, _: Self::Args) -> BinResult<Self>
+            where
+                R: AsyncRead + AsyncSeek + Unpin,
+            {
+                Ok((
+                    <$type1>::read_options_async(reader, options, ()).await?,
+                    $(
+                        <$types>::read_options_async(reader, options, ()).await?
+                    ),*
+                ))
+            }
+
+            async fn after_parse_async<R>(&mut self, reader: &mut R, options: &ReadOptions<'_>, _: Self::Args) -> BinResult<()>
+            where
+                R: AsyncRead + AsyncSeek + Unpin,
+            {
+                let ($type1, $(
+                    $types
+                ),*) = self;
+
+                $type1.after_parse_async(reader, options, ()).await?;
+                $(
+                    $types.after_parse_async(reader, options, ()).await?;
+                )*
+
+                Ok(())
+            }
+        }
+
+        binread_tuple_impl!($($types),*);
+    };
+
+    () => {};
+}
+
+binread_tuple_impl!(
+    b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15, b16, b17, b18, b19, b20, b21,
+    b22, b23, b24, b25, b26, b27, b28, b29, b30, b31, b32
+);
+
+impl AsyncBinRead for () {
+    async fn read_options_async<R>(_: &mut R, _: &ReadOptions<'_>, _: Self::Args) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        Ok(())
+    }
+}
+
+impl<T: AsyncBinRead> AsyncBinRead for Box<T> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        Ok(Box::new(
+            T::read_options_async(reader, options, args).await?,
+        ))
+    }
+}
+
+impl<T: AsyncBinRead> AsyncBinRead for Option<T> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        Ok(Some(T::read_options_async(reader, options, args).await?))
+    }
+
+    async fn after_parse_async<R>(
+        &mut self,
+        reader: &mut R,
+        ro: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        match self {
+            Some(val) => val.after_parse_async(reader, ro, args).await,
+            None => Ok(()),
+        }
+    }
+}
+
+macro_rules! binread_wrapper_impl {
+    ($($type_name:ident),*) => {
+        $(
+            impl<T: AsyncBinRead> AsyncBinRead for $type_name<T> {
+                async fn read_options_async<R>(
+                    reader: &mut R,
+                    options: &ReadOptions<'_>,
+                    args: Self::Args,
+                ) -> BinResult<Self>
+                where
+                    R: AsyncRead + AsyncSeek + Unpin,
+                {
+                    Ok($type_name(T::read_options_async(reader, options, args).await?))
+                }
+
+                async fn after_parse_async<R>(
+                    &mut self,
+                    reader: &mut R,
+                    options: &ReadOptions<'_>,
+                    args: Self::Args,
+                ) -> BinResult<()>
+                where
+                    R: AsyncRead + AsyncSeek + Unpin,
+                {
+                    self.0.after_parse_async(reader, options, args).await
+                }
+            }
+        )*
+    };
+}
+
+binread_wrapper_impl!(Wrapping, Reverse);
+
+impl<T: 'static> AsyncBinRead for core::marker::PhantomData<T> {
+    async fn read_options_async<R>(_: &mut R, _: &ReadOptions<'_>, _: Self::Args) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        Ok(core::marker::PhantomData)
+    }
+}
+
+impl<Ptr, BR> AsyncBinRead for FilePtr<Ptr, BR>
+where
+    Ptr: AsyncBinRead<Args = ()> + IntoSeekFrom,
+    BR: AsyncBinRead,
+{
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        Ok(FilePtr {
+            ptr: Ptr::read_options_async(reader, options, ()).await?,
+            value: None,
+        })
+    }
+
+    async fn after_parse_async<R>(
+        &mut self,
+        reader: &mut R,
+        ro: &ReadOptions<'_>,
+        args: BR::Args,
+    ) -> BinResult<()>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let relative_to = ro.offset();
+        let before = io::stream_position(reader).await?;
+        io::seek(reader, SeekFrom::Start(relative_to)).await?;
+        io::seek(reader, self.ptr.into_seek_from()).await?;
+
+        let mut inner = BR::read_options_async(reader, ro, args.clone()).await?;
+
+        inner.after_parse_async(reader, ro, args).await?;
+        io::seek(reader, SeekFrom::Start(before)).await?;
+
+        self.value = Some(inner);
+        Ok(())
+    }
+}
+
+impl<T: AsyncBinRead> AsyncBinRead for PosValue<T> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: T::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let pos = io::stream_position(reader).await?;
+
+        Ok(PosValue {
+            pos,
+            val: T::read_options_async(reader, options, args).await?,
+        })
+    }
+
+    async fn after_parse_async<R>(
+        &mut self,
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        self.val.after_parse_async(reader, options, args).await
+    }
+}
+
+impl AsyncBinRead for Vec<NonZeroU8> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let mut values = vec![];
+
+        loop {
+            match NonZeroU8::new(<u8>::read_options_async(reader, options, ()).await?) {
+                Some(val) => values.push(val),
+                None => return Ok(values),
+            }
+        }
+    }
+}
+
+impl AsyncBinRead for Vec<NonZeroU16> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let mut values = vec![];
+
+        loop {
+            match NonZeroU16::new(<u16>::read_options_async(reader, options, ()).await?) {
+                Some(val) => values.push(val),
+                None => return Ok(values),
+            }
+        }
+    }
+}
+
+impl AsyncBinRead for NullString {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        <Vec<NonZeroU8>>::read_options_async(reader, options, args)
+            .await
+            .map(Into::into)
+    }
+}
+
+impl AsyncBinRead for NullWideString {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        <Vec<NonZeroU16>>::read_options_async(reader, options, args)
+            .await
+            .map(Into::into)
+    }
+}
+
+/// Reads `N` characters and splits them into the string and its trailing
+/// characters.
+async fn read_fixed<C, R, const N: usize>(
+    reader: &mut R,
+    options: &ReadOptions<'_>,
+    terminator: Option<C>,
+    pad: C,
+) -> BinResult<(Vec<C>, Vec<C>)>
+where
+    C: AsyncBinRead<Args = ()> + PartialEq,
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let value = <Vec<C>>::read_options_async(
+        reader,
+        options,
+        VecArgs {
+            count: N,
+            inner: (),
+        },
+    )
+    .await?;
+    Ok(split_fixed(value, terminator, pad))
+}
+
+impl<const N: usize> AsyncBinRead for FixedString<N> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let (value, trailing) =
+            read_fixed::<_, _, N>(reader, options, args.terminator, args.pad).await?;
+        Ok(Self { value, trailing })
+    }
+}
+
+impl<const N: usize> AsyncBinRead for FixedWideString<N> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let (value, trailing) =
+            read_fixed::<_, _, N>(reader, options, args.terminator, args.pad).await?;
+        Ok(Self { value, trailing })
+    }
+}
+
+impl<L: LengthPrefix + AsyncBinRead> AsyncBinRead for PrefixedString<L> {
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        read_prefixed::<L, u8, R>(reader, options, args)
+            .await
+            .map(Into::into)
+    }
+}
+
+impl<S, E> AsyncBinRead for EncodedString<S, E>
+where
+    S: AsyncBinRead + RawString,
+    E: Encoding<Unit = S::Unit>,
+{
+    async fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        let pos = io::stream_position(reader).await?;
+        let raw = S::read_options_async(reader, options, args.inner).await?;
+        Self::decode(&raw, options.endian(), args.bom, args.errors, pos)
+    }
+}
@@ -0,0 +1,158 @@
+use super::{AsyncRead, AsyncSeek};
+use crate::{BinRead, BinResult, Endian, ReadOptions};
+use core::future::Future;
+
+mod impls;
+
+/// The `AsyncBinRead` trait reads data from asynchronous streams and converts
+/// it into objects.
+///
+/// This is the asynchronous counterpart to [`BinRead`], and shares its
+/// [`Args`](BinRead::Args) type. Methods have an `_async` suffix so that they
+/// do not conflict with the blocking methods of `BinRead` when both traits are
+/// in scope.
+///
+/// # Derivable
+///
+/// This trait is implemented by `#[derive(BinRead)]`, `#[binread]`, and
+/// `#[binrw]` when the type has the top-level
+/// [`async`](crate::attribute#async) directive.
+pub trait AsyncBinRead: BinRead {
+    /// Read `Self` from the reader using default arguments.
+    fn read_async<R>(reader: &mut R) -> impl Future<Output = BinResult<Self>>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+        Self::Args: Default,
+    {
+        Self::read_args_async(reader, Self::Args::default())
+    }
+
+    /// Read `Self` from the reader using the given arguments.
+    fn read_args_async<R>(reader: &mut R, args: Self::Args) -> impl Future<Output = BinResult<Self>>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        async move { Self::read_options_async(reader, &ReadOptions::default(), args).await }
+    }
+
+    /// Read `Self` from the reader using the given [`ReadOptions`] and
+    /// arguments.
+    fn read_options_async<R>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> impl Future<Output = BinResult<Self>>
+    where
+        R: AsyncRead + AsyncSeek + Unpin;
+
+    /// Runs any post-processing steps required to finalize construction of the
+    /// object.
+    fn after_parse_async<R>(
+        &mut self,
+        _: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> impl Future<Output = BinResult<()>>
+    where
+        R: AsyncRead + AsyncSeek + Unpin,
+    {
+        async { Ok(()) }
+    }
+}
+
+/// Extension methods for reading [`AsyncBinRead`] objects directly from an
+/// asynchronous reader.
+///
+/// # Examples
+///
+/// ```rust
+/// use binrw::{AsyncBinReaderExt, endian::LE};
+/// # use futures::{executor::block_on, io::Cursor};
+///
+/// # block_on(async {
+/// let mut reader = Cursor::new(b"\x07\0\0\0\xCC\0\0\x05");
+/// let x: u32 = reader.read_le().await.unwrap();
+/// let y: u16 = reader.read_type(LE).await.unwrap();
+/// let z = reader.read_be::<u16>().await.unwrap();
+///
+/// assert_eq!((x, y, z), (7u32, 0xCCu16, 5u16));
+/// # });
+/// ```
+pub trait AsyncBinReaderExt: AsyncRead + AsyncSeek + Unpin + Sized {
+    /// Read `T` from the reader with the given byte order.
+    fn read_type<T: AsyncBinRead>(&mut self, endian: Endian) -> impl Future<Output = BinResult<T>>
+    where
+        T::Args: Default,
+    {
+        self.read_type_args(endian, T::Args::default())
+    }
+
+    /// Read `T` from the reader assuming big-endian byte order.
+    fn read_be<T: AsyncBinRead>(&mut self) -> impl Future<Output = BinResult<T>>
+    where
+        T::Args: Default,
+    {
+        self.read_type(Endian::Big)
+    }
+
+    /// Read `T` from the reader assuming little-endian byte order.
+    fn read_le<T: AsyncBinRead>(&mut self) -> impl Future<Output = BinResult<T>>
+    where
+        T::Args: Default,
+    {
+        self.read_type(Endian::Little)
+    }
+
+    /// Read `T` from the reader assuming native-endian byte order.
+    fn read_ne<T: AsyncBinRead>(&mut self) -> impl Future<Output = BinResult<T>>
+    where
+        T::Args: Default,
+    {
+        self.read_type(Endian::Native)
+    }
+
+    /// Read `T` from the reader with the given byte order and arguments.
+    fn read_type_args<T: AsyncBinRead>(
+        &mut self,
+        endian: Endian,
+        args: T::Args,
+    ) -> impl Future<Output = BinResult<T>> {
+        async move {
+            let options = ReadOptions::default().with_endian(endian);
+
+            let mut res = T::read_options_async(self, &options, args.clone()).await?;
+            res.after_parse_async(self, &options, args).await?;
+
+            Ok(res)
+        }
+    }
+
+    /// Read `T` from the reader, assuming big-endian byte order, using the
+    /// given arguments.
+    fn read_be_args<T: AsyncBinRead>(
+        &mut self,
+        args: T::Args,
+    ) -> impl Future<Output = BinResult<T>> {
+        self.read_type_args(Endian::Big, args)
+    }
+
+    /// Read `T` from the reader, assuming little-endian byte order, using the
+    /// given arguments.
+    fn read_le_args<T: AsyncBinRead>(
+        &mut self,
+        args: T::Args,
+    ) -> impl Future<Output = BinResult<T>> {
+        self.read_type_args(Endian::Little, args)
+    }
+
+    /// Read `T` from the reader, assuming native-endian byte order, using the
+    /// given arguments.
+    fn read_ne_args<T: AsyncBinRead>(
+        &mut self,
+        args: T::Args,
+    ) -> impl Future<Output = BinResult<T>> {
+        self.read_type_args(Endian::Native, args)
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin + Sized> AsyncBinReaderExt for R {}
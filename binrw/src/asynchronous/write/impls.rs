@@ -0,0 +1,698 @@
+use super::AsyncBinWrite;
+use crate::{
+    asynchronous::{io, AsyncSeek, AsyncWrite},
+    encoding::Encoding,
+    file_ptr::{ptr_to, reserve_value, FilePtr, IntoSeekFrom},
+    io::SeekFrom,
+    prefixed::{length_prefix, LengthPrefix, PrefixedArgs},
+    punctuated::Punctuated,
+    strings::{fixed_parts, fixed_size_exceeded, RawString},
+    varint::{encode_signed, encode_unsigned, ZigZagInt},
+    BinResult, BinWrite, BoolArgs, EncodedString, Endian, Error, FixedString, FixedWideString,
+    NullString, NullWideString, PosValue, PrefixedString, PrefixedVec, SLeb128, ULeb128,
+    WriteOptions, ZigZag,
+};
+use core::any::Any;
+use core::cell::Cell;
+use core::cmp::Reverse;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::num::Wrapping;
+
+// ============================= nums =============================
+
+macro_rules! binwrite_num_impl {
+    ($($type_name:ty),*$(,)?) => {
+        $(
+            impl AsyncBinWrite for $type_name {
+                async fn write_options_async<W>(
+                    &self,
+                    writer: &mut W,
+                    options: &WriteOptions<'_>,
+                    _: Self::Args,
+                ) -> BinResult<()>
+                where
+                    W: AsyncWrite + AsyncSeek + Unpin,
+                {
+                    io::write_all(writer, &match options.endian() {
+                        Endian::Big => self.to_be_bytes(),
+                        Endian::Little => self.to_le_bytes(),
+                        Endian::Native => self.to_ne_bytes(),
+                    }).await.map_err(Into::into)
+                }
+            }
+        )*
+    };
+}
+
+binwrite_num_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl AsyncBinWrite for char {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        u32::from(*self)
+            .write_options_async(writer, options, ())
+            .await
+    }
+}
+
+macro_rules! binwrite_nonzero_impl {
+    ($($type_name:ident),*$(,)?) => {
+        $(
+            impl AsyncBinWrite for core::num::$type_name {
+                async fn write_options_async<W>(
+                    &self,
+                    writer: &mut W,
+                    options: &WriteOptions<'_>,
+                    _: Self::Args,
+                ) -> BinResult<()>
+                where
+                    W: AsyncWrite + AsyncSeek + Unpin,
+                {
+                    self.get().write_options_async(writer, options, ()).await
+                }
+            }
+        )*
+    };
+}
+
+binwrite_nonzero_impl!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+);
+
+impl AsyncBinWrite for bool {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        let low_byte = args.low_byte(options.endian(), io::stream_position(writer).await?)?;
+        let mut buf = [0; BoolArgs::MAX_SIZE];
+        buf[low_byte] = u8::from(*self);
+        io::write_all(writer, &buf[..args.size]).await?;
+        Ok(())
+    }
+}
+
+impl AsyncBinWrite for ULeb128 {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        let (buf, len) = encode_unsigned(self.0);
+        io::write_all(writer, &buf[..len]).await?;
+        Ok(())
+    }
+}
+
+impl AsyncBinWrite for SLeb128 {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        let (buf, len) = encode_signed(self.0);
+        io::write_all(writer, &buf[..len]).await?;
+        Ok(())
+    }
+}
+
+impl<T: ZigZagInt> AsyncBinWrite for ZigZag<T> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        let (buf, len) = encode_unsigned(self.0.to_zigzag());
+        io::write_all(writer, &buf[..len]).await?;
+        Ok(())
+    }
+}
+
+// =========================== end nums ===========================
+
+// =========================== array/vec ===========================
+
+impl<T: AsyncBinWrite + 'static, const N: usize> AsyncBinWrite for [T; N] {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        if let Some(this) = <dyn Any>::downcast_ref::<[u8; N]>(self) {
+            io::write_all(writer, &this[..]).await?;
+        } else {
+            for item in self {
+                T::write_options_async(item, writer, options, args.clone()).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: AsyncBinWrite> AsyncBinWrite for [T] {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        for item in self {
+            T::write_options_async(item, writer, options, args.clone()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: AsyncBinWrite + 'static> AsyncBinWrite for Vec<T> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        if let Some(this) = <dyn Any>::downcast_ref::<Vec<u8>>(self) {
+            io::write_all(writer, this).await?;
+        } else {
+            for item in self {
+                T::write_options_async(item, writer, options, args.clone()).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ========================= end array/vec =========================
+
+// ========================= std types =========================
+
+impl<T: AsyncBinWrite + ?Sized> AsyncBinWrite for &T {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        (**self).write_options_async(writer, options, args).await
+    }
+}
+
+impl<T: AsyncBinWrite + 'static> AsyncBinWrite for Box<T> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        (**self).write_options_async(writer, options, args).await
+    }
+}
+
+impl<T: AsyncBinWrite> AsyncBinWrite for Option<T> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        match self {
+            Some(inner) => inner.write_options_async(writer, options, args).await,
+            None => Ok(()),
+        }
+    }
+}
+
+macro_rules! binwrite_wrapper_impl {
+    ($($type_name:ident),*) => {
+        $(
+            impl<T: AsyncBinWrite> AsyncBinWrite for $type_name<T> {
+                async fn write_options_async<W>(
+                    &self,
+                    writer: &mut W,
+                    options: &WriteOptions<'_>,
+                    args: Self::Args,
+                ) -> BinResult<()>
+                where
+                    W: AsyncWrite + AsyncSeek + Unpin,
+                {
+                    self.0.write_options_async(writer, options, args).await
+                }
+            }
+        )*
+    };
+}
+
+binwrite_wrapper_impl!(Wrapping, Reverse);
+
+impl AsyncBinWrite for core::net::Ipv4Addr {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        io::write_all(writer, &self.octets()).await?;
+        Ok(())
+    }
+}
+
+impl AsyncBinWrite for core::net::Ipv6Addr {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        io::write_all(writer, &self.octets()).await?;
+        Ok(())
+    }
+}
+
+impl<T: AsyncBinWrite> AsyncBinWrite for PosValue<T> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        self.val.write_options_async(writer, options, args).await
+    }
+}
+
+impl<T: AsyncBinWrite> PosValue<T> {
+    /// Writes the inner value, returning an error if the writer is not at the
+    /// stored position.
+    ///
+    /// This is the async version of [`write_checked`](Self::write_checked).
+    pub async fn write_checked_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: T::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        self.check_pos(io::stream_position(writer).await?)?;
+        self.val.write_options_async(writer, options, args).await
+    }
+
+    /// Writes the inner value, storing the position it was written at in the
+    /// given [`Cell`].
+    ///
+    /// This is the async version of [`write_recorded`](Self::write_recorded).
+    pub async fn write_recorded_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        (pos, args): (&Cell<u64>, T::Args),
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        pos.set(io::stream_position(writer).await?);
+        self.val.write_options_async(writer, options, args).await
+    }
+}
+
+impl<Ptr, T> AsyncBinWrite for FilePtr<Ptr, T>
+where
+    Ptr: AsyncBinWrite<Args = ()> + IntoSeekFrom + TryFrom<u64>,
+    T: AsyncBinWrite,
+{
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        let value = match &self.value {
+            Some(value) => value,
+            None => return self.ptr.write_options_async(writer, options, ()).await,
+        };
+
+        let pos = io::stream_position(writer).await?;
+        let value_pos = match reserve_value(value, pos, options, args.clone())? {
+            Some(value_pos) => value_pos,
+            None => return self.ptr.write_options_async(writer, options, ()).await,
+        };
+
+        io::seek(writer, SeekFrom::Start(value_pos)).await?;
+        value.write_options_async(writer, options, args).await?;
+        io::seek(writer, SeekFrom::Start(pos)).await?;
+
+        let ptr: Ptr = ptr_to(value_pos, pos, options)?;
+        ptr.write_options_async(writer, options, ()).await
+    }
+}
+
+impl<T: AsyncBinWrite, P: AsyncBinWrite<Args = ()>> AsyncBinWrite for Punctuated<T, P> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        if self.separators.len() != self.len().max(1) - 1 && self.separators.len() != self.len() {
+            return Err(Error::AssertFail {
+                pos: io::stream_position(writer).await?,
+                message: format!(
+                    "cannot write {} values with {} separators",
+                    self.len(),
+                    self.separators.len()
+                ),
+            });
+        }
+
+        for (i, value) in self.iter().enumerate() {
+            value
+                .write_options_async(writer, options, args.clone())
+                .await?;
+            if let Some(separator) = self.separators.get(i) {
+                separator.write_options_async(writer, options, ()).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: AsyncBinWrite> AsyncBinWrite for PhantomData<T> {
+    async fn write_options_async<W>(
+        &self,
+        _: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        Ok(())
+    }
+}
+
+impl AsyncBinWrite for NullString {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        self.0.write_options_async(writer, options, args).await?;
+        0u8.write_options_async(writer, options, args).await
+    }
+}
+
+impl AsyncBinWrite for NullWideString {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        self.0.write_options_async(writer, options, args).await?;
+        0u16.write_options_async(writer, options, args).await
+    }
+}
+
+/// Writes a string and its trailing characters as exactly `N` characters.
+async fn write_fixed<C, W, const N: usize>(
+    value: &[C],
+    trailing: &[C],
+    writer: &mut W,
+    options: &WriteOptions<'_>,
+    terminator: Option<C>,
+    pad: C,
+    truncate: bool,
+) -> BinResult<()>
+where
+    C: AsyncBinWrite<Args = ()> + Copy + 'static,
+    W: AsyncWrite + AsyncSeek + Unpin,
+{
+    let (value, trailing) = match fixed_parts::<C, N>(value, trailing, terminator, pad, truncate) {
+        Some(parts) => parts,
+        None => {
+            return Err(fixed_size_exceeded::<C, N>(
+                io::stream_position(writer).await?,
+            ))
+        }
+    };
+
+    value.write_options_async(writer, options, ()).await?;
+    trailing.write_options_async(writer, options, ()).await
+}
+
+impl<const N: usize> AsyncBinWrite for FixedString<N> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        write_fixed::<_, _, N>(
+            &self.value,
+            &self.trailing,
+            writer,
+            options,
+            args.terminator,
+            args.pad,
+            args.truncate,
+        )
+        .await
+    }
+}
+
+impl<const N: usize> AsyncBinWrite for FixedWideString<N> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        write_fixed::<_, _, N>(
+            &self.value,
+            &self.trailing,
+            writer,
+            options,
+            args.terminator,
+            args.pad,
+            args.truncate,
+        )
+        .await
+    }
+}
+
+/// Writes a length prefix of type `L` followed by `value`, which is a list of
+/// `count` elements of type `T`.
+async fn write_prefixed<L, T, V, W>(
+    value: &V,
+    count: usize,
+    writer: &mut W,
+    options: &WriteOptions<'_>,
+    args: PrefixedArgs<T::Args>,
+) -> BinResult<()>
+where
+    L: LengthPrefix + AsyncBinWrite,
+    T: BinWrite + 'static,
+    V: AsyncBinWrite<Args = T::Args> + ?Sized,
+    W: AsyncWrite + AsyncSeek + Unpin,
+{
+    let pos = io::stream_position(writer).await?;
+    let prefix = length_prefix::<L, T, V>(value, count, pos, options, &args)?;
+    prefix.write_options_async(writer, options, ()).await?;
+    value.write_options_async(writer, options, args.inner).await
+}
+
+impl<L, T> AsyncBinWrite for PrefixedVec<L, T>
+where
+    L: LengthPrefix + AsyncBinWrite,
+    T: AsyncBinWrite + 'static,
+{
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        write_prefixed::<L, T, _, _>(&self.value, self.value.len(), writer, options, args).await
+    }
+}
+
+impl<L: LengthPrefix + AsyncBinWrite> AsyncBinWrite for PrefixedString<L> {
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        write_prefixed::<L, u8, _, _>(&self.value, self.value.len(), writer, options, args).await
+    }
+}
+
+impl<S, E> AsyncBinWrite for EncodedString<S, E>
+where
+    S: AsyncBinWrite + RawString,
+    E: Encoding<Unit = S::Unit>,
+{
+    async fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        let pos = io::stream_position(writer).await?;
+        self.encode(options.endian(), args.bom, args.errors, pos)?
+            .write_options_async(writer, options, args.inner)
+            .await
+    }
+}
+
+// ======================= end std types =======================
+
+// =========================== tuples ===========================
+
+impl AsyncBinWrite for () {
+    async fn write_options_async<W>(
+        &self,
+        _: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        Ok(())
+    }
+}
+
+macro_rules! binwrite_tuple_impl {
+    ($type1:ident $(, $types:ident)*) => {
+        #[allow(non_camel_case_types)]
+        impl<
+            $type1: AsyncBinWrite<Args=()>, $($types: AsyncBinWrite<Args=()>),*
+        > AsyncBinWrite for ($type1, $($types),*) {
+            async fn write_options_async<W>(
+                &self,
+                writer: &mut W,
+                options: &WriteOptions<'_>,
+                _: Self::Args,
+            ) -> BinResult<()>
+            where
+                W: AsyncWrite + AsyncSeek + Unpin,
+            {
+                let ($type1, $(
+                    $types
+                ),*) = self;
+
+                $type1.write_options_async(writer, options, ()).await?;
+                $(
+                    $types.write_options_async(writer, options, ()).await?;
+                )*
+
+                Ok(())
+            }
+        }
+
+        binwrite_tuple_impl!($($types),*);
+    };
+
+    () => {};
+}
+
+binwrite_tuple_impl!(
+    b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15, b16, b17, b18, b19, b20, b21,
+    b22, b23, b24, b25, b26, b27, b28, b29, b30, b31, b32
+);
+
+// ========================= end tuples =========================
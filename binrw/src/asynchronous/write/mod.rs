@@ -0,0 +1,155 @@
+use super::{AsyncSeek, AsyncWrite};
+use crate::{BinResult, BinWrite, Endian, WriteOptions};
+use core::future::Future;
+
+mod impls;
+
+/// A trait for writing a given type to an asynchronous writer.
+///
+/// This is the asynchronous counterpart to [`BinWrite`], and shares its
+/// [`Args`](BinWrite::Args) type. Methods have an `_async` suffix so that they
+/// do not conflict with the blocking methods of `BinWrite` when both traits
+/// are in scope.
+///
+/// # Derivable
+///
+/// This trait is implemented by `#[derive(BinWrite)]`, `#[binwrite]`, and
+/// `#[binrw]` when the type has the top-level
+/// [`async`](crate::attribute#async) directive.
+pub trait AsyncBinWrite: BinWrite {
+    /// Write a type to a writer while assuming no arguments are needed.
+    fn write_to_async<W>(&self, writer: &mut W) -> impl Future<Output = BinResult<()>>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+        Self::Args: Default,
+    {
+        self.write_with_args_async(writer, Self::Args::default())
+    }
+
+    /// Write the type to a writer while providing the default
+    /// [`WriteOptions`].
+    fn write_with_args_async<W>(
+        &self,
+        writer: &mut W,
+        args: Self::Args,
+    ) -> impl Future<Output = BinResult<()>>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin,
+    {
+        async move {
+            self.write_options_async(writer, &WriteOptions::default(), args)
+                .await
+        }
+    }
+
+    /// Write the type to a writer, given the options on how to write it and
+    /// the type-specific arguments.
+    fn write_options_async<W>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> impl Future<Output = BinResult<()>>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin;
+}
+
+/// Extension methods for writing [`AsyncBinWrite`] objects directly to an
+/// asynchronous writer.
+///
+/// # Examples
+///
+/// ```rust
+/// use binrw::{AsyncBinWriterExt, endian::LE};
+/// # use futures::{executor::block_on, io::Cursor};
+///
+/// # block_on(async {
+/// let mut writer = Cursor::new(Vec::new());
+/// writer.write_be(&1_u16).await.unwrap();
+/// writer.write_type(&2_u16, LE).await.unwrap();
+///
+/// assert_eq!(writer.into_inner(), [0, 1, 2, 0]);
+/// # });
+/// ```
+pub trait AsyncBinWriterExt: AsyncWrite + AsyncSeek + Unpin + Sized {
+    /// Write `T` to the writer with the given byte order.
+    fn write_type<T: AsyncBinWrite>(
+        &mut self,
+        value: &T,
+        endian: Endian,
+    ) -> impl Future<Output = BinResult<()>>
+    where
+        T::Args: Default,
+    {
+        self.write_type_args(value, endian, T::Args::default())
+    }
+
+    /// Write `T` to the writer assuming big-endian byte order.
+    fn write_be<T: AsyncBinWrite>(&mut self, value: &T) -> impl Future<Output = BinResult<()>>
+    where
+        T::Args: Default,
+    {
+        self.write_type(value, Endian::Big)
+    }
+
+    /// Write `T` to the writer assuming little-endian byte order.
+    fn write_le<T: AsyncBinWrite>(&mut self, value: &T) -> impl Future<Output = BinResult<()>>
+    where
+        T::Args: Default,
+    {
+        self.write_type(value, Endian::Little)
+    }
+
+    /// Write `T` to the writer assuming native-endian byte order.
+    fn write_ne<T: AsyncBinWrite>(&mut self, value: &T) -> impl Future<Output = BinResult<()>>
+    where
+        T::Args: Default,
+    {
+        self.write_type(value, Endian::Native)
+    }
+
+    /// Write `T` to the writer with the given byte order and arguments.
+    fn write_type_args<T: AsyncBinWrite>(
+        &mut self,
+        value: &T,
+        endian: Endian,
+        args: T::Args,
+    ) -> impl Future<Output = BinResult<()>> {
+        async move {
+            let options = WriteOptions::new(endian);
+            value.write_options_async(self, &options, args).await
+        }
+    }
+
+    /// Write `T` to the writer, assuming big-endian byte order, using the
+    /// given arguments.
+    fn write_be_args<T: AsyncBinWrite>(
+        &mut self,
+        value: &T,
+        args: T::Args,
+    ) -> impl Future<Output = BinResult<()>> {
+        self.write_type_args(value, Endian::Big, args)
+    }
+
+    /// Write `T` to the writer, assuming little-endian byte order, using the
+    /// given arguments.
+    fn write_le_args<T: AsyncBinWrite>(
+        &mut self,
+        value: &T,
+        args: T::Args,
+    ) -> impl Future<Output = BinResult<()>> {
+        self.write_type_args(value, Endian::Little, args)
+    }
+
+    /// Write `T` to the writer, assuming native-endian byte order, using the
+    /// given arguments.
+    fn write_ne_args<T: AsyncBinWrite>(
+        &mut self,
+        value: &T,
+        args: T::Args,
+    ) -> impl Future<Output = BinResult<()>> {
+        self.write_type_args(value, Endian::Native, args)
+    }
+}
+
+impl<W: AsyncWrite + AsyncSeek + Unpin + Sized> AsyncBinWriterExt for W {}
@@ -10,8 +10,14 @@
 //! | [`args`](#arguments) | struct field, data variant | Passes arguments to another `BinRead` object.
 //! | [`args_raw`](#arguments) | struct field, data variant | Like `args`, but specifies a tuple containing the arguments.
 //! | [`assert`](#assert) | struct, field, non-unit enum, data variant | Asserts that a condition is true. Can be used multiple times.
+//! | [`async`](#async) | struct, non-unit enum, unit-like enum | Also implements `AsyncBinRead`.
 //! | [`big`](#byte-order) | all except unit variant | Sets the byte order to big-endian.
+//! | [`bit_order`](#bit-fields) | struct, data variant | Sets the bit order used by `bits` fields.
+//! | [`bits`](#bit-fields) | field | Reads a field from N bits instead of whole bytes.
+//! | [`borrow`](#borrowing) | struct, non-unit enum, unit-like enum | Implements [`BorrowedBinRead`](crate::BorrowedBinRead) instead of `BinRead`, so fields can borrow from the input.
 //! | [`calc`](#calculations) | field | Computes the value of a field instead of reading data.
+//! | [`checksum`](#checksums) | field | Verifies a checksum of a range of fields once all fields have been read.
+//! | [`context`](#context) | struct, non-unit enum, unit-like enum | Binds the user-defined context from the [`ReadOptions`](crate::ReadOptions) to a variable.
 //! | [`count`](#count) | field | Sets the length of a vector.
 //! | [`default`](#default) | field | Uses the [`default`](core::default::Default) value for a field instead of reading data.
 //! | [`deref_now`](#postprocessing) | field | An alias for `postprocess_now`.
@@ -24,6 +30,7 @@
 //! | [`is_little`](#byte-order) | field | Conditionally set the byte order to little-endian.
 //! | [`little`](#byte-order) | all except unit variant | Sets the byte order to little-endian.
 //! | [`magic`](#magic) | all | Matches a magic number.
+//! | [`magic_endian`](#byte-order-from-magic) | struct, non-unit enum, unit-like enum, data variant | Matches a magic number in either byte order and uses that byte order for the rest of the object.
 //! | [`map`](#map) | all except unit variant | Maps a read value to a new value. When used on a struct or enum, the map function must return `Self`.
 //! | [`offset`](#offset) | field | Modifies the offset used by a [`FilePtr`](crate::FilePtr).
 //! | [`pad_after`](#padding-and-alignment) | field | Skips N bytes after reading a field.
@@ -37,9 +44,15 @@
 //! | [`return_all_errors`](#enum-errors) | non-unit enum | Returns a [`Vec`] containing the error which occurred on each variant of an enum on failure. This is the default.
 //! | [`return_unexpected_error`](#enum-errors) | non-unit enum | Returns a single generic error on failure.
 //! | [`seek_before`](#padding-and-alignment) | field | Moves the reader to a specific position before reading data.
+//! | [`since`](#versioning) | field | Reads a field only in versions newer than or equal to N.
+//! | [`size`](#size) | field | Restricts a field to exactly N bytes of the input.
+//! | [`stream`](#streaming) | struct, unit-like enum | Allows reading from a reader which cannot seek. Also implements [`StreamRead`](crate::StreamRead).
 //! | [`temp`](#temp) | field | Uses a field as a temporary variable. Only usable with the [`macro@binread`] attribute macro.
+//! | [`transform`](#transforms) | field | Decodes the bytes of a field with a [`Transform`](crate::io::transform::Transform) before parsing it.
 //! | [`try`](#try) | field | Tries to parse and stores the [`default`](core::default::Default) value for the type if parsing fails instead of returning an error.
 //! | [`try_map`](#map) | all except unit variant | Like `map`, but returns a [`BinResult`](crate::BinResult).
+//! | [`until`](#versioning) | field | Reads a field only in versions older than N.
+//! | [`version`](#versioning) | struct, data variant | Sets the format version used by `since` and `until`.
 //!
 //! # Byte order
 //!
@@ -127,6 +140,51 @@
 //! # assert_eq!(MyType::read(&mut Cursor::new(b"\x03\x01\x00")).unwrap(), MyType { val: 3, other_val: 1 });
 //! ```
 //!
+//! ## Byte order from magic
+//!
+//! Some formats store a magic number in the same byte order as the rest of
+//! the data, so the byte order is only known after reading it. The
+//! `magic_endian` directive matches a magic number in either byte order and
+//! uses the matching byte order for the rest of the struct or enum, including
+//! any objects read by it:
+//!
+//! ```text
+//! #[br(magic_endian = $magic:literal)] or #[br(magic_endian($magic:literal))]
+//! #[br(magic_endian($little:literal, $big:literal))]
+//! ```
+//!
+//! A single magic number must be a number literal with a type suffix. For
+//! formats which use different byte sequences instead, like the `II` and `MM`
+//! markers of TIFF, a pair of byte strings can be given for little- and
+//! big-endian data. If neither matches, an
+//! [`Error::BadMagic`](crate::Error::BadMagic) is returned.
+//!
+//! Since `magic_endian` sets the byte order of the object, it cannot be used
+//! together with any other byte order directive on the same struct or enum.
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor};
+//! #[derive(BinRead, Debug, PartialEq)]
+//! #[br(magic_endian = 0xa1b2c3d4u32)]
+//! struct PcapHeader {
+//!     version_major: u16,
+//!     version_minor: u16,
+//! }
+//!
+//! #[derive(BinRead)]
+//! #[br(magic_endian(b"II", b"MM"))]
+//! struct TiffHeader {
+//!     version: u16,
+//!     first_ifd: u32,
+//! }
+//!
+//! let header: PcapHeader = Cursor::new(b"\xd4\xc3\xb2\xa1\x02\0\x04\0").read_be().unwrap();
+//! assert_eq!((header.version_major, header.version_minor), (2, 4));
+//!
+//! let header = TiffHeader::read(&mut Cursor::new(b"MM\0\x2a\0\0\0\x08")).unwrap();
+//! assert_eq!((header.version, header.first_ifd), (42, 8));
+//! ```
+//!
 //! # Magic
 //!
 //! The `magic` directive matches [magic numbers](https://en.wikipedia.org/wiki/Magic_number_(programming))
@@ -137,9 +195,10 @@
 //! ```
 //!
 //! The magic number can be a byte literal, byte string, char, float, or
-//! integer. When a magic number is matched, parsing begins with the first byte
-//! after the magic number in the data. When a magic number is not matched, an
-//! error is returned.
+//! integer. A char magic must be at most `'\u{ff}'` and is stored as a single
+//! byte. When a magic number is matched, parsing begins with the first byte
+//! after the magic number in the data. When a magic number is not matched, an error is
+//! returned.
 //!
 //! ## Examples
 //!
@@ -426,6 +485,49 @@
 //! Which, due to the fact the associated type `Args` cannot have a lifetime tied to the associated
 //! function `read_options`, the type is inexpressible without [GATs](https://github.com/rust-lang/rfcs/pull/1598).
 //!
+//! # Context
+//!
+//! The `context` directive gives a struct or enum access to a user-defined
+//! value stored in the [`ReadOptions`](crate::ReadOptions), like a format
+//! version or a string table. Unlike [arguments](#arguments), the context is
+//! passed along automatically to everything being read, so types in between
+//! do not need to know about it.
+//!
+//! ```text
+//! #[br(context($binding:ident : $ty:ty))] or #[br(context = $binding:ident : $ty:ty)]
+//! ```
+//!
+//! The context is bound to a variable of type `&$ty` which can be used in
+//! any directive. If the options do not contain a context of type `$ty`, an
+//! [`Error::MissingContext`](crate::Error::MissingContext) is returned.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor, Endian, ReadOptions};
+//! struct Version(u8);
+//!
+//! #[derive(BinRead)]
+//! #[br(context(version: Version))]
+//! struct Record {
+//!     id: u8,
+//!     #[br(if(version.0 >= 2))]
+//!     flags: Option<u8>,
+//! }
+//!
+//! #[derive(BinRead)]
+//! struct File {
+//!     len: u8,
+//!     #[br(count = len)]
+//!     records: Vec<Record>,
+//! }
+//!
+//! let version = Version(2);
+//! let options = ReadOptions::new(Endian::Big).with_context(&version);
+//! let file = File::read_options(&mut Cursor::new(b"\x01\x05\x80"), &options, ()).unwrap();
+//! assert_eq!(file.records[0].flags, Some(0x80));
+//! ```
+//!
 //! # Default
 //!
 //! The `default` directive, and its alias `ignore`, sets the value of the field
@@ -844,6 +946,12 @@
 //! #[br(args { count: $count as usize })]
 //! ```
 //!
+//! If the expression is just the name of an earlier field with a
+//! variable-length integer type like [`ULeb128`](crate::ULeb128) or
+//! [`ZigZag`](crate::ZigZag), its value is used as the count instead, since
+//! these types cannot be cast with `as`. In any other expression, use the
+//! inner value, like `len.0`.
+//!
 //! As such, when manually implementing
 //! [`BinRead::read_options`](crate::BinRead::read_options) or a
 //! [custom parser function](#custom-parsers), the `count` value is accessible
@@ -997,6 +1105,187 @@
 //! # assert_eq!(Cursor::new(b"\0\0\0\x01\x03").read_be::<MyType>().unwrap().other_byte, 42);
 //! ```
 //!
+//! # Versioning
+//!
+//! The `version` directive sets the version of the format being read, and the
+//! `since` and `until` directives only read a field in some versions. This is
+//! useful for formats which gained or lost fields over time.
+//!
+//! ```text
+//! #[br(version = $version:expr)]
+//! #[br(since = $since:expr)] or #[br(since($since:expr))]
+//! #[br(until = $until:expr)] or #[br(until($until:expr))]
+//! ```
+//!
+//! A field using `since` is only read when the version is greater than or
+//! equal to `$since`, and a field using `until` is only read when the version
+//! is less than `$until`. When a field is not read, its value is set to its
+//! [`default`](core::default::Default), so fields which are only present in
+//! some versions are usually [`Option`]s.
+//!
+//! The version expression is evaluated each time a versioned field is read,
+//! so it can refer to arguments, the [context](#context), or previously read
+//! fields.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor};
+//! #[derive(BinRead)]
+//! #[br(big, version = version)]
+//! struct Header {
+//!     version: u8,
+//!     #[br(since = 2)]
+//!     flags: Option<u16>,
+//!     #[br(until = 3)]
+//!     reserved: u8,
+//! }
+//!
+//! let v1: Header = Cursor::new(b"\x01\xff").read_ne().unwrap();
+//! assert_eq!((v1.flags, v1.reserved), (None, 0xff));
+//!
+//! let v3: Header = Cursor::new(b"\x03\x00\x01").read_ne().unwrap();
+//! assert_eq!((v3.flags, v3.reserved), (Some(1), 0));
+//! ```
+//!
+//! # Checksums
+//!
+//! The `checksum` directive verifies that a field holds the checksum of the
+//! bytes read for a range of fields, using any type which implements
+//! [`Checksum`](crate::checksum::Checksum):
+//!
+//! ```text
+//! #[br(checksum($algorithm:ty, from = $first:ident))]
+//! #[br(checksum($algorithm:ty, from = $first:ident, to = $last:ident))]
+//! #[brw(checksum($algorithm:ty, from = $first:ident, to = $last:ident))]
+//! ```
+//!
+//! The range starts at the position before the `from` field, including any
+//! magic or padding, and ends at the position after the `to` field. If `to`
+//! is omitted, only the `from` field is covered. The range may be anywhere in
+//! the same struct or variant, but cannot include the checksum field itself.
+//! The type of the checksum field must be the
+//! [`Output`](crate::checksum::Checksum::Output) of the algorithm.
+//!
+//! Checksums are verified after all fields have been read by reading the range
+//! again, so the reader must support seeking backwards. If the checksum does
+//! not match, [`Error::BadChecksum`](crate::Error::BadChecksum) is returned.
+//! Checksum fields which are not read because of a [condition](#conditional-values)
+//! or [versioning](#versioning) are not verified.
+//!
+//! `checksum` cannot be used with `calc`, `default`, `bits`, `stream`, or
+//! `async`.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, checksum::Crc32, io::Cursor};
+//! #[derive(BinRead)]
+//! #[br(big)]
+//! struct Packet {
+//!     len: u8,
+//!     #[br(count = len)]
+//!     data: Vec<u8>,
+//!     #[br(checksum(Crc32, from = len, to = data))]
+//!     crc: u32,
+//! }
+//!
+//! # let mut data = Cursor::new(b"\x02hi\x65\x8f\x35\x2f");
+//! # assert_eq!(data.read_be::<Packet>().unwrap().crc, 0x658f_352f);
+//! # let mut data = Cursor::new(b"\x02ho\x65\x8f\x35\x2f");
+//! # assert!(matches!(data.read_be::<Packet>(), Err(binrw::Error::BadChecksum { .. })));
+//! ```
+//!
+//! # Transforms
+//!
+//! The `transform` directive decodes a region of the input with any type
+//! which implements [`Transform`](crate::io::transform::Transform), then
+//! parses the field from the decoded data:
+//!
+//! ```text
+//! #[br(transform = $transform:expr)] or #[br(transform($transform:expr))]
+//! #[br(transform($transform:expr, size = $size:expr))]
+//! ```
+//!
+//! The region is `size` bytes long, or extends to the end of the input if
+//! `size` is omitted. The whole region is read and decoded before the field is
+//! parsed, and the field is parsed from the start of the decoded data, so any
+//! positions used while parsing it (like the offsets of a
+//! [`FilePtr`](crate::FilePtr)) are relative to the decoded data. Because of
+//! this, [`after_parse`](crate::BinRead::after_parse) is always called
+//! immediately, as if [`postprocess_now`](#postprocessing) were used.
+//!
+//! Built-in transforms are in the [`io::transform`](crate::io::transform)
+//! module.
+//!
+//! `transform` cannot be used with `calc`, `default`, `bits`, `borrow`, or
+//! `async`.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::{transform::Xor, Cursor}};
+//! #[derive(BinRead)]
+//! #[br(little)]
+//! struct Save {
+//!     len: u8,
+//!     #[br(transform(Xor(b"key"), size = len))]
+//!     level: u16,
+//! }
+//!
+//! # let save: Save = Cursor::new(b"\x02\x69\x64").read_le().unwrap();
+//! # assert_eq!(save.level, 0x0102);
+//! ```
+//!
+//! # Size
+//!
+//! The `size` directive restricts a field to exactly `size` bytes of the
+//! input, starting at the current position:
+//!
+//! ```text
+//! #[br(size = $size:expr)] or #[br(size($size:expr))]
+//! ```
+//!
+//! The field is read through a [`BoundedReader`](crate::io::BoundedReader),
+//! so any attempt to read past the end of the region stops at its end. If
+//! this causes the field to fail, a
+//! [`SizeExceeded`](crate::Error::SizeExceeded) error is returned with the
+//! position of the start of the field. If the field does not use all of its
+//! region, the rest is skipped.
+//!
+//! Positions are not changed, so seeking inside the field works as usual, but
+//! data outside of the region cannot be read while parsing the field. This
+//! includes the values of any [`FilePtr`](crate::FilePtr) which are
+//! dereferenced immediately.
+//!
+//! `size` cannot be used with `calc`, `default`, `bits`, `borrow`, or
+//! `async`. To limit the encoded size of a [transformed](#transforms) field,
+//! use the `size` argument of `transform` instead.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor};
+//! #[derive(BinRead, Debug)]
+//! struct Entry {
+//!     id: u16,
+//!     flags: u8,
+//! }
+//!
+//! #[derive(BinRead, Debug)]
+//! struct Record {
+//!     len: u8,
+//!     #[br(size = len)]
+//!     entry: Entry,
+//!     kind: u8,
+//! }
+//!
+//! # let record: Record = Cursor::new(b"\x04\x01\0\x02\xff\x01").read_le().unwrap();
+//! # assert_eq!((record.entry.id, record.entry.flags, record.kind), (1, 2, 1));
+//! # let error = Cursor::new(b"\x02\x01\0\x02\x01").read_le::<Record>().unwrap_err();
+//! # assert!(matches!(error.root_cause(), binrw::Error::SizeExceeded { pos: 1, size: 2 }));
+//! ```
+//!
 //! # Padding and alignment
 //!
 //! BinRead offers different directives for common forms of
@@ -1097,6 +1386,69 @@
 //! If seeking fails, an [`Io`](crate::Error::Io) error is returned and the
 //! reader’s position is reset to where it was before parsing started.
 //!
+//! # Bit fields
+//!
+//! The `bits` directive reads a field from the given number of bits instead of
+//! whole bytes:
+//!
+//! ```text
+//! #[br(bits = $count:expr)] or #[br(bits($count:expr))]
+//! ```
+//!
+//! Consecutive `bits` fields share bytes, and any bits left over at the end of
+//! a run of `bits` fields are skipped, so the next field always starts on a
+//! byte boundary. The field type must implement
+//! [`BitValue`](crate::io::BitValue), which is implemented for integers up to
+//! 64 bits wide and `bool`. Signed integers are sign-extended. Use
+//! [`map`](#map) to convert the value into any other type.
+//!
+//! By default, bits are read from the most significant bit of each byte
+//! first. The `bit_order` directive accepts a
+//! [`BitOrder`](crate::io::BitOrder) to change this for a struct or variant:
+//!
+//! ```text
+//! #[br(bit_order = $order:expr)] or #[br(bit_order($order:expr))]
+//! ```
+//!
+//! Directives which move the reader by whole bytes, like `pad_before` or
+//! `magic`, cannot be used on `bits` fields. To read bits outside of a derived
+//! type, use [`BitReader`](crate::io::BitReader).
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::{BitOrder, Cursor}};
+//! #[derive(BinRead)]
+//! struct Header {
+//!     #[br(bits = 4)]
+//!     version: u8,
+//!     #[br(bits = 3)]
+//!     flags: u8,
+//!     #[br(bits = 1, map = |x: u8| x == 0)]
+//!     is_last: bool,
+//!     len: u16,
+//! }
+//!
+//! #[derive(BinRead)]
+//! #[br(bit_order = BitOrder::LsbFirst)]
+//! struct Block {
+//!     #[br(bits = 1)]
+//!     is_final: bool,
+//!     #[br(bits = 2)]
+//!     kind: u8,
+//! }
+//!
+//! # let header = Cursor::new(b"\x4a\x00\x10").read_be::<Header>().unwrap();
+//! # assert_eq!((header.version, header.flags, header.is_last, header.len), (4, 0b101, true, 16));
+//! # let block = Cursor::new(b"\x05").read_be::<Block>().unwrap();
+//! # assert_eq!((block.is_final, block.kind), (true, 0b10));
+//! ```
+//!
+//! ## Errors
+//!
+//! If `$count` is wider than the field type, an [`Io`](crate::Error::Io)
+//! error is returned.
+//!
 //! # Repr
 //!
 //! The `repr` directive is used on a unit-like (C-style) enum to specify the
@@ -1129,6 +1481,141 @@
 //!
 //! In all cases, the reader’s position is reset to where it was before parsing
 //! started.
+//!
+//! # Async
+//!
+//! The `async` directive additionally implements `AsyncBinRead` for a type,
+//! so that it can be read from a non-blocking `AsyncRead + AsyncSeek`
+//! reader. It requires the `async` feature:
+//!
+//! ```text
+//! #[br(async)]
+//! #[brw(async)]
+//! ```
+//!
+//! All other directives work the same as they do when reading from a blocking
+//! reader, except for `parse_with`, which cannot be used because it calls a
+//! blocking function. Every field type must also implement `AsyncBinRead`.
+//!
+//! ## Examples
+//!
+//! ```ignore
+//! # use binrw::{prelude::*, AsyncBinReaderExt};
+//! #[derive(BinRead)]
+//! #[br(async, big, magic = b"PKT")]
+//! struct Packet {
+//!     len: u16,
+//!     #[br(count = len)]
+//!     data: Vec<u8>,
+//! }
+//!
+//! async fn read_packet<R>(reader: &mut R) -> BinResult<Packet>
+//! where
+//!     R: binrw::asynchronous::AsyncRead + binrw::asynchronous::AsyncSeek + Unpin,
+//! {
+//!     reader.read_ne().await
+//! }
+//! ```
+//!
+//! ## Errors
+//!
+//! Using the directive without the `async` feature is a compile-time error.
+//!
+//! # Borrowing
+//!
+//! The `borrow` directive implements
+//! [`BorrowedBinRead`](crate::BorrowedBinRead) instead of `BinRead`, so that a
+//! type can be read directly from a byte slice with fields which point into
+//! the slice instead of copying from it:
+//!
+//! ```text
+//! #[br(borrow)]
+//! ```
+//!
+//! The first lifetime parameter of the type is used as the lifetime of the
+//! input. Fields may be `&'a [u8]`, `&'a str`, slices and arrays of other
+//! [`Unaligned`](crate::Unaligned) types like
+//! [`U32Le`](crate::endian::U32Le), or any `BinRead` or `BorrowedBinRead`
+//! type. Slices and strings are sized with the [`count`](#count) directive.
+//!
+//! All other directives work the same as they do for `BinRead`.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, BorrowedBinRead, endian::U16Be};
+//! #[derive(BinRead)]
+//! #[br(borrow, big, magic = b"TBL")]
+//! struct Table<'a> {
+//!     name_len: u8,
+//!     #[br(count = name_len, align_after = 2)]
+//!     name: &'a str,
+//!     #[br(count = 2)]
+//!     ids: &'a [U16Be],
+//!     flags: u8,
+//! }
+//!
+//! let bytes = b"TBL\x03abc\0\0\x01\0\x02\x80";
+//! let table = Table::read_borrowed(bytes).unwrap();
+//! assert_eq!(table.name, "abc");
+//! assert_eq!(table.ids[1].get(), 2);
+//! assert_eq!(table.flags, 0x80);
+//! ```
+//!
+//! ## Errors
+//!
+//! Using `borrow` on a type without a lifetime parameter, or together with
+//! `async` or `stream`, is a compile-time error. A `&str` field which is not
+//! valid UTF-8 returns a [`Custom`](crate::Error::Custom) error containing a
+//! [`Utf8Error`](core::str::Utf8Error).
+//!
+//! # Streaming
+//!
+//! The `stream` directive implements [`StreamRead`](crate::StreamRead) for a
+//! type, promising that reading it never seeks backwards. This allows it to be
+//! read from a reader which only implements [`Read`](crate::io::Read), like
+//! a socket or a pipe, using [`StreamReaderExt`](crate::StreamReaderExt):
+//!
+//! ```text
+//! #[br(stream)]
+//! ```
+//!
+//! Skipping forwards, as `pad_before`, `pad_after`, and `pad_size_to` do, is
+//! still allowed. Every field type must implement `StreamRead`. For `map` and
+//! `try_map`, this applies to the type which is read before it is mapped.
+//! Functions used with `parse_with` are not checked, so they must not seek
+//! backwards themselves. Wrapping any
+//! reader in [`io::NoSeek`](crate::io::NoSeek) gives it the same forward-only
+//! `Seek` implementation used by `StreamReaderExt`.
+//!
+//! Since a stream cannot be rewound, a failed read leaves the stream at the
+//! position where the error occurred.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor, Endian, StreamReaderExt};
+//! #[derive(BinRead)]
+//! #[br(stream, big, magic = b"PKT")]
+//! struct Packet {
+//!     len: u8,
+//!     #[br(count = len, pad_after = 1)]
+//!     data: Vec<u8>,
+//! }
+//!
+//! let mut stream = Cursor::new(b"PKT\x02hi\0");
+//! let packet: Packet = stream.read_stream(Endian::Big).unwrap();
+//! assert_eq!(packet.data, b"hi");
+//! assert_eq!(stream.position(), 7);
+//! ```
+//!
+//! ## Errors
+//!
+//! Using `stream` with a directive which needs to seek, like `seek_before`,
+//! `restore_position`, `align_before`, `align_after`, or `try`, is a
+//! compile-time error, as is using it on an enum with data, since each variant
+//! is tried from the same starting position. Fields with types which need to
+//! seek, like [`FilePtr`](crate::FilePtr), are also a compile-time error.
 
 #![allow(unused_imports)]
 
@@ -11,8 +11,18 @@
 //! | [`args`](#arguments) | struct field, data variant | Passes arguments to another `BinWrite` type.
 //! | [`args_raw`](#arguments) | struct field, data variant | Like `args`, but specifies a type containing the arguments.
 //! | [`assert`](#assert) | struct, field, non-unit enum, data variant | Asserts that a condition is true before writing.
+//! | [`async`](#async) | struct, non-unit enum, unit-like enum | Also implements `AsyncBinWrite`.
+//! | [`backpatch`](#backpatching) | field | Overwrites a field with a value computed after the rest of the struct is written.
 //! | [`big`](#byte-order) | all except unit variant | Sets the byte order to big-endian.
+//! | [`bit_order`](#bit-fields) | struct, data variant | Sets the bit order used by `bits` fields.
+//! | [`bits`](#bit-fields) | field | Writes a field in N bits instead of whole bytes.
+//! | [`byte_range`](#backpatching) | field | Binds the range of positions a field was written to to a variable.
 //! | [`calc`](#calculations) | field | Computes the value of a field instead of pulling the value from a struct. Removes the field from the actual type definition.
+//! | [`checksum`](#checksums) | field | Overwrites a field with a checksum of a range of fields after the rest of the struct is written.
+//! | [`context`](#context) | struct, non-unit enum, unit-like enum | Binds the user-defined context from the [`WriteOptions`](crate::WriteOptions) to a variable.
+//! | [`data_section`](#data-sections) | struct, non-unit enum | Writes values pointed to by [`FilePtr`](crate::FilePtr)s after the struct or enum.
+//! | [`err_context`](#backtrace) | field | Add additional context to errors.
+//! | [`if`](#conditional-values) | field | Writes data only if a condition is true.
 //! | [`ignore`](#ignore) | field | Skip writing the field.
 //! | [`import`](#arguments) | struct, non-unit enum, unit-like enum | Defines extra arguments for a struct or enum.
 //! | [`import_tuple`](#arguments) | struct, non-unit enum, unit-like enum | Like `import`, but receives the arguments as a tuple.
@@ -20,7 +30,9 @@
 //! | [`is_little`](#byte-order) | field | Conditionally set the byte order to little-endian.
 //! | [`little`](#byte-order) | all except unit variant | Sets the byte order to little-endian.
 //! | [`magic`](#magic) | all | Writes a magic constant.
+//! | [`magic_endian`](#byte-order-from-magic) | struct, non-unit enum, unit-like enum, data variant | Writes a magic number in the current byte order.
 //! | [`map`](#map) | all except unit variant | Maps a value before writing. When used in the top-level position, the map function must take `Self`.
+//! | [`offset`](#offset) | field | Modifies the offset used by a [`FilePtr`](crate::FilePtr).
 //! | [`pad_after`](#padding-and-alignment) | field | Writes N bytes of padding after writing the field.
 //! | [`pad_before`](#padding-and-alignment) | field | Writes N bytes of padding before writing the field.
 //! | [`pad_size_to`](#padding-and-alignment) | field | Ensures the writer is at least N bytes after the starting position for this field.
@@ -28,7 +40,12 @@
 //! | [`repr`](#repr) | unit-like enum | Specifies the underlying type for a unit-like (C-style) enum.
 //! | [`restore_position`](#restore-position) | field | Restores the writer’s position after writing a field.
 //! | [`seek_before`](#padding-and-alignment) | field | Moves the writer to a specific position before writing data.
+//! | [`since`](#versioning) | field | Writes a field only in versions newer than or equal to N.
+//! | [`size`](#size) | field | Pads a field to exactly N bytes, or returns an error if it is larger.
+//! | [`transform`](#transforms) | field | Encodes the bytes written for a field with a [`Transform`](crate::io::transform::Transform).
 //! | [`try_map`](#map) | all except unit variant | Like `map`, but returns a [`BinResult`](crate::BinResult).
+//! | [`until`](#versioning) | field | Writes a field only in versions older than N.
+//! | [`version`](#versioning) | struct, data variant | Sets the format version used by `since` and `until`.
 //!
 //!
 //! # Padding and alignment
@@ -76,6 +93,47 @@
 //!  }
 //!  ```
 //!
+//! # Bit fields
+//!
+//! The `bits` directive writes a field in the given number of bits instead of
+//! whole bytes:
+//!
+//! ```text
+//! #[bw(bits = $count:expr)] or #[bw(bits($count:expr))]
+//! #[brw(bits = $count:expr)] or #[brw(bits($count:expr))]
+//! ```
+//!
+//! Consecutive `bits` fields are packed into the same bytes, and the last byte
+//! of a run of `bits` fields is padded with zero bits, so the next field always
+//! starts on a byte boundary. The order of the bits in each byte is set with
+//! the `bit_order` directive, which defaults to
+//! [`BitOrder::MsbFirst`](crate::io::BitOrder::MsbFirst):
+//!
+//! ```text
+//! #[bw(bit_order = $order:expr)] or #[bw(bit_order($order:expr))]
+//! ```
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor};
+//! #[derive(BinWrite)]
+//! struct Header {
+//!     #[bw(bits = 4)]
+//!     version: u8,
+//!     #[bw(bits = 4)]
+//!     len: u8,
+//!     id: u16,
+//! }
+//!
+//! # let mut out = Cursor::new(Vec::new());
+//! # out.write_be(&Header { version: 4, len: 5, id: 1 }).unwrap();
+//! # assert_eq!(out.into_inner(), b"\x45\0\x01");
+//! ```
+//!
+//! ## Errors
+//!
+//! If a value does not fit in `$count` bits, an [`Io`](crate::Error::Io) error
+//! is returned.
+//!
 //! # Arguments
 //!
 //! The `import` and `args` directives define the type of
@@ -207,6 +265,40 @@
 //! function `write_options`, the type is inexpressible without [GATs](https://github.com/rust-lang/rfcs/pull/1598).
 //!
 //!
+//! # Context
+//!
+//! The `context` directive gives a struct or enum access to a user-defined
+//! value stored in the [`WriteOptions`](crate::WriteOptions). The context is
+//! passed along automatically to everything being written.
+//!
+//! ```text
+//! #[bw(context($binding:ident : $ty:ty))] or #[bw(context = $binding:ident : $ty:ty)]
+//! ```
+//!
+//! The context is bound to a variable of type `&$ty` which can be used in
+//! any directive. If the options do not contain a context of type `$ty`, an
+//! [`Error::MissingContext`](crate::Error::MissingContext) is returned.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor, Endian, WriteOptions};
+//! struct Scale(u32);
+//!
+//! #[derive(BinWrite)]
+//! #[bw(context(scale: Scale))]
+//! struct Point {
+//!     #[bw(map = |x| x * scale.0)]
+//!     x: u32,
+//! }
+//!
+//! let scale = Scale(10);
+//! let options = WriteOptions::new(Endian::Big).with_context(&scale);
+//! let mut writer = Cursor::new(Vec::new());
+//! vec![Point { x: 1 }, Point { x: 2 }].write_options(&mut writer, &options, ()).unwrap();
+//! assert_eq!(writer.into_inner(), b"\0\0\0\x0a\0\0\0\x14");
+//! ```
+//!
 //! # Assert
 //!
 //! The `assert` directive validates objects and fields before they are written,
@@ -324,6 +416,30 @@
 //!    [`BinWrite::write_options`](crate::BinWrite::write_options) by the caller
 //! 5. The host machine’s native byte order
 //!
+//! ## Byte order from magic
+//!
+//! The `magic_endian` directive writes a magic number in the byte order of the
+//! struct or enum, which is the byte order passed by the caller, so it can be
+//! detected again when [reading](crate::attribute::read#byte-order-from-magic):
+//!
+//! ```text
+//! #[bw(magic_endian = $magic:literal)] or #[bw(magic_endian($magic:literal))]
+//! #[bw(magic_endian($little:literal, $big:literal))]
+//! ```
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor};
+//! #[derive(BinWrite)]
+//! #[bw(magic_endian(b"II", b"MM"))]
+//! struct TiffHeader {
+//!     version: u16,
+//!     first_ifd: u32,
+//! }
+//!
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_be(&TiffHeader { version: 42, first_ifd: 8 }).unwrap();
+//! assert_eq!(writer.into_inner(), b"MM\0\x2a\0\0\0\x08");
+//! ```
 //!
 //! # Calculations
 //!
@@ -380,6 +496,267 @@
 //! # assert_eq!(&writer.into_inner()[..], b"\0\0\0\x03\0\x01\x02");
 //! ```
 //!
+//! # Backpatching
+//!
+//! The `backpatch` directive writes a field as a placeholder, then overwrites
+//! it with the value of an expression once every other field of the struct or
+//! variant has been written. The `byte_range` directive binds the
+//! [`Range`](core::ops::Range) of stream positions that a field was written to
+//! to a variable, which is useful for computing sizes and offsets:
+//!
+//! ```text
+//! #[bw(backpatch = $value:expr)] or #[bw(backpatch($value:expr))]
+//! #[bw(byte_range = $binding:ident)] or #[bw(byte_range($binding:ident))]
+//! ```
+//!
+//! The placeholder is the field’s own value, or the value from `calc` if the
+//! field uses it. The backpatched value is written with the same byte order
+//! and arguments as the placeholder, and must be exactly as large as the
+//! placeholder, otherwise [`Error::BackpatchSize`](crate::Error::BackpatchSize)
+//! is returned. Afterwards, the writer is moved back to the end of the struct.
+//!
+//! Byte ranges do not include any padding or alignment. Fields which are not
+//! written because of [versioning](#versioning) have an empty range at the
+//! position where they would have been written, and are not backpatched.
+//!
+//! `backpatch` cannot be used with `map`, `try_map`, `write_with`, `bits`, or
+//! `ignore`. The writer must support seeking backwards.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{binrw, prelude::*, io::Cursor};
+//! #[binrw]
+//! #[brw(big)]
+//! struct Chunk {
+//!     #[br(temp)]
+//!     #[bw(calc = 0, backpatch = (data_range.end - data_range.start) as u32)]
+//!     len: u32,
+//!     #[br(temp)]
+//!     #[bw(calc = 0, backpatch = names_range.start as u16)]
+//!     names_offset: u16,
+//!     #[br(count = len)]
+//!     #[bw(byte_range = data_range)]
+//!     data: Vec<u8>,
+//!     #[br(seek_before = binrw::io::SeekFrom::Start(names_offset.into()))]
+//!     #[bw(align_before = 4, byte_range = names_range)]
+//!     names: [u8; 4],
+//! }
+//!
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_be(&Chunk { data: vec![1, 2, 3], names: *b"abcd" }).unwrap();
+//! assert_eq!(writer.into_inner(), b"\0\0\0\x03\0\x0c\x01\x02\x03\0\0\0abcd");
+//! ```
+//!
+//! # Checksums
+//!
+//! The `checksum` directive writes a field as a placeholder, then overwrites
+//! it with the checksum of the bytes written for a range of fields once every
+//! other field of the struct or variant has been written, using any type
+//! which implements [`Checksum`](crate::checksum::Checksum):
+//!
+//! ```text
+//! #[bw(checksum($algorithm:ty, from = $first:ident))]
+//! #[bw(checksum($algorithm:ty, from = $first:ident, to = $last:ident))]
+//! #[brw(checksum($algorithm:ty, from = $first:ident, to = $last:ident))]
+//! ```
+//!
+//! The range is the same as when [reading](crate::attribute::read#checksums):
+//! it starts at the position before the `from` field, including any magic or
+//! padding, and ends at the position after the `to` field. The placeholder
+//! works like a [`backpatch`](#backpatching) placeholder. Checksums are
+//! written after all `backpatch` values, in the order of their fields, so a
+//! checksum can cover another checksum declared before it.
+//!
+//! Since writers cannot be read from, a copy of everything written for the
+//! struct is kept in memory until the checksums have been written. The writer
+//! must support seeking backwards.
+//!
+//! `checksum` cannot be used with `map`, `try_map`, `write_with`, `bits`,
+//! `ignore`, `backpatch`, or `async`.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{binrw, prelude::*, checksum::Crc32, io::Cursor};
+//! #[binrw]
+//! #[brw(big)]
+//! struct Packet {
+//!     #[br(temp)]
+//!     #[bw(calc = data.len() as u8)]
+//!     len: u8,
+//!     #[br(count = len)]
+//!     data: Vec<u8>,
+//!     #[br(temp)]
+//!     #[bw(calc = 0)]
+//!     #[brw(checksum(Crc32, from = len, to = data))]
+//!     crc: u32,
+//! }
+//!
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_be(&Packet { data: b"hi".to_vec() }).unwrap();
+//! assert_eq!(writer.into_inner(), b"\x02hi\x65\x8f\x35\x2f");
+//! ```
+//!
+//! # Transforms
+//!
+//! The `transform` directive writes a field into a buffer, encodes the buffer
+//! with any type which implements
+//! [`Transform`](crate::io::transform::Transform), then writes the encoded
+//! data:
+//!
+//! ```text
+//! #[bw(transform = $transform:expr)] or #[bw(transform($transform:expr))]
+//! ```
+//!
+//! The field is written starting at position 0 of the buffer, so any
+//! positions used while writing it are relative to the decoded data. The
+//! `size` used when [reading](crate::attribute::read#transforms) is accepted
+//! but ignored. To store the size of the encoded data, combine the field with
+//! [`byte_range`](#backpatching) and a `backpatch` field.
+//!
+//! `transform` cannot be used with `bits`, `backpatch`, `checksum`, or
+//! `async`.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{binrw, prelude::*, io::{transform::Rle, Cursor}};
+//! #[binrw]
+//! struct Image {
+//!     width: u8,
+//!     #[brw(transform = Rle)]
+//!     pixels: [u8; 8],
+//! }
+//!
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_le(&Image { width: 8, pixels: [0; 8] }).unwrap();
+//! assert_eq!(writer.into_inner(), b"\x08\xf9\0");
+//! ```
+//!
+//! # Size
+//!
+//! The `size` directive makes sure a field takes up exactly `size` bytes of
+//! the output:
+//!
+//! ```text
+//! #[bw(size = $size:expr)] or #[bw(size($size:expr))]
+//! ```
+//!
+//! If fewer bytes are written for the field, the rest is filled with zeroes.
+//! If more bytes are written, a [`SizeExceeded`](crate::Error::SizeExceeded)
+//! error is returned. Unlike [`pad_size_to`](#padding-and-alignment), this
+//! means the field can be read back with the same
+//! [`size`](crate::attribute::read#size) directive.
+//!
+//! `size` cannot be used with `bits` or `transform`.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor, NullString};
+//! #[derive(BinWrite)]
+//! struct Record {
+//!     #[bw(size = 4)]
+//!     name: NullString,
+//! }
+//!
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_le(&Record { name: NullString::from_string("ab".into()) }).unwrap();
+//! assert_eq!(writer.into_inner(), b"ab\0\0");
+//!
+//! let mut writer = Cursor::new(Vec::new());
+//! let result = writer.write_le(&Record { name: NullString::from_string("abcd".into()) });
+//! assert!(matches!(result, Err(binrw::Error::SizeExceeded { pos: 0, size: 4 })));
+//! ```
+//!
+//! # Data sections
+//!
+//! The `data_section` directive writes the values pointed to by every
+//! [`FilePtr`](crate::FilePtr) in a struct or enum, including ones nested in
+//! other pointed-to values, into a [`DataSection`](crate::file_ptr::DataSection)
+//! directly after the end of the struct or enum:
+//!
+//! ```text
+//! #[bw(data_section)]
+//! ```
+//!
+//! Pointed-to values are written in the order that their pointers are written,
+//! and the writer is left at the end of the data section afterwards. If the
+//! struct or enum is written as part of another object which already has a
+//! data section, its values are written into that data section instead.
+//!
+//! To write pointed-to values somewhere else, like into a separate part of a
+//! format, pass a `DataSection` using
+//! [`WriteOptions::with_data_section`](crate::WriteOptions::with_data_section).
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{binrw, prelude::*, io::Cursor, FilePtr32};
+//! #[binrw]
+//! #[brw(little)]
+//! #[bw(data_section)]
+//! struct Archive {
+//!     version: u16,
+//!     first: FilePtr32<[u8; 2]>,
+//!     second: FilePtr32<u8>,
+//! }
+//!
+//! let archive = Archive {
+//!     version: 1,
+//!     first: FilePtr32 { ptr: 0, value: Some([1, 2]) },
+//!     second: FilePtr32 { ptr: 0, value: Some(3) },
+//! };
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_le(&archive).unwrap();
+//! assert_eq!(writer.into_inner(), b"\x01\0\x0a\0\0\0\x0c\0\0\0\x01\x02\x03");
+//! ```
+//!
+//! ## Errors
+//!
+//! Writing a `FilePtr` with a value fails with
+//! [`Error::MissingDataSection`](crate::Error::MissingDataSection) if there is
+//! no data section, or with
+//! [`Error::PointerOverflow`](crate::Error::PointerOverflow) if the offset to
+//! its value does not fit in its pointer type.
+//!
+//! # Offset
+//!
+//! The `offset` directive specifies an absolute offset which is subtracted
+//! from the position of a value before writing the offset to it, for
+//! `BinWrite` implementations which write data at an offset, like
+//! [`FilePtr`](crate::FilePtr):
+//!
+//! ```text
+//! #[bw(offset = $offset:expr)] or #[bw(offset($offset:expr))]
+//! ```
+//!
+//! When manually implementing
+//! [`BinWrite::write_options`](crate::BinWrite::write_options) or a
+//! [custom writer function](#custom-writers), the offset is accessible
+//! from [`WriteOptions::offset`](crate::WriteOptions::offset).
+//!
+//! Any field or [import](#arguments) can be referenced by the expression in
+//! the directive.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{binrw, prelude::*, io::Cursor, FilePtr8};
+//! #[binrw]
+//! #[bw(data_section)]
+//! struct Table {
+//!     #[br(offset = 1)]
+//!     #[bw(offset = 1)]
+//!     entry: FilePtr8<u8>,
+//! }
+//!
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_le(&Table { entry: FilePtr8 { ptr: 0, value: Some(0xff) } }).unwrap();
+//! assert_eq!(writer.into_inner(), b"\0\xff");
+//! ```
+//!
 //! # Default
 //!
 //! The `default` directive, and its alias `ignore`, sets the value of the field
@@ -416,9 +793,10 @@
 //! ```
 //!
 //! The magic number can be a byte literal, byte string, char, float, or
-//! integer. When a magic number is matched, parsing begins with the first byte
-//! after the magic number in the data. When a magic number is not matched, an
-//! error is returned.
+//! integer. A char magic must be at most `'\u{ff}'` and is stored as a single
+//! byte. When a magic number is matched, parsing begins with the first byte
+//! after the magic number in the data. When a magic number is not matched, an error is
+//! returned.
 //!
 //! ## Examples
 //!
@@ -448,6 +826,122 @@
 //! [`BadMagic`](crate::Error::BadMagic) error is returned and the writer’s
 //! position is reset to where it was before parsing started.
 //!
+//! # Conditional values
+//!
+//! The `if` directive allows conditional writing of a field, only writing the
+//! field if the condition is true:
+//!
+//! ```text
+//! #[bw(if = $cond:expr)] or #[bw(if($cond:expr))]
+//! #[brw(if = $cond:expr)] or #[brw(if($cond:expr))]
+//! ```
+//!
+//! An alternate value may also be given so the same directive can be used for
+//! reading with `brw`, but it is ignored when writing.
+//!
+//! Any earlier field or [import](#arguments) can be referenced by the
+//! expression in the directive. Fields are references when writing, so they
+//! may need to be dereferenced.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor};
+//! #[derive(BinWrite)]
+//! struct MyType {
+//!     var: u32,
+//!
+//!     #[bw(if(*var == 1))]
+//!     original_byte: Option<u8>,
+//!
+//!     #[bw(if(*var != 1))]
+//!     other_byte: u8,
+//! }
+//!
+//! let value = MyType { var: 1, original_byte: Some(3), other_byte: 42 };
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_be(&value).unwrap();
+//! assert_eq!(writer.into_inner(), b"\0\0\0\x01\x03");
+//! ```
+//!
+//! # Versioning
+//!
+//! The `version` directive sets the version of the format being written, and
+//! the `since` and `until` directives only write a field in some versions.
+//!
+//! ```text
+//! #[bw(version = $version:expr)]
+//! #[bw(since = $since:expr)] or #[bw(since($since:expr))]
+//! #[bw(until = $until:expr)] or #[bw(until($until:expr))]
+//! ```
+//!
+//! A field using `since` is only written when the version is greater than or
+//! equal to `$since`, and a field using `until` is only written when the
+//! version is less than `$until`.
+//!
+//! Fields are references when writing, so a version taken from a field of the
+//! struct needs to be dereferenced.
+//!
+//! ## Examples
+//!
+//! ```
+//! # use binrw::{prelude::*, io::Cursor};
+//! #[derive(BinWrite)]
+//! #[bw(big, version = *version)]
+//! struct Header {
+//!     version: u8,
+//!     #[bw(since = 2)]
+//!     flags: u16,
+//!     #[bw(until = 3)]
+//!     reserved: u8,
+//! }
+//!
+//! let mut writer = Cursor::new(Vec::new());
+//! writer.write_ne(&Header { version: 1, flags: 1, reserved: 0xff }).unwrap();
+//! writer.write_ne(&Header { version: 3, flags: 1, reserved: 0xff }).unwrap();
+//! assert_eq!(writer.into_inner(), b"\x01\xff\x03\x00\x01");
+//! ```
+//!
+//! # Backtrace
+//!
+//! When an error is raised during writing, BinWrite forms a backtrace, bubbling
+//! the error upwards and attaching a frame for every field it passes through.
+//! By default, each frame names the field and the struct or enum variant it
+//! belongs to, along with the position of the writer when the error happened.
+//!
+//! The `#[bw(err_context(...))]` attribute replaces the default frame of a
+//! field, and works the same way as
+//! [`#[br(err_context(...))]`](crate::attribute::read#backtrace):
+//!
+//! 1. If the first (or only) item is a string literal, it will be a message
+//!    format string, with any other arguments being used as arguments.
+//!
+//! 2. Otherwise, only a single argument is allowed, which will then be
+//!    attached as a context type. This type must implement
+//!    [`Display`](core::fmt::Display), [`Debug`], [`Send`], and [`Sync`].
+//!
+//! ## Example
+//!
+//! ```
+//! # use binrw::{io::Cursor, BinWrite, BinWriterExt};
+//! #[derive(BinWrite)]
+//! struct Inner {
+//!     #[bw(assert(*len < 0x100, "len is too big"))]
+//!     len: u32,
+//! }
+//!
+//! #[derive(BinWrite)]
+//! struct Outer {
+//!     #[bw(err_context("While writing inner with len {}", inner.len))]
+//!     inner: Inner,
+//! }
+//!
+//! let err = Cursor::new(Vec::new())
+//!     .write_le(&Outer { inner: Inner { len: 0x100 } })
+//!     .unwrap_err();
+//! assert!(matches!(err.root_cause(), binrw::Error::AssertFail { .. }));
+//! ```
+//!
 //! # Map
 //!
 //! The `map` and `try_map` directives allow data to be written using one type and
@@ -653,3 +1147,18 @@
 //!     assert_eq!(&x.into_inner()[..], b"\x01abcdabcd");
 //! }
 //! ```
+//!
+//! # Async
+//!
+//! The `async` directive additionally implements `AsyncBinWrite` for a type,
+//! so that it can be written to a non-blocking `AsyncWrite + AsyncSeek`
+//! writer. It requires the `async` feature:
+//!
+//! ```text
+//! #[bw(async)]
+//! #[brw(async)]
+//! ```
+//!
+//! All other directives work the same as they do when writing to a blocking
+//! writer, except for `write_with`, which cannot be used because it calls a
+//! blocking function. Every field type must also implement `AsyncBinWrite`.
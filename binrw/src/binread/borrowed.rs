@@ -0,0 +1,203 @@
+use super::{BinRead, ReadOptions, VecArgs};
+use crate::{
+    io::{self, Cursor},
+    BinResult, Error,
+};
+use core::convert::TryFrom;
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+/// The `BorrowedBinRead` trait reads objects which borrow from the input
+/// directly out of a byte slice, without copying.
+///
+/// Every [`BinRead`] type also implements `BorrowedBinRead`, so borrowed
+/// objects can freely mix borrowed and owned fields. The borrowed impls are
+/// for `&'a [T]` and `&'a [T; N]`, where `T` is [`Unaligned`], and for
+/// `&'a str`. Slices and strings are sized using the [`count`] directive.
+///
+/// [`count`]: crate::attribute::read#count
+///
+/// # Derivable
+///
+/// This trait is implemented instead of `BinRead` when a type is derived with
+/// the [`borrow`](crate::attribute::read#borrowing) directive.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, BorrowedBinRead, endian::U32Le};
+///
+/// #[derive(BinRead)]
+/// #[br(borrow, little, magic = b"IDX")]
+/// struct Index<'a> {
+///     name_len: u8,
+///     #[br(count = name_len)]
+///     name: &'a str,
+///     len: u16,
+///     #[br(count = len)]
+///     offsets: &'a [U32Le],
+/// }
+///
+/// let bytes = b"IDX\x04main\x02\0\x10\0\0\0\x20\0\0\0";
+/// let index = Index::read_borrowed(bytes).unwrap();
+/// assert_eq!(index.name, "main");
+/// assert_eq!(index.offsets[1].get(), 0x20);
+/// // The fields point into the input
+/// assert_eq!(index.name.as_ptr(), bytes[4..].as_ptr());
+/// ```
+pub trait BorrowedBinRead<'a>: Sized {
+    /// The type used for the `args` parameter of [`read_borrowed_args()`] and
+    /// [`read_options_borrowed()`].
+    ///
+    /// [`read_borrowed_args()`]: Self::read_borrowed_args
+    /// [`read_options_borrowed()`]: Self::read_options_borrowed
+    type Args: Clone;
+
+    /// Read `Self` from the start of `bytes` using default arguments.
+    fn read_borrowed(bytes: &'a [u8]) -> BinResult<Self>
+    where
+        Self::Args: Default,
+    {
+        Self::read_borrowed_args(bytes, Self::Args::default())
+    }
+
+    /// Read `Self` from the start of `bytes` using the given arguments.
+    fn read_borrowed_args(bytes: &'a [u8], args: Self::Args) -> BinResult<Self> {
+        let mut reader = Cursor::new(bytes);
+        let options = ReadOptions::default();
+        let mut res = Self::read_options_borrowed(&mut reader, &options, args.clone())?;
+        res.after_parse_borrowed(&mut reader, &options, args)?;
+        Ok(res)
+    }
+
+    /// Read `Self` from the cursor using the given [`ReadOptions`] and
+    /// arguments.
+    fn read_options_borrowed(
+        reader: &mut Cursor<&'a [u8]>,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self>;
+
+    /// Runs any post-processing steps required to finalize construction of the
+    /// object.
+    fn after_parse_borrowed(
+        &mut self,
+        _: &mut Cursor<&'a [u8]>,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()> {
+        Ok(())
+    }
+}
+
+impl<'a, T: BinRead> BorrowedBinRead<'a> for T {
+    type Args = T::Args;
+
+    fn read_options_borrowed(
+        reader: &mut Cursor<&'a [u8]>,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        T::read_options(reader, options, args)
+    }
+
+    fn after_parse_borrowed(
+        &mut self,
+        reader: &mut Cursor<&'a [u8]>,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        self.after_parse(reader, options, args)
+    }
+}
+
+/// A marker for types which can be borrowed directly from a byte slice by
+/// [`BorrowedBinRead`].
+///
+/// Multi-byte integers generally have an alignment greater than one and a
+/// byte order, so the fixed byte order types in [`endian`](crate::endian),
+/// like [`U32Le`](crate::endian::U32Le), are used instead.
+///
+/// # Safety
+///
+/// Implementors must have an alignment of 1, contain no padding, and be valid
+/// for any bit pattern.
+pub unsafe trait Unaligned: Copy + 'static {}
+
+unsafe impl Unaligned for u8 {}
+unsafe impl Unaligned for i8 {}
+unsafe impl<T: Unaligned, const N: usize> Unaligned for [T; N] {}
+
+/// Takes `len` bytes from the cursor without copying them.
+fn take_bytes<'a>(reader: &mut Cursor<&'a [u8]>, len: Option<usize>) -> BinResult<&'a [u8]> {
+    let bytes: &'a [u8] = reader.get_ref();
+    let pos = reader.position();
+    let start = usize::try_from(pos).unwrap_or(usize::MAX).min(bytes.len());
+    match len.and_then(|len| bytes[start..].get(..len)) {
+        Some(taken) => {
+            reader.set_position(pos + taken.len() as u64);
+            Ok(taken)
+        }
+        None => Err(Error::Io(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "not enough bytes in reader",
+        ))),
+    }
+}
+
+fn cast_slice<T: Unaligned>(bytes: &[u8], count: usize) -> &[T] {
+    debug_assert_eq!(bytes.len(), count * core::mem::size_of::<T>());
+    // SAFETY: `Unaligned` types have no alignment requirement and are valid for
+    // any bit pattern, and `bytes` holds exactly `count` of them
+    unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast::<T>(), count) }
+}
+
+impl<'a, T: Unaligned> BorrowedBinRead<'a> for &'a [T] {
+    type Args = VecArgs<()>;
+
+    fn read_options_borrowed(
+        reader: &mut Cursor<&'a [u8]>,
+        _: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        let len = args.count.checked_mul(core::mem::size_of::<T>());
+        take_bytes(reader, len).map(|bytes| cast_slice(bytes, args.count))
+    }
+}
+
+impl<'a, T: Unaligned, const N: usize> BorrowedBinRead<'a> for &'a [T; N] {
+    type Args = ();
+
+    fn read_options_borrowed(
+        reader: &mut Cursor<&'a [u8]>,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self> {
+        let len = N.checked_mul(core::mem::size_of::<T>());
+        take_bytes(reader, len).map(|bytes| {
+            <&[T; N]>::try_from(cast_slice::<T>(bytes, N))
+                .unwrap_or_else(|_| unreachable!("took exactly N elements"))
+        })
+    }
+}
+
+impl<'a> BorrowedBinRead<'a> for &'a str {
+    type Args = VecArgs<()>;
+
+    fn read_options_borrowed(
+        reader: &mut Cursor<&'a [u8]>,
+        _: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        let pos = reader.position();
+        let bytes = take_bytes(reader, Some(args.count))?;
+        core::str::from_utf8(bytes).map_err(|err| {
+            reader.set_position(pos);
+            Error::Custom {
+                pos,
+                err: Box::new(err),
+            }
+        })
+    }
+}
@@ -1,14 +1,14 @@
 use crate::{
+    bulk,
     io::{self, Read, Seek, SeekFrom},
     BinRead, BinResult, Endian, Error, ReadOptions,
 };
-use core::any::Any;
-use core::convert::TryInto;
+use core::{cmp::Reverse, convert::TryInto, num::Wrapping};
 
 use binrw_derive::BinrwNamedArgs;
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, format, vec::Vec};
 
 macro_rules! binread_impl {
     ($($type_name:ty),*$(,)?) => {
@@ -16,13 +16,18 @@ macro_rules! binread_impl {
             impl BinRead for $type_name {
                 type Args = ();
 
-                fn read_options<R: Read + Seek>(reader: &mut R, options: &ReadOptions, _: Self::Args) -> BinResult<Self> {
+                fn read_options<R: Read + Seek>(reader: &mut R, options: &ReadOptions<'_>, _: Self::Args) -> BinResult<Self> {
                     let mut val = [0; core::mem::size_of::<$type_name>()];
                     let pos = reader.stream_position()?;
 
                     reader.read_exact(&mut val).or_else(|e| {
-                        reader.seek(SeekFrom::Start(pos))?;
-                        Err(e)
+                        // A stream which cannot seek backwards (like
+                        // `io::NoSeek`) can never be rewound, so only the read
+                        // error is reported for it
+                        match reader.seek(SeekFrom::Start(pos)) {
+                            Err(seek_error) if !io::is_seek_backwards(&seek_error) => Err(seek_error),
+                            _ => Err(e),
+                        }
                     })?;
                     Ok(match options.endian() {
                         Endian::Big => {
@@ -45,30 +50,209 @@ macro_rules! binread_impl {
     }
 }
 
+/// Arguments passed to the binread and binwrite impls for `bool`.
+///
+/// By default, a `bool` is stored as a single byte, and any non-zero value is
+/// read as `true`. When written, `true` is always stored as 1.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{binrw, BinReaderExt, Error, io::Cursor};
+///
+/// #[binrw]
+/// # #[derive(Debug)]
+/// #[brw(little)]
+/// struct Flags {
+///     enabled: bool,
+///     #[brw(args { strict: true, size: 4 })]
+///     visible: bool,
+/// }
+///
+/// let flags: Flags = Cursor::new(b"\x02\x01\0\0\0").read_le().unwrap();
+/// assert!(flags.enabled && flags.visible);
+///
+/// let error = Cursor::new(b"\x01\x02\0\0\0").read_le::<Flags>().unwrap_err();
+/// assert!(matches!(error.root_cause(), Error::AssertFail { pos: 1, .. }));
+/// ```
+#[derive(BinrwNamedArgs, Clone)]
+pub struct BoolArgs {
+    /// Whether values other than 0 and 1 are rejected with an
+    /// [`AssertFail`](crate::Error::AssertFail) error when reading. Defaults
+    /// to `false`.
+    #[named_args(default = false)]
+    pub strict: bool,
+
+    /// The number of bytes used to store the value, from 1 to 16. The byte
+    /// order determines which byte holds the 1 of a `true` value. Defaults
+    /// to 1.
+    #[named_args(default = 1)]
+    pub size: usize,
+}
+
+impl Default for BoolArgs {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            size: 1,
+        }
+    }
+}
+
+impl BoolArgs {
+    /// The largest supported size of a `bool`, which is the size of the
+    /// largest primitive integer.
+    pub(crate) const MAX_SIZE: usize = 16;
+
+    /// Returns the index of the least significant byte of a `bool` stored with
+    /// these arguments, or an error if the size is invalid.
+    pub(crate) fn low_byte(&self, endian: Endian, pos: u64) -> BinResult<usize> {
+        if !(1..=Self::MAX_SIZE).contains(&self.size) {
+            return Err(Error::AssertFail {
+                pos,
+                message: format!(
+                    "bool size {} is not between 1 and {} bytes",
+                    self.size,
+                    Self::MAX_SIZE
+                ),
+            });
+        }
+
+        let big = match endian {
+            Endian::Big => true,
+            Endian::Little => false,
+            Endian::Native => cfg!(target_endian = "big"),
+        };
+        Ok(if big { self.size - 1 } else { 0 })
+    }
+
+    /// Converts the bytes of a `bool` which was read from `pos` to a value.
+    pub(crate) fn decode(&self, bytes: &[u8], low_byte: usize, pos: u64) -> BinResult<bool> {
+        let value = bytes.iter().any(|&byte| byte != 0);
+        let is_canonical = bytes
+            .iter()
+            .enumerate()
+            .all(|(i, &byte)| byte == 0 || (i == low_byte && byte == 1));
+        if self.strict && !is_canonical {
+            return Err(Error::AssertFail {
+                pos,
+                message: format!("invalid bool value {:02x?}, expected 0 or 1", bytes),
+            });
+        }
+
+        Ok(value)
+    }
+}
+
+impl BinRead for bool {
+    type Args = BoolArgs;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+        let low_byte = args.low_byte(options.endian(), pos)?;
+        let mut buf = [0; BoolArgs::MAX_SIZE];
+        let bytes = &mut buf[..args.size];
+        reader.read_exact(bytes)?;
+        args.decode(bytes, low_byte, pos)
+    }
+}
+
+/// Reads a `char` as a UTF-32 code point, using the byte order of the
+/// [`ReadOptions`].
 impl BinRead for char {
     type Args = ();
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         _: Self::Args,
     ) -> BinResult<Self> {
-        // TODO: somehow do proper unicode handling?
-        Ok(<u8>::read_options(reader, options, ())? as char)
+        let pos = reader.stream_position()?;
+        let value = <u32>::read_options(reader, options, ())?;
+        core::char::from_u32(value).ok_or_else(|| Error::AssertFail {
+            pos,
+            message: format!("invalid char value 0x{:x}", value),
+        })
     }
 }
 
+macro_rules! binread_nonzero_impl {
+    ($($type_name:ident($inner:ty)),*$(,)?) => {
+        $(
+            impl BinRead for core::num::$type_name {
+                type Args = ();
+
+                fn read_options<R: Read + Seek>(
+                    reader: &mut R,
+                    options: &ReadOptions<'_>,
+                    _: Self::Args,
+                ) -> BinResult<Self> {
+                    let pos = reader.stream_position()?;
+                    Self::new(<$inner>::read_options(reader, options, ())?).ok_or_else(|| {
+                        Error::AssertFail {
+                            pos,
+                            message: concat!("unexpected zero value for ", stringify!($type_name))
+                                .into(),
+                        }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+// `NonZeroU8` and `NonZeroU16` are left out so that `Vec<NonZeroU8>` and
+// `Vec<NonZeroU16>` keep their null-terminated impls in `strings`
+binread_nonzero_impl!(
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+    NonZeroU128(u128),
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroI128(i128),
+);
+
 binread_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
 
-fn not_enough_bytes<T>(_: T) -> Error {
-    Error::Io(io::Error::new(
-        io::ErrorKind::UnexpectedEof,
-        "not enough bytes in reader",
-    ))
+/// Reads an IPv4 address as 4 bytes in network byte order, regardless of the
+/// byte order of the [`ReadOptions`].
+impl BinRead for core::net::Ipv4Addr {
+    type Args = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self> {
+        <[u8; 4]>::read_options(reader, options, ()).map(Self::from)
+    }
+}
+
+/// Reads an IPv6 address as 16 bytes in network byte order, regardless of the
+/// byte order of the [`ReadOptions`].
+impl BinRead for core::net::Ipv6Addr {
+    type Args = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self> {
+        <[u8; 16]>::read_options(reader, options, ()).map(Self::from)
+    }
 }
 
 /// Arguments passed to the binread impl for Vec
 ///
+/// Vecs of primitive numbers, like `Vec<u32>` or `Vec<f64>`, are read with a
+/// single read of all of their bytes instead of one element at a time.
+///
 /// # Examples
 ///
 /// ```rust
@@ -137,22 +321,15 @@ impl<B: BinRead> BinRead for Vec<B> {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<Self> {
-        let mut list = Self::with_capacity(args.count);
+        let mut list = Self::new();
 
-        if let Some(bytes) = <dyn Any>::downcast_mut::<Vec<u8>>(&mut list) {
-            let byte_count = reader
-                .take(args.count.try_into().map_err(not_enough_bytes)?)
-                .read_to_end(bytes)?;
-
-            if byte_count == args.count {
-                Ok(list)
-            } else {
-                Err(not_enough_bytes(()))
-            }
+        if let Some(result) = bulk::read_vec(reader, options.endian(), args.count, &mut list) {
+            result.map(|_| list)
         } else {
+            list.reserve(args.count);
             for _ in 0..args.count {
                 list.push(B::read_options(reader, options, args.inner.clone())?);
             }
@@ -163,7 +340,7 @@ impl<B: BinRead> BinRead for Vec<B> {
     fn after_parse<R>(
         &mut self,
         reader: &mut R,
-        ro: &ReadOptions,
+        ro: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()>
     where
@@ -182,13 +359,26 @@ impl<B: BinRead, const N: usize> BinRead for [B; N] {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<Self> {
-        array_init::try_array_init(|_| BinRead::read_options(reader, options, args.clone()))
+        let mut list = Vec::new();
+        if let Some(result) = bulk::read_vec(reader, options.endian(), N, &mut list) {
+            result?;
+            Ok(list
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("bulk read returned the wrong length")))
+        } else {
+            array_init::try_array_init(|_| BinRead::read_options(reader, options, args.clone()))
+        }
     }
 
-    fn after_parse<R>(&mut self, reader: &mut R, ro: &ReadOptions, args: B::Args) -> BinResult<()>
+    fn after_parse<R>(
+        &mut self,
+        reader: &mut R,
+        ro: &ReadOptions<'_>,
+        args: B::Args,
+    ) -> BinResult<()>
     where
         R: Read + Seek,
     {
@@ -206,7 +396,7 @@ macro_rules! binread_tuple_impl {
         impl<$type1: BinRead<Args=()>, $($types: BinRead<Args=()>),*> BinRead for ($type1, $($types),*) {
             type Args = ();
 
-            fn read_options<R: Read + Seek>(reader: &mut R, options: &ReadOptions, _: Self::Args) -> BinResult<Self> {
+            fn read_options<R: Read + Seek>(reader: &mut R, options: &ReadOptions<'_>, _: Self::Args) -> BinResult<Self> {
                 Ok((
                     BinRead::read_options(reader, options, ())?,
                     $(
@@ -215,7 +405,7 @@ macro_rules! binread_tuple_impl {
                 ))
             }
 
-            fn after_parse<R: Read + Seek>(&mut self, reader: &mut R, options: &ReadOptions, _: Self::Args) -> BinResult<()> {
+            fn after_parse<R: Read + Seek>(&mut self, reader: &mut R, options: &ReadOptions<'_>, _: Self::Args) -> BinResult<()> {
                 let ($type1, $(
                     $types
                 ),*) = self;
@@ -243,7 +433,11 @@ binread_tuple_impl!(
 impl BinRead for () {
     type Args = ();
 
-    fn read_options<R: Read + Seek>(_: &mut R, _: &ReadOptions, _: Self::Args) -> BinResult<Self> {
+    fn read_options<R: Read + Seek>(
+        _: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self> {
         Ok(())
     }
 }
@@ -253,7 +447,7 @@ impl<T: BinRead> BinRead for Box<T> {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<Self> {
         Ok(Box::new(T::read_options(reader, options, args)?))
@@ -265,7 +459,7 @@ impl<T: BinRead> BinRead for Option<T> {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<Self> {
         Ok(Some(T::read_options(reader, options, args)?))
@@ -274,7 +468,7 @@ impl<T: BinRead> BinRead for Option<T> {
     fn after_parse<R>(
         &mut self,
         reader: &mut R,
-        ro: &ReadOptions,
+        ro: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()>
     where
@@ -287,10 +481,43 @@ impl<T: BinRead> BinRead for Option<T> {
     }
 }
 
+macro_rules! binread_wrapper_impl {
+    ($($type_name:ident),*) => {
+        $(
+            impl<T: BinRead> BinRead for $type_name<T> {
+                type Args = T::Args;
+
+                fn read_options<R: Read + Seek>(
+                    reader: &mut R,
+                    options: &ReadOptions<'_>,
+                    args: Self::Args,
+                ) -> BinResult<Self> {
+                    Ok($type_name(T::read_options(reader, options, args)?))
+                }
+
+                fn after_parse<R: Read + Seek>(
+                    &mut self,
+                    reader: &mut R,
+                    options: &ReadOptions<'_>,
+                    args: Self::Args,
+                ) -> BinResult<()> {
+                    self.0.after_parse(reader, options, args)
+                }
+            }
+        )*
+    };
+}
+
+binread_wrapper_impl!(Wrapping, Reverse);
+
 impl<T: 'static> BinRead for core::marker::PhantomData<T> {
     type Args = ();
 
-    fn read_options<R: Read + Seek>(_: &mut R, _: &ReadOptions, _: Self::Args) -> BinResult<Self> {
+    fn read_options<R: Read + Seek>(
+        _: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self> {
         Ok(core::marker::PhantomData)
     }
 }
@@ -7,7 +7,13 @@ mod options;
 pub use options::*;
 
 mod impls;
-pub use impls::VecArgs;
+pub use impls::{BoolArgs, VecArgs};
+
+mod borrowed;
+pub use borrowed::{BorrowedBinRead, Unaligned};
+
+mod stream;
+pub use stream::{StreamRead, StreamReaderExt};
 
 /// The `BinRead` trait reads data from streams and converts it into objects.
 ///
@@ -68,7 +74,7 @@ pub trait BinRead: Sized + 'static {
     /// arguments.
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<Self>;
 
@@ -77,7 +83,7 @@ pub trait BinRead: Sized + 'static {
     fn after_parse<R: Read + Seek>(
         &mut self,
         _: &mut R,
-        _: &ReadOptions,
+        _: &ReadOptions<'_>,
         _: Self::Args,
     ) -> BinResult<()> {
         Ok(())
@@ -3,11 +3,12 @@ extern crate alloc;
 use super::Endian;
 #[cfg(all(doc, not(feature = "std")))]
 use alloc::vec::Vec;
+use core::any::Any;
 
 /// Runtime options for
 /// [`BinRead::read_options()`](crate::BinRead::read_options).
 #[derive(Default, Clone, Copy)]
-pub struct ReadOptions {
+pub struct ReadOptions<'a> {
     /// The [byte order](crate::Endian) to use when reading data.
     ///
     /// Note that if a derived type uses one of the
@@ -18,9 +19,12 @@ pub struct ReadOptions {
     /// An absolute offset added to the [`FilePtr::ptr`](crate::FilePtr::ptr)
     /// offset before reading the pointed-to value.
     offset: u64,
+
+    /// A user-defined value which is passed along to every object being read.
+    context: Option<&'a dyn Any>,
 }
 
-impl ReadOptions {
+impl<'a> ReadOptions<'a> {
     /// Create a new ReadOptions with a given Endian
     pub fn new(endian: Endian) -> Self {
         Self {
@@ -53,4 +57,21 @@ impl ReadOptions {
     pub fn offset(&self) -> u64 {
         self.offset
     }
+
+    /// Returns the given ReadOptions but with the context replaced
+    pub fn with_context<T: Any>(self, context: &'a T) -> Self {
+        Self {
+            context: Some(context),
+            ..self
+        }
+    }
+
+    /// A user-defined value which is passed along to every object being read,
+    /// or `None` if there is no context or it is not a `T`.
+    ///
+    /// Derived types can refer to the context from directives using the
+    /// [`context`](crate::attribute::read#context) directive.
+    pub fn context<T: Any>(&self) -> Option<&'a T> {
+        self.context?.downcast_ref()
+    }
 }
@@ -0,0 +1,116 @@
+use super::{BinRead, BinReaderExt};
+use crate::{
+    io::{NoSeek, Read},
+    BinResult, Endian,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+
+/// A marker for [`BinRead`] types which never need to seek backwards, so can
+/// be read from streams which cannot seek at all, like sockets and pipes.
+///
+/// Derived types implement this trait when they use the
+/// [`stream`](crate::attribute::read#streaming) directive.
+pub trait StreamRead: BinRead {}
+
+/// Extension methods for reading [`StreamRead`] objects from a reader which
+/// does not implement [`Seek`](crate::io::Seek).
+///
+/// Positions reported in errors are relative to the position of the stream
+/// when the read started.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{io::Cursor, BinRead, Endian, StreamReaderExt};
+///
+/// #[derive(BinRead, Debug, PartialEq)]
+/// #[br(stream, big, magic = b"PKT")]
+/// struct Packet {
+///     len: u8,
+///     #[br(count = len)]
+///     data: Vec<u8>,
+/// }
+///
+/// // Only `Read` is used, even though `Cursor` also implements `Seek`
+/// let mut stream = Cursor::new(b"PKT\x02hi");
+/// let packet: Packet = stream.read_stream(Endian::Big).unwrap();
+/// assert_eq!(packet, Packet { len: 2, data: b"hi".to_vec() });
+/// ```
+pub trait StreamReaderExt: Read + Sized {
+    /// Read `T` from the stream with the given byte order.
+    fn read_stream<T: StreamRead>(&mut self, endian: Endian) -> BinResult<T>
+    where
+        T::Args: Default,
+    {
+        self.read_stream_args(endian, T::Args::default())
+    }
+
+    /// Read `T` from the stream with the given byte order and arguments.
+    fn read_stream_args<T: StreamRead>(&mut self, endian: Endian, args: T::Args) -> BinResult<T> {
+        NoSeek::new(self).read_type_args(endian, args)
+    }
+}
+
+impl<R: Read + Sized> StreamReaderExt for R {}
+
+macro_rules! stream_read_impl {
+    ($($type_name:ty),*$(,)?) => {
+        $(
+            impl StreamRead for $type_name {}
+        )*
+    }
+}
+
+stream_read_impl!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    f32,
+    f64,
+    char,
+    bool,
+    core::num::NonZeroU32,
+    core::num::NonZeroU64,
+    core::num::NonZeroU128,
+    core::num::NonZeroI8,
+    core::num::NonZeroI16,
+    core::num::NonZeroI32,
+    core::num::NonZeroI64,
+    core::num::NonZeroI128,
+    ()
+);
+
+stream_read_impl!(core::net::Ipv4Addr, core::net::Ipv6Addr);
+
+macro_rules! stream_read_tuple_impl {
+    ($type1:ident $(, $types:ident)*) => {
+        #[allow(non_camel_case_types)]
+        impl<$type1: StreamRead<Args=()>, $($types: StreamRead<Args=()>),*> StreamRead for ($type1, $($types),*) {}
+
+        stream_read_tuple_impl!($($types),*);
+    };
+
+    () => {};
+}
+
+stream_read_tuple_impl!(
+    b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15, b16, b17, b18, b19, b20, b21,
+    b22, b23, b24, b25, b26, b27, b28, b29, b30, b31, b32
+);
+
+impl<B: StreamRead> StreamRead for Vec<B> {}
+impl<B: StreamRead, const N: usize> StreamRead for [B; N] {}
+impl<T: StreamRead> StreamRead for Box<T> {}
+impl<T: StreamRead> StreamRead for Option<T> {}
+impl<T: 'static> StreamRead for core::marker::PhantomData<T> {}
+impl<T: StreamRead> StreamRead for core::num::Wrapping<T> {}
+impl<T: StreamRead> StreamRead for core::cmp::Reverse<T> {}
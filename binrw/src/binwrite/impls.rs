@@ -1,10 +1,12 @@
 use core::any::Any;
+use core::cmp::Reverse;
 use core::marker::PhantomData;
+use core::num::Wrapping;
 
 use crate::alloc::boxed::Box;
 use crate::alloc::vec::Vec;
 use crate::io::{Seek, Write};
-use crate::{BinResult, BinWrite, Endian, WriteOptions};
+use crate::{bulk, BinResult, BinWrite, BoolArgs, Endian, WriteOptions};
 
 // ============================= nums =============================
 
@@ -17,7 +19,7 @@ macro_rules! binwrite_num_impl {
                 fn write_options<W: Write + Seek>(
                     &self,
                     writer: &mut W,
-                    options: &WriteOptions,
+                    options: &WriteOptions<'_>,
                     _: Self::Args,
                 ) -> BinResult<()> {
                     writer.write_all(&match options.endian() {
@@ -33,6 +35,70 @@ macro_rules! binwrite_num_impl {
 
 binwrite_num_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
 
+macro_rules! binwrite_nonzero_impl {
+    ($($type_name:ident),*$(,)?) => {
+        $(
+            impl BinWrite for core::num::$type_name {
+                type Args = ();
+
+                fn write_options<W: Write + Seek>(
+                    &self,
+                    writer: &mut W,
+                    options: &WriteOptions<'_>,
+                    _: Self::Args,
+                ) -> BinResult<()> {
+                    self.get().write_options(writer, options, ())
+                }
+            }
+        )*
+    };
+}
+
+binwrite_nonzero_impl!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+);
+
+impl BinWrite for bool {
+    type Args = BoolArgs;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        let low_byte = args.low_byte(options.endian(), writer.stream_position()?)?;
+        let mut buf = [0; BoolArgs::MAX_SIZE];
+        buf[low_byte] = u8::from(*self);
+        writer.write_all(&buf[..args.size])?;
+        Ok(())
+    }
+}
+
+/// Writes a `char` as a UTF-32 code point, using the byte order of the
+/// [`WriteOptions`].
+impl BinWrite for char {
+    type Args = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()> {
+        u32::from(*self).write_options(writer, options, ())
+    }
+}
+
 // =========================== end nums ===========================
 
 // =========================== array/vec ===========================
@@ -43,11 +109,11 @@ impl<T: BinWrite + 'static, const N: usize> BinWrite for [T; N] {
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &WriteOptions,
+        options: &WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
-        if let Some(this) = <dyn Any>::downcast_ref::<[u8; N]>(self) {
-            writer.write_all(&this[..])?;
+        if let Some(result) = bulk::write_array::<_, N>(writer, options.endian(), self) {
+            result?;
         } else {
             for item in self {
                 T::write_options(item, writer, options, args.clone())?;
@@ -64,7 +130,7 @@ impl<T: BinWrite> BinWrite for [T] {
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &WriteOptions,
+        options: &WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
         for item in self {
@@ -81,11 +147,11 @@ impl<T: BinWrite + 'static> BinWrite for Vec<T> {
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &WriteOptions,
+        options: &WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
-        if let Some(this) = <dyn Any>::downcast_ref::<Vec<u8>>(self) {
-            writer.write_all(this)?;
+        if let Some(result) = bulk::write_vec(writer, options.endian(), self) {
+            result?;
         } else {
             for item in self {
                 T::write_options(item, writer, options, args.clone())?;
@@ -106,7 +172,7 @@ impl<T: BinWrite + ?Sized> BinWrite for &T {
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &WriteOptions,
+        options: &WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
         (**self).write_options(writer, options, args)
@@ -119,7 +185,7 @@ impl<T: BinWrite + 'static> BinWrite for Box<T> {
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &WriteOptions,
+        options: &WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
         if let Some(this) = <dyn Any>::downcast_ref::<Box<[u8]>>(self) {
@@ -138,7 +204,7 @@ impl<T: BinWrite> BinWrite for Option<T> {
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &WriteOptions,
+        options: &WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
         match self {
@@ -148,13 +214,66 @@ impl<T: BinWrite> BinWrite for Option<T> {
     }
 }
 
+macro_rules! binwrite_wrapper_impl {
+    ($($type_name:ident),*) => {
+        $(
+            impl<T: BinWrite> BinWrite for $type_name<T> {
+                type Args = T::Args;
+
+                fn write_options<W: Write + Seek>(
+                    &self,
+                    writer: &mut W,
+                    options: &WriteOptions<'_>,
+                    args: Self::Args,
+                ) -> BinResult<()> {
+                    self.0.write_options(writer, options, args)
+                }
+            }
+        )*
+    };
+}
+
+binwrite_wrapper_impl!(Wrapping, Reverse);
+
+/// Writes an IPv4 address as 4 bytes in network byte order, regardless of the
+/// byte order of the [`WriteOptions`].
+impl BinWrite for core::net::Ipv4Addr {
+    type Args = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()> {
+        writer.write_all(&self.octets())?;
+        Ok(())
+    }
+}
+
+/// Writes an IPv6 address as 16 bytes in network byte order, regardless of the
+/// byte order of the [`WriteOptions`].
+impl BinWrite for core::net::Ipv6Addr {
+    type Args = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()> {
+        writer.write_all(&self.octets())?;
+        Ok(())
+    }
+}
+
 impl<T: BinWrite> BinWrite for PhantomData<T> {
     type Args = T::Args;
 
     fn write_options<W: Write + Seek>(
         &self,
         _: &mut W,
-        _: &WriteOptions,
+        _: &WriteOptions<'_>,
         _: Self::Args,
     ) -> BinResult<()> {
         Ok(())
@@ -171,7 +290,7 @@ impl BinWrite for () {
     fn write_options<W: Write + Seek>(
         &self,
         _: &mut W,
-        _: &WriteOptions,
+        _: &WriteOptions<'_>,
         _: Self::Args,
     ) -> BinResult<()> {
         Ok(())
@@ -189,7 +308,7 @@ macro_rules! binwrite_tuple_impl {
             fn write_options<W: Write + Seek>(
                 &self,
                 writer: &mut W,
-                options: &WriteOptions,
+                options: &WriteOptions<'_>,
                 _: Self::Args,
             ) -> BinResult<()> {
                 let ($type1, $(
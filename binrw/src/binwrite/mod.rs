@@ -2,8 +2,14 @@ use crate::{
     io::{Seek, Write},
     BinResult, Endian,
 };
+use core::any::Any;
+
+use crate::file_ptr::DataSection;
 
 mod impls;
+pub(crate) mod size;
+
+pub use size::BinSize;
 
 /// A trait for writing a given type to a writer
 pub trait BinWrite {
@@ -31,7 +37,7 @@ pub trait BinWrite {
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &WriteOptions,
+        options: &WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()>;
 }
@@ -40,15 +46,21 @@ pub trait BinWrite {
 ///
 /// Functionally the purpose of WriteOptions is simple: maintaining context which is implicitly
 /// passed throughout all types being written.
-#[derive(Default, Clone)]
-pub struct WriteOptions {
+#[derive(Default, Clone, Copy)]
+pub struct WriteOptions<'a> {
     endian: Endian,
+    offset: u64,
+    context: Option<&'a dyn Any>,
+    data_section: Option<&'a DataSection>,
 }
 
-impl WriteOptions {
+impl<'a> WriteOptions<'a> {
     /// Create a new `WriteOptions`. Additional fields can be instantiated using `.with_{field}`.
     pub fn new(endian: Endian) -> Self {
-        Self { endian }
+        Self {
+            endian,
+            ..Default::default()
+        }
     }
 
     /// Retrieves the specified endian
@@ -58,7 +70,49 @@ impl WriteOptions {
 
     /// Returns the same `WriteOptions` but with the endian set
     pub fn with_endian(self, endian: Endian) -> Self {
-        WriteOptions { endian }
+        WriteOptions { endian, ..self }
+    }
+
+    /// An absolute offset subtracted from the position of a pointed-to value
+    /// to get the [`FilePtr::ptr`](crate::FilePtr::ptr) offset.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the same `WriteOptions` but with the offset set
+    pub fn with_offset(self, offset: u64) -> Self {
+        WriteOptions { offset, ..self }
+    }
+
+    /// Retrieves the [`DataSection`] that [`FilePtr`](crate::FilePtr) writes
+    /// pointed-to values into, if there is one.
+    pub fn data_section(&self) -> Option<&'a DataSection> {
+        self.data_section
+    }
+
+    /// Returns the same `WriteOptions` but with the data section set
+    pub fn with_data_section(self, data_section: &'a DataSection) -> Self {
+        WriteOptions {
+            data_section: Some(data_section),
+            ..self
+        }
+    }
+
+    /// Retrieves the user-defined context, or `None` if there is no context or
+    /// it is not a `T`.
+    ///
+    /// Derived types can refer to the context from directives using the
+    /// [`context`](crate::attribute::write#context) directive.
+    pub fn context<T: Any>(&self) -> Option<&'a T> {
+        self.context?.downcast_ref()
+    }
+
+    /// Returns the same `WriteOptions` but with the context set
+    pub fn with_context<T: Any>(self, context: &'a T) -> Self {
+        WriteOptions {
+            context: Some(context),
+            ..self
+        }
     }
 }
 
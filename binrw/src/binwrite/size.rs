@@ -0,0 +1,330 @@
+use super::{BinWrite, WriteOptions};
+use crate::file_ptr::DataSection;
+use crate::{
+    encoding::Encoding,
+    io::{self, Seek, SeekFrom, Write},
+    prefixed::LengthPrefix,
+    punctuated::Punctuated,
+    BinResult, EncodedString, FixedString, FixedWideString, NullString, NullWideString, PosValue,
+    PrefixedString, PrefixedVec, RawString, SLeb128, ULeb128, ZigZag, ZigZagInt,
+};
+use core::{
+    cmp::Reverse,
+    marker::PhantomData,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
+        NonZeroU32, NonZeroU64, NonZeroU8, Wrapping,
+    },
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+
+/// The `BinSize` trait computes the number of bytes a [`BinWrite`] object
+/// writes, without writing it anywhere.
+///
+/// Types whose written size never changes report it in [`FIXED_SIZE`], which
+/// can be used in constant expressions. For every other type, the size is
+/// measured by writing the object to a sink which only counts bytes, so every
+/// directive used by the [`BinWrite`] implementation is taken into account.
+///
+/// Sizes are measured as if the object was written at the start of a stream,
+/// so objects which use alignment directives may take up a different number of
+/// bytes when written at some other position.
+///
+/// [`FIXED_SIZE`]: Self::FIXED_SIZE
+///
+/// # Derivable
+///
+/// This trait can be derived alongside [`BinWrite`]. The derived
+/// [`FIXED_SIZE`] is the sum of the sizes of all magic and written fields,
+/// and is `None` if any field uses a directive whose size depends on the value
+/// being written, like `count`, `map`, `write_with`, `bits`, or any of the
+/// padding, alignment, and seek directives. Enums only have a fixed size when
+/// every variant has the same fixed size. Every written field needs to
+/// implement `BinSize`.
+///
+/// When using the [`binwrite`](crate::binwrite) or [`binrw`](macro@crate::binrw)
+/// attribute macros, `#[derive(BinSize)]` must come after the attribute.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{binrw, BinSize};
+///
+/// #[binrw]
+/// #[derive(BinSize)]
+/// #[brw(big, magic = b"HDR")]
+/// struct Header {
+///     version: u16,
+///     flags: [u8; 3],
+/// }
+///
+/// #[binrw]
+/// #[derive(BinSize)]
+/// #[brw(big)]
+/// struct Message {
+///     #[bw(calc = data.len() as u16)]
+///     len: u16,
+///     #[br(count = len)]
+///     #[brw(align_after = 4)]
+///     data: Vec<u8>,
+/// }
+///
+/// const HEADER_SIZE: Option<u64> = Header::FIXED_SIZE;
+/// assert_eq!(HEADER_SIZE, Some(8));
+///
+/// let message = Message { data: vec![1, 2, 3] };
+/// assert_eq!(Message::FIXED_SIZE, None);
+/// assert_eq!(message.bin_size().unwrap(), 8);
+/// ```
+pub trait BinSize: BinWrite {
+    /// The number of bytes written for every object of this type, or `None` if
+    /// the size depends on the object or on the arguments used to write it.
+    const FIXED_SIZE: Option<u64> = None;
+
+    /// Returns the number of bytes written using the default [`WriteOptions`]
+    /// and arguments.
+    fn bin_size(&self) -> BinResult<u64>
+    where
+        Self::Args: Default,
+    {
+        self.bin_size_options(&WriteOptions::default(), Self::Args::default())
+    }
+
+    /// Returns the number of bytes written using the given [`WriteOptions`]
+    /// and arguments.
+    ///
+    /// # Errors
+    ///
+    /// If writing the object fails, an [`Error`](crate::Error) variant will be
+    /// returned.
+    fn bin_size_options(&self, options: &WriteOptions<'_>, args: Self::Args) -> BinResult<u64> {
+        match Self::FIXED_SIZE {
+            Some(size) => Ok(size),
+            None => {
+                let mut counter = SizeCounter::default();
+                self.write_options(&mut counter, options, args)?;
+                Ok(counter.len)
+            }
+        }
+    }
+}
+
+/// Returns the number of bytes written for `value` when writing it at `pos`,
+/// not counting values written into a [`DataSection`].
+pub(crate) fn in_place_size<T: BinWrite + ?Sized>(
+    value: &T,
+    pos: u64,
+    options: &WriteOptions<'_>,
+    args: T::Args,
+) -> BinResult<u64> {
+    let data_section = DataSection::measuring();
+    let options = &options.with_data_section(&data_section);
+
+    let mut counter = SizeCounter { pos, len: pos };
+    value.write_options(&mut counter, options, args)?;
+    Ok(counter.pos - pos)
+}
+
+/// A writer which discards everything written to it, keeping only the length
+/// of the stream.
+#[derive(Default)]
+struct SizeCounter {
+    pos: u64,
+    len: u64,
+}
+
+impl Write for SizeCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pos += buf.len() as u64;
+        self.len = self.len.max(self.pos);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for SizeCounter {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => Some(pos),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        };
+
+        match pos {
+            Some(pos) => {
+                self.pos = pos;
+                Ok(pos)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+/// Adds up fixed sizes, returning `None` if any of them is not fixed.
+pub const fn sum_fixed_sizes(sizes: &[Option<u64>]) -> Option<u64> {
+    let mut total = 0;
+    let mut i = 0;
+    while i < sizes.len() {
+        match sizes[i] {
+            Some(size) => total += size,
+            None => return None,
+        }
+        i += 1;
+    }
+    Some(total)
+}
+
+/// Returns the fixed size shared by all of the given sizes, or `None` if they
+/// differ or any of them is not fixed.
+pub const fn same_fixed_size(sizes: &[Option<u64>]) -> Option<u64> {
+    let first = match sizes.first() {
+        Some(Some(size)) => *size,
+        _ => return None,
+    };
+    let mut i = 1;
+    while i < sizes.len() {
+        match sizes[i] {
+            Some(size) if size == first => {}
+            _ => return None,
+        }
+        i += 1;
+    }
+    Some(first)
+}
+
+macro_rules! bin_size_num_impl {
+    ($($type_name:ty),*$(,)?) => {
+        $(
+            impl BinSize for $type_name {
+                const FIXED_SIZE: Option<u64> = Some(core::mem::size_of::<$type_name>() as u64);
+            }
+        )*
+    };
+}
+
+bin_size_num_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, char);
+
+bin_size_num_impl!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+);
+
+impl BinSize for bool {}
+
+impl<T: BinSize> BinSize for Wrapping<T> {
+    const FIXED_SIZE: Option<u64> = T::FIXED_SIZE;
+}
+
+impl<T: BinSize> BinSize for Reverse<T> {
+    const FIXED_SIZE: Option<u64> = T::FIXED_SIZE;
+}
+
+impl BinSize for core::net::Ipv4Addr {
+    const FIXED_SIZE: Option<u64> = Some(4);
+}
+
+impl BinSize for core::net::Ipv6Addr {
+    const FIXED_SIZE: Option<u64> = Some(16);
+}
+
+impl<T: BinSize + 'static, const N: usize> BinSize for [T; N] {
+    const FIXED_SIZE: Option<u64> = match T::FIXED_SIZE {
+        Some(size) => Some(size * N as u64),
+        None => None,
+    };
+}
+
+impl<T: BinSize> BinSize for [T] {}
+
+impl<T: BinSize + 'static> BinSize for Vec<T> {}
+
+impl<T: BinSize + ?Sized> BinSize for &T {
+    const FIXED_SIZE: Option<u64> = T::FIXED_SIZE;
+}
+
+impl<T: BinSize + 'static> BinSize for Box<T> {
+    const FIXED_SIZE: Option<u64> = T::FIXED_SIZE;
+}
+
+impl<T: BinSize> BinSize for Option<T> {}
+
+impl<T: BinWrite> BinSize for PhantomData<T> {
+    const FIXED_SIZE: Option<u64> = Some(0);
+}
+
+impl BinSize for () {
+    const FIXED_SIZE: Option<u64> = Some(0);
+}
+
+impl BinSize for NullString {}
+
+impl BinSize for NullWideString {}
+
+impl<const N: usize> BinSize for FixedString<N> {
+    const FIXED_SIZE: Option<u64> = Some(N as u64);
+}
+
+impl<const N: usize> BinSize for FixedWideString<N> {
+    const FIXED_SIZE: Option<u64> = Some(N as u64 * 2);
+}
+
+impl<T: BinSize> BinSize for PosValue<T> {
+    const FIXED_SIZE: Option<u64> = T::FIXED_SIZE;
+}
+
+impl<T: BinSize, P: BinSize + BinWrite<Args = ()>> BinSize for Punctuated<T, P> {}
+
+impl<L: LengthPrefix> BinSize for PrefixedString<L> {}
+
+impl<S, E> BinSize for EncodedString<S, E>
+where
+    S: BinSize + RawString,
+    E: Encoding<Unit = S::Unit>,
+{
+    const FIXED_SIZE: Option<u64> = S::FIXED_SIZE;
+}
+
+impl<L: LengthPrefix, T: BinSize + 'static> BinSize for PrefixedVec<L, T> {}
+
+impl BinSize for ULeb128 {}
+
+impl BinSize for SLeb128 {}
+
+impl<T: ZigZagInt> BinSize for ZigZag<T> {}
+
+macro_rules! bin_size_tuple_impl {
+    ($type1:ident $(, $types:ident)*) => {
+        #[allow(non_camel_case_types)]
+        impl<
+            $type1: BinSize<Args=()>, $($types: BinSize<Args=()>),*
+        > BinSize for ($type1, $($types),*) {
+            const FIXED_SIZE: Option<u64> =
+                sum_fixed_sizes(&[$type1::FIXED_SIZE, $($types::FIXED_SIZE),*]);
+        }
+
+        bin_size_tuple_impl!($($types),*);
+    };
+
+    () => {};
+}
+
+bin_size_tuple_impl!(
+    b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15, b16, b17, b18, b19, b20, b21,
+    b22, b23, b24, b25, b26, b27, b28, b29, b30, b31, b32
+);
@@ -0,0 +1,240 @@
+//! Fast paths for reading and writing many primitive numbers at once.
+//!
+//! Collections of primitive numbers are read and written as a single block of
+//! bytes which is then byte-swapped in place, instead of one element at a
+//! time. Since stable Rust does not have specialization, the collection impls
+//! check whether their element type is a primitive number with [`Any`] and
+//! fall back to their usual per-element path otherwise.
+
+use crate::{
+    io::{self, Read, Seek, Write},
+    BinResult, Endian, Error,
+};
+use core::any::{Any, TypeId};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// The number of elements which are byte-swapped at a time when writing.
+const SWAP_CHUNK_LEN: usize = 0x400;
+
+/// The maximum number of bytes which are allocated ahead of the data being
+/// read, so a large count from untrusted input fails at the end of the data
+/// instead of exhausting memory first.
+const READ_CHUNK_BYTES: usize = 0x1_0000;
+
+/// A primitive number type which has no padding and is valid for any bit
+/// pattern.
+trait Primitive: Copy + Default + 'static {
+    fn reverse_bytes(self) -> Self;
+}
+
+macro_rules! primitive_int_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl Primitive for $ty {
+                fn reverse_bytes(self) -> Self {
+                    self.swap_bytes()
+                }
+            }
+        )*
+    };
+}
+
+primitive_int_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+macro_rules! primitive_float_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl Primitive for $ty {
+                fn reverse_bytes(self) -> Self {
+                    Self::from_bits(self.to_bits().swap_bytes())
+                }
+            }
+        )*
+    };
+}
+
+primitive_float_impl!(f32, f64);
+
+/// Invokes the macro `$m` with the list of primitive number types.
+macro_rules! for_each_primitive {
+    ($m:ident) => {
+        $m!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64)
+    };
+}
+
+/// Reads `count` elements into `list` at once if it is a `Vec` of a primitive
+/// number type, or returns `None` if it is not.
+pub(crate) fn read_vec<R: Read + Seek>(
+    reader: &mut R,
+    endian: Endian,
+    count: usize,
+    list: &mut dyn Any,
+) -> Option<BinResult<()>> {
+    macro_rules! try_types {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(list) = list.downcast_mut::<Vec<$ty>>() {
+                    return Some(read_primitives(list, reader, endian, count));
+                }
+            )*
+        };
+    }
+
+    for_each_primitive!(try_types);
+    None
+}
+
+/// Writes all elements of `list` at once if it is a `Vec` of a primitive
+/// number type, or returns `None` if it is not.
+pub(crate) fn write_vec<W: Write + Seek>(
+    writer: &mut W,
+    endian: Endian,
+    list: &dyn Any,
+) -> Option<BinResult<()>> {
+    macro_rules! try_types {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(list) = list.downcast_ref::<Vec<$ty>>() {
+                    return Some(write_primitives(list, writer, endian));
+                }
+            )*
+        };
+    }
+
+    for_each_primitive!(try_types);
+    None
+}
+
+/// Writes all elements of `array` at once if it is an array of a primitive
+/// number type, or returns `None` if it is not.
+pub(crate) fn write_array<W: Write + Seek, const N: usize>(
+    writer: &mut W,
+    endian: Endian,
+    array: &dyn Any,
+) -> Option<BinResult<()>> {
+    macro_rules! try_types {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(array) = array.downcast_ref::<[$ty; N]>() {
+                    return Some(write_primitives(array, writer, endian));
+                }
+            )*
+        };
+    }
+
+    for_each_primitive!(try_types);
+    None
+}
+
+/// Returns the size of `T` if it is a primitive number type.
+pub(crate) fn primitive_size<T: 'static>() -> Option<usize> {
+    macro_rules! try_types {
+        ($($ty:ty),*) => {
+            $(
+                if TypeId::of::<T>() == TypeId::of::<$ty>() {
+                    return Some(core::mem::size_of::<$ty>());
+                }
+            )*
+        };
+    }
+
+    for_each_primitive!(try_types);
+    None
+}
+
+fn needs_swap(endian: Endian) -> bool {
+    match endian {
+        Endian::Big => cfg!(target_endian = "little"),
+        Endian::Little => cfg!(target_endian = "big"),
+        Endian::Native => false,
+    }
+}
+
+fn read_primitives<T: Primitive, R: Read + Seek>(
+    list: &mut Vec<T>,
+    reader: &mut R,
+    endian: Endian,
+    count: usize,
+) -> BinResult<()> {
+    let not_enough_bytes = || {
+        Error::Io(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "not enough bytes in reader",
+        ))
+    };
+
+    // A count this large can never be satisfied, so fail before reading
+    if count.checked_mul(core::mem::size_of::<T>()).is_none() {
+        return Err(not_enough_bytes());
+    }
+
+    let start = list.len();
+
+    let chunk_len = (READ_CHUNK_BYTES / core::mem::size_of::<T>()).max(1);
+    let mut remaining = count;
+    while remaining != 0 {
+        let chunk_start = list.len();
+        let len = remaining.min(chunk_len);
+        list.resize(chunk_start + len, T::default());
+
+        let values = &mut list[chunk_start..];
+        // SAFETY: `Primitive` types have no padding and are valid for any bit
+        // pattern, and the byte length is that of exactly `values`
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(
+                values.as_mut_ptr().cast::<u8>(),
+                core::mem::size_of_val(values),
+            )
+        };
+        if let Err(error) = reader.read_exact(bytes) {
+            list.truncate(start);
+            return Err(if error.kind() == io::ErrorKind::UnexpectedEof {
+                not_enough_bytes()
+            } else {
+                error.into()
+            });
+        }
+
+        if needs_swap(endian) {
+            for value in values {
+                *value = value.reverse_bytes();
+            }
+        }
+
+        remaining -= len;
+    }
+
+    Ok(())
+}
+
+fn write_primitives<T: Primitive, W: Write + Seek>(
+    list: &[T],
+    writer: &mut W,
+    endian: Endian,
+) -> BinResult<()> {
+    fn as_bytes<T: Primitive>(values: &[T]) -> &[u8] {
+        // SAFETY: `Primitive` types have no padding, so every byte of `values`
+        // is initialised
+        unsafe {
+            core::slice::from_raw_parts(
+                values.as_ptr().cast::<u8>(),
+                core::mem::size_of_val(values),
+            )
+        }
+    }
+
+    if needs_swap(endian) {
+        let mut swapped = Vec::with_capacity(list.len().min(SWAP_CHUNK_LEN));
+        for chunk in list.chunks(SWAP_CHUNK_LEN) {
+            swapped.clear();
+            swapped.extend(chunk.iter().map(|value| value.reverse_bytes()));
+            writer.write_all(as_bytes(&swapped))?;
+        }
+    } else {
+        writer.write_all(as_bytes(list))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! Checksum algorithms for the [`checksum`](crate::attribute::read#checksums)
+//! directive.
+//!
+//! A [`Checksum`] is an incremental digest: it is fed the bytes covered by the
+//! checksum with [`update`](Checksum::update), and then produces its result
+//! with [`finish`](Checksum::finish). Any type implementing the trait can be
+//! used with the `checksum` directive, so formats with unusual checksums only
+//! need to implement it for their own algorithm.
+//!
+//! # Examples
+//!
+//! ```
+//! use binrw::checksum::{Checksum, Crc32};
+//!
+//! let mut crc = Crc32::default();
+//! crc.update(b"1234");
+//! crc.update(b"56789");
+//! assert_eq!(crc.finish(), 0xcbf4_3926);
+//! assert_eq!(Crc32::checksum(b"123456789"), 0xcbf4_3926);
+//! ```
+
+/// An incremental checksum algorithm.
+pub trait Checksum: Default {
+    /// The type of the finished checksum.
+    ///
+    /// This must be the same as the type of the field which stores the
+    /// checksum.
+    type Output: Copy + PartialEq + Into<u64>;
+
+    /// Adds `data` to the checksum.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the checksum of all data added so far.
+    fn finish(&self) -> Self::Output;
+
+    /// Returns the checksum of `data`.
+    fn checksum(data: &[u8]) -> Self::Output {
+        let mut checksum = Self::default();
+        checksum.update(data);
+        checksum.finish()
+    }
+}
+
+/// The CRC-16/ARC checksum, also known as CRC-16/IBM.
+///
+/// This uses the reflected polynomial `0xa001` with no initial value or final
+/// XOR.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Crc16(u16);
+
+const CRC16_TABLE: [u16; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < table.len() {
+        let mut crc = i as u16;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ 0xa001
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+impl Checksum for Crc16 {
+    type Output = u16;
+
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.0 = (self.0 >> 8) ^ CRC16_TABLE[usize::from(self.0 as u8 ^ byte)];
+        }
+    }
+
+    fn finish(&self) -> Self::Output {
+        self.0
+    }
+}
+
+/// The CRC-32 checksum used by zlib, PNG, and Ethernet.
+///
+/// This uses the reflected polynomial `0xedb88320` with an initial value and
+/// final XOR of `0xffffffff`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Crc32(u32);
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < table.len() {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ 0xedb8_8320
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self(0xffff_ffff)
+    }
+}
+
+impl Checksum for Crc32 {
+    type Output = u32;
+
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.0 = (self.0 >> 8) ^ CRC32_TABLE[usize::from(self.0 as u8 ^ byte)];
+        }
+    }
+
+    fn finish(&self) -> Self::Output {
+        !self.0
+    }
+}
+
+/// The Adler-32 checksum used by zlib.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    const MOD: u32 = 65521;
+
+    // The largest number of bytes which can be summed before `b` can overflow
+    const CHUNK_SIZE: usize = 5552;
+}
+
+impl Default for Adler32 {
+    fn default() -> Self {
+        Self { a: 1, b: 0 }
+    }
+}
+
+impl Checksum for Adler32 {
+    type Output = u32;
+
+    fn update(&mut self, data: &[u8]) {
+        for chunk in data.chunks(Self::CHUNK_SIZE) {
+            for &byte in chunk {
+                self.a += u32::from(byte);
+                self.b += self.a;
+            }
+            self.a %= Self::MOD;
+            self.b %= Self::MOD;
+        }
+    }
+
+    fn finish(&self) -> Self::Output {
+        (self.b << 16) | self.a
+    }
+}
+
+/// An 8-bit checksum which adds every byte together, ignoring overflow.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Sum8(u8);
+
+impl Checksum for Sum8 {
+    type Output = u8;
+
+    fn update(&mut self, data: &[u8]) {
+        self.0 = data
+            .iter()
+            .fold(self.0, |sum, &byte| sum.wrapping_add(byte));
+    }
+
+    fn finish(&self) -> Self::Output {
+        self.0
+    }
+}
+
+/// An 8-bit checksum which XORs every byte together.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Xor8(u8);
+
+impl Checksum for Xor8 {
+    type Output = u8;
+
+    fn update(&mut self, data: &[u8]) {
+        self.0 = data.iter().fold(self.0, |sum, &byte| sum ^ byte);
+    }
+
+    fn finish(&self) -> Self::Output {
+        self.0
+    }
+}
@@ -0,0 +1,360 @@
+//! Character encodings for reading and writing strings as [`String`]s.
+//!
+//! An [`Encoding`] converts between a [`String`] and the code units stored by
+//! one of the raw string types, like [`NullString`](crate::NullString) or
+//! [`FixedWideString`](crate::FixedWideString). Encodings are used through
+//! [`EncodedString`](crate::EncodedString), which combines a raw string type,
+//! which decides how the string is stored in the stream, with an encoding,
+//! which decides what the stored code units mean.
+//!
+//! The [`Utf8`], [`Latin1`], [`Windows1252`], [`Utf16`], [`Utf16Le`], and
+//! [`Utf16Be`] encodings are always available. The `ShiftJis` encoding
+//! requires the `shift_jis` feature.
+//!
+//! # Errors
+//!
+//! With [`ErrorMode::Strict`], which is the default, data which is not valid
+//! in its encoding and strings which cannot be represented in it return a
+//! [`BadEncoding`](crate::Error::BadEncoding) error. With
+//! [`ErrorMode::Replace`], invalid data is decoded as U+FFFD REPLACEMENT
+//! CHARACTER, and characters which cannot be encoded are written as `?`.
+//!
+//! # Examples
+//!
+//! ```
+//! use binrw::{
+//!     encoding::{Latin1, Utf16Le},
+//!     BinReaderExt, EncodedString, FixedString, NullWideString, io::Cursor,
+//! };
+//!
+//! let mut data = Cursor::new(b"caf\xe9\0\0\0\0\xac\x20\0\0");
+//! let name: EncodedString<FixedString<8>, Latin1> = data.read_be().unwrap();
+//! let price: EncodedString<NullWideString, Utf16Le> = data.read_be().unwrap();
+//! assert_eq!(*name, "café");
+//! assert_eq!(*price, "€");
+//! ```
+
+use crate::Endian;
+use core::{char::REPLACEMENT_CHARACTER, convert::TryFrom};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// How characters which cannot be decoded or encoded are handled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorMode {
+    /// Return an error.
+    Strict,
+
+    /// Decode invalid data as U+FFFD REPLACEMENT CHARACTER, and encode
+    /// characters which cannot be represented as `?`.
+    Replace,
+}
+
+/// A character encoding which converts between strings and code units.
+pub trait Encoding: 'static {
+    /// The type of the code units of this encoding, which is `u16` for UTF-16
+    /// and `u8` for every other encoding.
+    type Unit: Copy + 'static;
+
+    /// The name of this encoding, used in error messages.
+    const NAME: &'static str;
+
+    /// Decodes code units into a string.
+    ///
+    /// `endian` is the byte order the code units were read with. If `bom` is
+    /// true, a leading byte order mark is removed and, for UTF-16, decides the
+    /// byte order of the rest of the string.
+    ///
+    /// Returns `None` if the code units are invalid and `errors` is
+    /// [`ErrorMode::Strict`].
+    fn decode(units: &[Self::Unit], endian: Endian, bom: bool, errors: ErrorMode)
+        -> Option<String>;
+
+    /// Encodes a string into code units.
+    ///
+    /// `endian` is the byte order the code units will be written with. If
+    /// `bom` is true, a byte order mark is written before the string.
+    ///
+    /// Returns `None` if the string cannot be represented in this encoding
+    /// and `errors` is [`ErrorMode::Strict`].
+    fn encode(s: &str, endian: Endian, bom: bool, errors: ErrorMode) -> Option<Vec<Self::Unit>>;
+}
+
+/// Encodes every character of `s` with `encode_char`, which returns `None`
+/// for characters that cannot be represented.
+fn encode_bytes(
+    s: &str,
+    errors: ErrorMode,
+    encode_char: impl Fn(char) -> Option<u8>,
+) -> Option<Vec<u8>> {
+    s.chars()
+        .map(|c| match (encode_char(c), errors) {
+            (Some(byte), _) => Some(byte),
+            (None, ErrorMode::Strict) => None,
+            (None, ErrorMode::Replace) => Some(b'?'),
+        })
+        .collect()
+}
+
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// The UTF-8 encoding.
+///
+/// A byte order mark is the bytes `EF BB BF`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Utf8;
+
+impl Encoding for Utf8 {
+    type Unit = u8;
+    const NAME: &'static str = "UTF-8";
+
+    fn decode(units: &[u8], _: Endian, bom: bool, errors: ErrorMode) -> Option<String> {
+        let units = match units.strip_prefix(UTF8_BOM) {
+            Some(rest) if bom => rest,
+            _ => units,
+        };
+
+        match errors {
+            ErrorMode::Strict => core::str::from_utf8(units).ok().map(Into::into),
+            ErrorMode::Replace => Some(String::from_utf8_lossy(units).into()),
+        }
+    }
+
+    fn encode(s: &str, _: Endian, bom: bool, _: ErrorMode) -> Option<Vec<u8>> {
+        let mut units = Vec::with_capacity(s.len() + 3);
+        if bom {
+            units.extend_from_slice(UTF8_BOM);
+        }
+        units.extend_from_slice(s.as_bytes());
+        Some(units)
+    }
+}
+
+/// The ISO-8859-1 (Latin-1) encoding, which maps each byte to the Unicode
+/// character with the same value.
+///
+/// Latin-1 has no byte order mark.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Latin1;
+
+impl Encoding for Latin1 {
+    type Unit = u8;
+    const NAME: &'static str = "Latin-1";
+
+    fn decode(units: &[u8], _: Endian, _: bool, _: ErrorMode) -> Option<String> {
+        Some(units.iter().map(|&byte| char::from(byte)).collect())
+    }
+
+    fn encode(s: &str, _: Endian, _: bool, errors: ErrorMode) -> Option<Vec<u8>> {
+        encode_bytes(s, errors, |c| u8::try_from(u32::from(c)).ok())
+    }
+}
+
+/// The characters of Windows-1252 from 0x80 to 0x9F. Bytes which are not
+/// assigned in Windows-1252 decode to the C1 control character with the same
+/// value, like in the WHATWG Encoding Standard.
+const WINDOWS_1252: [char; 32] = [
+    '\u{20ac}', '\u{81}', '\u{201a}', '\u{192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2c6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8d}', '\u{17d}', '\u{8f}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2dc}', '\u{2122}', '\u{161}', '\u{203a}', '\u{153}', '\u{9d}', '\u{17e}', '\u{178}',
+];
+
+/// The Windows-1252 encoding, which is Latin-1 with extra printable
+/// characters from 0x80 to 0x9F.
+///
+/// Windows-1252 has no byte order mark.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Windows1252;
+
+impl Encoding for Windows1252 {
+    type Unit = u8;
+    const NAME: &'static str = "Windows-1252";
+
+    fn decode(units: &[u8], _: Endian, _: bool, _: ErrorMode) -> Option<String> {
+        Some(
+            units
+                .iter()
+                .map(|&byte| match byte {
+                    0x80..=0x9f => WINDOWS_1252[usize::from(byte - 0x80)],
+                    _ => char::from(byte),
+                })
+                .collect(),
+        )
+    }
+
+    fn encode(s: &str, _: Endian, _: bool, errors: ErrorMode) -> Option<Vec<u8>> {
+        encode_bytes(s, errors, |c| match u32::from(c) {
+            0..=0x7f | 0xa0..=0xff => Some(u32::from(c) as u8),
+            _ => WINDOWS_1252
+                .iter()
+                .position(|&other| other == c)
+                .map(|i| 0x80 + i as u8),
+        })
+    }
+}
+
+const UTF16_BOM: u16 = 0xfeff;
+
+fn is_little(endian: Endian) -> bool {
+    match endian {
+        Endian::Big => false,
+        Endian::Little => true,
+        Endian::Native => cfg!(target_endian = "little"),
+    }
+}
+
+/// Decodes UTF-16 code units which were read with byte order `endian` and are
+/// stored in byte order `text`.
+fn decode_utf16(
+    units: &[u16],
+    endian: Endian,
+    text: Endian,
+    bom: bool,
+    errors: ErrorMode,
+) -> Option<String> {
+    let (units, text) = match units.split_first() {
+        Some((&first, rest)) if bom => {
+            let bytes = match endian {
+                Endian::Big => first.to_be_bytes(),
+                Endian::Little => first.to_le_bytes(),
+                Endian::Native => first.to_ne_bytes(),
+            };
+            match Endian::from_utf16_bom_bytes(bytes) {
+                Ok(text) => (rest, text),
+                Err(_) => (units, text),
+            }
+        }
+        _ => (units, text),
+    };
+
+    let swap = is_little(endian) != is_little(text);
+    let chars =
+        core::char::decode_utf16(
+            units
+                .iter()
+                .map(|&unit| if swap { unit.swap_bytes() } else { unit }),
+        );
+    match errors {
+        ErrorMode::Strict => chars.collect::<Result<_, _>>().ok(),
+        ErrorMode::Replace => Some(chars.map(|c| c.unwrap_or(REPLACEMENT_CHARACTER)).collect()),
+    }
+}
+
+/// Encodes a string as UTF-16 code units which will be written with byte
+/// order `endian` and should be stored in byte order `text`.
+fn encode_utf16(s: &str, endian: Endian, text: Endian, bom: bool) -> Vec<u16> {
+    let swap = is_little(endian) != is_little(text);
+    let bom = if bom { Some(UTF16_BOM) } else { None };
+    bom.into_iter()
+        .chain(s.encode_utf16())
+        .map(|unit| if swap { unit.swap_bytes() } else { unit })
+        .collect()
+}
+
+/// The UTF-16 encoding, in the byte order of the field.
+///
+/// If a byte order mark is enabled and present when reading, it decides the
+/// byte order of the string instead.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Utf16;
+
+impl Encoding for Utf16 {
+    type Unit = u16;
+    const NAME: &'static str = "UTF-16";
+
+    fn decode(units: &[u16], endian: Endian, bom: bool, errors: ErrorMode) -> Option<String> {
+        decode_utf16(units, endian, endian, bom, errors)
+    }
+
+    fn encode(s: &str, endian: Endian, bom: bool, _: ErrorMode) -> Option<Vec<u16>> {
+        Some(encode_utf16(s, endian, endian, bom))
+    }
+}
+
+/// The UTF-16LE encoding, which is always little-endian regardless of the byte
+/// order of the field.
+///
+/// If a byte order mark is enabled and present when reading, it decides the
+/// byte order of the string instead.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Utf16Le;
+
+impl Encoding for Utf16Le {
+    type Unit = u16;
+    const NAME: &'static str = "UTF-16LE";
+
+    fn decode(units: &[u16], endian: Endian, bom: bool, errors: ErrorMode) -> Option<String> {
+        decode_utf16(units, endian, Endian::Little, bom, errors)
+    }
+
+    fn encode(s: &str, endian: Endian, bom: bool, _: ErrorMode) -> Option<Vec<u16>> {
+        Some(encode_utf16(s, endian, Endian::Little, bom))
+    }
+}
+
+/// The UTF-16BE encoding, which is always big-endian regardless of the byte
+/// order of the field.
+///
+/// If a byte order mark is enabled and present when reading, it decides the
+/// byte order of the string instead.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Utf16Be;
+
+impl Encoding for Utf16Be {
+    type Unit = u16;
+    const NAME: &'static str = "UTF-16BE";
+
+    fn decode(units: &[u16], endian: Endian, bom: bool, errors: ErrorMode) -> Option<String> {
+        decode_utf16(units, endian, Endian::Big, bom, errors)
+    }
+
+    fn encode(s: &str, endian: Endian, bom: bool, _: ErrorMode) -> Option<Vec<u16>> {
+        Some(encode_utf16(s, endian, Endian::Big, bom))
+    }
+}
+
+/// The Shift_JIS encoding, as defined by the WHATWG Encoding Standard.
+///
+/// Shift_JIS has no byte order mark.
+///
+/// This requires the `shift_jis` feature.
+#[cfg(feature = "shift_jis")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ShiftJis;
+
+#[cfg(feature = "shift_jis")]
+impl Encoding for ShiftJis {
+    type Unit = u8;
+    const NAME: &'static str = "Shift_JIS";
+
+    fn decode(units: &[u8], _: Endian, _: bool, errors: ErrorMode) -> Option<String> {
+        let encoding = encoding_rs::SHIFT_JIS;
+        match errors {
+            ErrorMode::Strict => encoding
+                .decode_without_bom_handling_and_without_replacement(units)
+                .map(Into::into),
+            ErrorMode::Replace => Some(encoding.decode_without_bom_handling(units).0.into()),
+        }
+    }
+
+    fn encode(s: &str, _: Endian, _: bool, errors: ErrorMode) -> Option<Vec<u8>> {
+        use encoding_rs::EncoderResult;
+
+        let mut encoder = encoding_rs::SHIFT_JIS.new_encoder();
+        let mut units = Vec::with_capacity(s.len());
+        let mut s = s;
+        loop {
+            let (result, read) =
+                encoder.encode_from_utf8_to_vec_without_replacement(s, &mut units, true);
+            s = &s[read..];
+            match (result, errors) {
+                (EncoderResult::InputEmpty, _) => return Some(units),
+                (EncoderResult::Unmappable(_), ErrorMode::Strict) => return None,
+                (EncoderResult::Unmappable(_), ErrorMode::Replace) => units.push(b'?'),
+                (EncoderResult::OutputFull, _) => units.reserve(s.len() * 2 + 2),
+            }
+        }
+    }
+}
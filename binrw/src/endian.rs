@@ -1,7 +1,8 @@
 //! Type definitions for byte order handling.
 
 use crate::alloc::boxed::Box;
-use crate::BinResult;
+use crate::io::{Read, Seek, Write};
+use crate::{BinRead, BinResult, BinSize, BinWrite, ReadOptions, Unaligned, WriteOptions};
 
 /// Defines the order of bytes in a multi-byte type.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -58,3 +59,113 @@ impl Endian {
         }
     }
 }
+
+macro_rules! fixed_endian_impl {
+    ($($name:ident($type_name:ty, $endian:literal, $from_bytes:ident, $to_bytes:ident)),*$(,)?) => {
+        $(
+            #[doc = concat!(
+                "A `", stringify!($type_name), "` which is always stored in ", $endian,
+                " byte order and has an alignment of 1.\n\n",
+                "This type can be borrowed directly from a byte slice by ",
+                "[`BorrowedBinRead`](crate::BorrowedBinRead), and is read and written in ",
+                $endian, " byte order regardless of the byte order in the options."
+            )]
+            #[derive(Clone, Copy, Default)]
+            #[repr(transparent)]
+            pub struct $name([u8; core::mem::size_of::<$type_name>()]);
+
+            impl $name {
+                /// Creates a new value.
+                pub fn new(value: $type_name) -> Self {
+                    Self(value.$to_bytes())
+                }
+
+                /// Returns the value.
+                pub fn get(self) -> $type_name {
+                    <$type_name>::$from_bytes(self.0)
+                }
+            }
+
+            impl From<$type_name> for $name {
+                fn from(value: $type_name) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl From<$name> for $type_name {
+                fn from(value: $name) -> Self {
+                    value.get()
+                }
+            }
+
+            impl PartialEq for $name {
+                fn eq(&self, other: &Self) -> bool {
+                    self.get() == other.get()
+                }
+            }
+
+            impl core::fmt::Debug for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Debug::fmt(&self.get(), f)
+                }
+            }
+
+            // SAFETY: A byte array has an alignment of 1, no padding, and every
+            // bit pattern of its bytes is a valid number.
+            unsafe impl Unaligned for $name {}
+
+            impl BinRead for $name {
+                type Args = ();
+
+                fn read_options<R: Read + Seek>(
+                    reader: &mut R,
+                    options: &ReadOptions<'_>,
+                    _: Self::Args,
+                ) -> BinResult<Self> {
+                    <[u8; core::mem::size_of::<$type_name>()]>::read_options(reader, options, ())
+                        .map(Self)
+                }
+            }
+
+            impl BinWrite for $name {
+                type Args = ();
+
+                fn write_options<W: Write + Seek>(
+                    &self,
+                    writer: &mut W,
+                    _: &WriteOptions<'_>,
+                    _: Self::Args,
+                ) -> BinResult<()> {
+                    writer.write_all(&self.0).map_err(Into::into)
+                }
+            }
+
+            impl BinSize for $name {
+                const FIXED_SIZE: Option<u64> = Some(core::mem::size_of::<$type_name>() as u64);
+            }
+        )*
+    };
+}
+
+fixed_endian_impl!(
+    U16Le(u16, "little-endian", from_le_bytes, to_le_bytes),
+    U16Be(u16, "big-endian", from_be_bytes, to_be_bytes),
+    U32Le(u32, "little-endian", from_le_bytes, to_le_bytes),
+    U32Be(u32, "big-endian", from_be_bytes, to_be_bytes),
+    U64Le(u64, "little-endian", from_le_bytes, to_le_bytes),
+    U64Be(u64, "big-endian", from_be_bytes, to_be_bytes),
+    U128Le(u128, "little-endian", from_le_bytes, to_le_bytes),
+    U128Be(u128, "big-endian", from_be_bytes, to_be_bytes),
+    I16Le(i16, "little-endian", from_le_bytes, to_le_bytes),
+    I16Be(i16, "big-endian", from_be_bytes, to_be_bytes),
+    I32Le(i32, "little-endian", from_le_bytes, to_le_bytes),
+    I32Be(i32, "big-endian", from_be_bytes, to_be_bytes),
+    I64Le(i64, "little-endian", from_le_bytes, to_le_bytes),
+    I64Be(i64, "big-endian", from_be_bytes, to_be_bytes),
+    I128Le(i128, "little-endian", from_le_bytes, to_le_bytes),
+    I128Be(i128, "big-endian", from_be_bytes, to_be_bytes),
+    F32Le(f32, "little-endian", from_le_bytes, to_le_bytes),
+    F32Be(f32, "big-endian", from_be_bytes, to_be_bytes),
+    F64Le(f64, "little-endian", from_le_bytes, to_le_bytes),
+    F64Be(f64, "big-endian", from_be_bytes, to_be_bytes),
+);
@@ -169,6 +169,81 @@ pub enum Error {
         variant_errors: Vec<(&'static str, Error)>,
     },
 
+    /// A [`context`](crate::attribute::read#context) directive needed a context of
+    /// a type which was not given in the read or write options.
+    MissingContext {
+        /// The byte position of the start of the object which needed the
+        /// context.
+        pos: u64,
+
+        /// The name of the type of the missing context.
+        type_name: &'static str,
+    },
+
+    /// A [`backpatch`](crate::attribute::write#backpatch) value was a
+    /// different size than the placeholder it replaced.
+    BackpatchSize {
+        /// The byte position of the placeholder in the writer.
+        pos: u64,
+
+        /// The number of bytes written for the placeholder.
+        reserved: u64,
+
+        /// The number of bytes written for the backpatched value.
+        written: u64,
+    },
+
+    /// A [`FilePtr`](crate::FilePtr) with a value was written without a
+    /// [`DataSection`](crate::file_ptr::DataSection) to write its value into.
+    MissingDataSection {
+        /// The byte position of the pointer in the writer.
+        pos: u64,
+    },
+
+    /// The offset to the value of a [`FilePtr`](crate::FilePtr) did not fit
+    /// in its pointer type.
+    PointerOverflow {
+        /// The byte position of the pointer in the writer.
+        pos: u64,
+
+        /// The byte position the value was written at.
+        value_pos: u64,
+    },
+
+    /// A [`checksum`](crate::attribute::read#checksums) stored in the data did
+    /// not match the checksum of the bytes it covers.
+    BadChecksum {
+        /// The byte position of the stored checksum in the reader.
+        pos: u64,
+
+        /// The checksum of the bytes covered by the stored checksum.
+        expected: u64,
+
+        /// The checksum which was actually read.
+        found: u64,
+    },
+
+    /// A field needed more bytes than were given to it by a
+    /// [`size`](crate::attribute::read#size) directive, or a
+    /// [`FixedString`](crate::FixedString) was too long to write.
+    SizeExceeded {
+        /// The byte position of the start of the field.
+        pos: u64,
+
+        /// The number of bytes given to the field.
+        size: u64,
+    },
+
+    /// A string could not be decoded from or encoded to its
+    /// [character encoding](crate::encoding).
+    BadEncoding {
+        /// The byte position of the start of the string.
+        pos: u64,
+
+        /// The name of the character encoding.
+        encoding: &'static str,
+    },
+
     /// An error with additional frames of context used to construct a backtrace
     Backtrace(Backtrace),
 }
@@ -224,6 +299,45 @@ impl fmt::Display for Error {
                 }
                 Ok(())
             }
+            Self::MissingContext { pos, type_name } => {
+                write!(f, "missing context of type {} at 0x{:x}", type_name, pos)
+            }
+            Self::BackpatchSize {
+                pos,
+                reserved,
+                written,
+            } => write!(
+                f,
+                "backpatched value at 0x{:x} is {} bytes, but {} bytes were reserved",
+                pos, written, reserved
+            ),
+            Self::MissingDataSection { pos } => write!(
+                f,
+                "pointer at 0x{:x} needs a data section to write its value into",
+                pos
+            ),
+            Self::PointerOverflow { pos, value_pos } => write!(
+                f,
+                "offset to value at 0x{:x} does not fit in the pointer at 0x{:x}",
+                value_pos, pos
+            ),
+            Self::BadChecksum {
+                pos,
+                expected,
+                found,
+            } => write!(
+                f,
+                "bad checksum at 0x{:x}: expected 0x{:x}, found 0x{:x}",
+                pos, expected, found
+            ),
+            Self::SizeExceeded { pos, size } => write!(
+                f,
+                "field at 0x{:x} is larger than its size of {} bytes",
+                pos, size
+            ),
+            Self::BadEncoding { pos, encoding } => {
+                write!(f, "invalid {} string at 0x{:x}", encoding, pos)
+            }
             Self::Backtrace(backtrace) => write!(f, "{}", backtrace),
         }
     }
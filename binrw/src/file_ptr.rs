@@ -3,10 +3,12 @@
 
 use core::fmt;
 use core::ops::{Deref, DerefMut};
+use core::{cell::Cell, convert::TryFrom};
 
 use crate::{
-    io::{Read, Seek, SeekFrom},
-    BinRead, BinResult, ReadOptions,
+    binwrite::size::in_place_size,
+    io::{Read, Seek, SeekFrom, Write},
+    BinRead, BinResult, BinWrite, Error, ReadOptions, WriteOptions,
 };
 
 /// A wrapper type which represents a layer of indirection within a file.
@@ -43,6 +45,46 @@ use crate::{
 ///           [pointer]           [value]
 /// 00000000: 0000 0008 0000 0000 ff                   ............
 /// ```
+///
+/// # Writing
+///
+/// When a `FilePtr` is written, the pointed-to value is written into the
+/// [`DataSection`] from the [`WriteOptions`], and the offset to where it was
+/// written is written in place of the `FilePtr`. The offset is relative to
+/// [`WriteOptions::offset`], which can be set with the
+/// [`offset`](crate::attribute::write#offset) directive, and writing fails
+/// with [`Error::PointerOverflow`](crate::Error::PointerOverflow) if it does
+/// not fit in the pointer type.
+///
+/// The simplest way to get a data section is to use the
+/// [`data_section`](crate::attribute::write#data-sections) directive, which
+/// writes all pointed-to values after the end of a struct. Writing a value
+/// without a data section fails with
+/// [`Error::MissingDataSection`](crate::Error::MissingDataSection). If the
+/// `FilePtr` has no value, only `ptr` is written.
+///
+/// ```
+/// # use binrw::{binrw, prelude::*, io::Cursor, FilePtr8, NullString};
+/// #[binrw]
+/// #[brw(big)]
+/// #[bw(data_section)]
+/// #[derive(Debug, PartialEq)]
+/// struct Names {
+///     first: FilePtr8<NullString>,
+///     last: FilePtr8<NullString>,
+/// }
+///
+/// let names = Names {
+///     first: FilePtr8 { ptr: 0, value: Some(NullString(b"Ada".to_vec())) },
+///     last: FilePtr8 { ptr: 0, value: Some(NullString(b"Lovelace".to_vec())) },
+/// };
+/// let mut writer = Cursor::new(Vec::new());
+/// writer.write_be(&names).unwrap();
+/// assert_eq!(writer.get_ref(), b"\x02\x06Ada\0Lovelace\0");
+///
+/// writer.set_position(0);
+/// assert_eq!(writer.read_be::<Names>().unwrap(), names);
+/// ```
 pub struct FilePtr<Ptr: IntoSeekFrom, T> {
     /// The raw offset to the value.
     pub ptr: Ptr,
@@ -71,7 +113,7 @@ impl<Ptr: BinRead<Args = ()> + IntoSeekFrom, BR: BinRead> BinRead for FilePtr<Pt
     /// [`after_parse()`](Self::after_parse) is called.
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         _: Self::Args,
     ) -> BinResult<Self> {
         Ok(FilePtr {
@@ -81,7 +123,12 @@ impl<Ptr: BinRead<Args = ()> + IntoSeekFrom, BR: BinRead> BinRead for FilePtr<Pt
     }
 
     /// Finalizes the `FilePtr` by seeking to and reading the pointed-to value.
-    fn after_parse<R>(&mut self, reader: &mut R, ro: &ReadOptions, args: BR::Args) -> BinResult<()>
+    fn after_parse<R>(
+        &mut self,
+        reader: &mut R,
+        ro: &ReadOptions<'_>,
+        args: BR::Args,
+    ) -> BinResult<()>
     where
         R: Read + Seek,
     {
@@ -89,19 +136,193 @@ impl<Ptr: BinRead<Args = ()> + IntoSeekFrom, BR: BinRead> BinRead for FilePtr<Pt
     }
 }
 
+impl<Ptr, T> BinWrite for FilePtr<Ptr, T>
+where
+    Ptr: BinWrite<Args = ()> + IntoSeekFrom + TryFrom<u64>,
+    T: BinWrite,
+{
+    type Args = T::Args;
+
+    /// Writes the pointed-to value into the data section, then writes the
+    /// offset to it.
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        let value = match &self.value {
+            Some(value) => value,
+            None => return self.ptr.write_options(writer, options, ()),
+        };
+
+        let pos = writer.stream_position()?;
+        let value_pos = match reserve_value(value, pos, options, args.clone())? {
+            Some(value_pos) => value_pos,
+            None => return self.ptr.write_options(writer, options, ()),
+        };
+
+        writer.seek(SeekFrom::Start(value_pos))?;
+        value.write_options(writer, options, args)?;
+        writer.seek(SeekFrom::Start(pos))?;
+
+        let ptr: Ptr = ptr_to(value_pos, pos, options)?;
+        ptr.write_options(writer, options, ())
+    }
+}
+
+/// Reserves space for a pointed-to value at the end of the data section,
+/// returning its position, or `None` if only the pointer should be written.
+pub(crate) fn reserve_value<T: BinWrite>(
+    value: &T,
+    pos: u64,
+    options: &WriteOptions<'_>,
+    args: T::Args,
+) -> BinResult<Option<u64>> {
+    let data_section = options
+        .data_section()
+        .ok_or(Error::MissingDataSection { pos })?;
+
+    // Only the pointer itself is written in place, so that is all that
+    // needs to be written when measuring the size of the outer value
+    if data_section.measuring {
+        return Ok(None);
+    }
+
+    // The space for the value is reserved before writing it so any
+    // pointers inside of it are written after it
+    let value_pos = data_section.end();
+    data_section.reserve(in_place_size(value, value_pos, options, args)?);
+    Ok(Some(value_pos))
+}
+
+/// Converts the position of a pointed-to value into the pointer written at
+/// `pos`.
+pub(crate) fn ptr_to<Ptr: TryFrom<u64>>(
+    value_pos: u64,
+    pos: u64,
+    options: &WriteOptions<'_>,
+) -> BinResult<Ptr> {
+    value_pos
+        .checked_sub(options.offset())
+        .and_then(|ptr| Ptr::try_from(ptr).ok())
+        .ok_or(Error::PointerOverflow { pos, value_pos })
+}
+
+/// A region of a stream where [`FilePtr`] writes pointed-to values.
+///
+/// Values are written one after another, starting at the position that the
+/// section was created with. A data section is used by passing it to
+/// [`WriteOptions::with_data_section`], which makes it possible to place
+/// pointed-to values at the end of a file or in a separate part of a format.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor, file_ptr::DataSection, Endian, FilePtr32, WriteOptions};
+/// let ptrs = [
+///     FilePtr32 { ptr: 0, value: Some(1u16) },
+///     FilePtr32 { ptr: 0, value: Some(2u16) },
+/// ];
+///
+/// // Values go after the 0x10-byte header, with offsets relative to it
+/// let data_section = DataSection::new(0x10);
+/// let options = WriteOptions::new(Endian::Big)
+///     .with_data_section(&data_section)
+///     .with_offset(0x10);
+///
+/// let mut writer = Cursor::new(Vec::new());
+/// ptrs.write_options(&mut writer, &options, ()).unwrap();
+/// assert_eq!(data_section.end(), 0x14);
+/// assert_eq!(writer.get_ref()[..8], *b"\0\0\0\0\0\0\0\x02");
+/// assert_eq!(writer.get_ref()[0x10..], *b"\0\x01\0\x02");
+/// ```
+#[derive(Debug)]
+pub struct DataSection {
+    end: Cell<u64>,
+    measuring: bool,
+}
+
+impl DataSection {
+    /// Creates an empty data section which starts at the given position.
+    pub fn new(start: u64) -> Self {
+        Self {
+            end: Cell::new(start),
+            measuring: false,
+        }
+    }
+
+    /// Creates a data section which pointed-to values are never written to,
+    /// for measuring how many bytes are written in place of a value.
+    pub(crate) fn measuring() -> Self {
+        Self {
+            end: Cell::new(0),
+            measuring: true,
+        }
+    }
+
+    /// Returns the position after the last value in the data section.
+    pub fn end(&self) -> u64 {
+        self.end.get()
+    }
+
+    /// Reserves `size` bytes at the end of the data section, returning the
+    /// position of the reserved space.
+    pub fn reserve(&self, size: u64) -> u64 {
+        let pos = self.end.get();
+        self.end.set(pos + size);
+        pos
+    }
+
+    /// Creates a data section which starts directly after `value` when it is
+    /// written at `start`.
+    pub(crate) fn after<T: BinWrite + ?Sized>(
+        value: &T,
+        start: u64,
+        options: &WriteOptions<'_>,
+        args: T::Args,
+    ) -> BinResult<Self> {
+        Ok(Self::new(
+            start + in_place_size(value, start, options, args)?,
+        ))
+    }
+
+    /// Writes a value with its pointed-to values in a new data section
+    /// directly after it, leaving the writer at the end of the data section.
+    ///
+    /// This is what the [`data_section`](crate::attribute::write#data-sections)
+    /// directive does for derived types.
+    pub fn write_after<T, W>(
+        value: &T,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: T::Args,
+    ) -> BinResult<()>
+    where
+        T: BinWrite + ?Sized,
+        W: Write + Seek,
+    {
+        let start = writer.stream_position()?;
+        let data_section = Self::after(value, start, options, args.clone())?;
+        value.write_options(writer, &options.with_data_section(&data_section), args)?;
+        writer.seek(SeekFrom::Start(data_section.end()))?;
+        Ok(())
+    }
+}
+
 impl<Ptr: BinRead<Args = ()> + IntoSeekFrom, T> FilePtr<Ptr, T> {
     fn read_with_parser<R, Parser, AfterParse, Args>(
         parser: Parser,
         after_parse: AfterParse,
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Args,
     ) -> BinResult<Self>
     where
         R: Read + Seek,
         Args: Clone,
-        Parser: Fn(&mut R, &ReadOptions, Args) -> BinResult<T>,
-        AfterParse: Fn(&mut T, &mut R, &ReadOptions, Args) -> BinResult<()>,
+        Parser: Fn(&mut R, &ReadOptions<'_>, Args) -> BinResult<T>,
+        AfterParse: Fn(&mut T, &mut R, &ReadOptions<'_>, Args) -> BinResult<()>,
     {
         let mut file_ptr = Self {
             ptr: Ptr::read_options(reader, options, ())?,
@@ -116,14 +337,14 @@ impl<Ptr: BinRead<Args = ()> + IntoSeekFrom, T> FilePtr<Ptr, T> {
         parser: Parser,
         after_parse: AfterParse,
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Args,
     ) -> BinResult<()>
     where
         R: Read + Seek,
         Args: Clone,
-        Parser: Fn(&mut R, &ReadOptions, Args) -> BinResult<T>,
-        AfterParse: Fn(&mut T, &mut R, &ReadOptions, Args) -> BinResult<()>,
+        Parser: Fn(&mut R, &ReadOptions<'_>, Args) -> BinResult<T>,
+        AfterParse: Fn(&mut T, &mut R, &ReadOptions<'_>, Args) -> BinResult<()>,
     {
         let relative_to = options.offset();
         let before = reader.stream_position()?;
@@ -143,7 +364,7 @@ impl<Ptr: BinRead<Args = ()> + IntoSeekFrom, T> FilePtr<Ptr, T> {
     /// [`parse_with`](crate::attribute#custom-parsers) directive that reads
     /// and then immediately finalizes a [`FilePtr`], returning the pointed-to
     /// value as the result.
-    pub fn parse<R, Args>(reader: &mut R, options: &ReadOptions, args: Args) -> BinResult<T>
+    pub fn parse<R, Args>(reader: &mut R, options: &ReadOptions<'_>, args: Args) -> BinResult<T>
     where
         R: Read + Seek,
         Args: Clone,
@@ -159,14 +380,16 @@ impl<Ptr: BinRead<Args = ()> + IntoSeekFrom, T> FilePtr<Ptr, T> {
     /// [`parse_with`](crate::attribute#custom-parsers) directive that reads and then
     /// immediately finalizes a [`FilePtr`] using the specified parser, returning the pointed-to
     /// value as the result.
-    pub fn parse_with<R, F, Args>(parser: F) -> impl Fn(&mut R, &ReadOptions, Args) -> BinResult<T>
+    pub fn parse_with<R, F, Args>(
+        parser: F,
+    ) -> impl Fn(&mut R, &ReadOptions<'_>, Args) -> BinResult<T>
     where
         R: Read + Seek,
         Args: Clone,
-        F: Fn(&mut R, &ReadOptions, Args) -> BinResult<T>,
+        F: Fn(&mut R, &ReadOptions<'_>, Args) -> BinResult<T>,
     {
         move |reader, ro, args| {
-            let after_parse = |_: &mut T, _: &mut R, _: &ReadOptions, _: Args| Ok(());
+            let after_parse = |_: &mut T, _: &mut R, _: &ReadOptions<'_>, _: Args| Ok(());
             Ok(Self::read_with_parser(&parser, after_parse, reader, ro, args)?.into_inner())
         }
     }
@@ -175,14 +398,14 @@ impl<Ptr: BinRead<Args = ()> + IntoSeekFrom, T> FilePtr<Ptr, T> {
     /// [`parse_with`](crate::attribute#custom-parsers) directive that reads and then
     /// immediately finalizes a [`FilePtr`] using the specified parser, returning the [`FilePtr`]
     /// as the result.
-    pub fn with<R, F, Args>(parser: F) -> impl Fn(&mut R, &ReadOptions, Args) -> BinResult<Self>
+    pub fn with<R, F, Args>(parser: F) -> impl Fn(&mut R, &ReadOptions<'_>, Args) -> BinResult<Self>
     where
         R: Read + Seek,
         Args: Clone,
-        F: Fn(&mut R, &ReadOptions, Args) -> BinResult<T>,
+        F: Fn(&mut R, &ReadOptions<'_>, Args) -> BinResult<T>,
     {
         move |reader, ro, args| {
-            let after_parse = |_: &mut T, _: &mut R, _: &ReadOptions, _: Args| Ok(());
+            let after_parse = |_: &mut T, _: &mut R, _: &ReadOptions<'_>, _: Args| Ok(());
             Self::read_with_parser(&parser, after_parse, reader, ro, args)
         }
     }
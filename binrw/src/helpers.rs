@@ -1,10 +1,9 @@
 //! Helper functions for reading data.
 
 use crate::{
-    io::{self, Read, Seek},
-    BinRead, BinResult, Error, ReadOptions, VecArgs,
+    io::{Read, Seek},
+    BinRead, BinResult, ReadOptions, VecArgs,
 };
-use core::convert::TryInto;
 
 #[cfg(not(feature = "std"))]
 use alloc::{vec, vec::Vec};
@@ -29,7 +28,7 @@ use alloc::{vec, vec::Vec};
 #[deprecated(since = "0.2.0", note = "Use Vec<u8> instead.")]
 pub fn read_bytes<R: Read + Seek>(
     reader: &mut R,
-    _options: &ReadOptions,
+    _options: &ReadOptions<'_>,
     args: VecArgs<()>,
 ) -> BinResult<Vec<u8>> {
     let mut buf = vec![0; args.count];
@@ -56,7 +55,7 @@ pub fn read_bytes<R: Read + Seek>(
 /// ```
 pub fn until<Reader, T, CondFn, Arg, Ret>(
     cond: CondFn,
-) -> impl Fn(&mut Reader, &ReadOptions, Arg) -> BinResult<Ret>
+) -> impl Fn(&mut Reader, &ReadOptions<'_>, Arg) -> BinResult<Ret>
 where
     T: BinRead<Args = Arg>,
     Reader: Read + Seek,
@@ -64,7 +63,7 @@ where
     Arg: Clone,
     Ret: core::iter::FromIterator<T>,
 {
-    let read = |reader: &mut Reader, ro: &ReadOptions, args: Arg| {
+    let read = |reader: &mut Reader, ro: &ReadOptions<'_>, args: Arg| {
         let mut value = T::read_options(reader, ro, args.clone())?;
         value.after_parse(reader, ro, args)?;
         Ok(value)
@@ -93,12 +92,12 @@ where
 pub fn until_with<Reader, T, CondFn, Arg, ReadFn, Ret>(
     cond: CondFn,
     read: ReadFn,
-) -> impl Fn(&mut Reader, &ReadOptions, Arg) -> BinResult<Ret>
+) -> impl Fn(&mut Reader, &ReadOptions<'_>, Arg) -> BinResult<Ret>
 where
     Reader: Read + Seek,
     CondFn: Fn(&T) -> bool,
     Arg: Clone,
-    ReadFn: Fn(&mut Reader, &ReadOptions, Arg) -> BinResult<T>,
+    ReadFn: Fn(&mut Reader, &ReadOptions<'_>, Arg) -> BinResult<T>,
     Ret: core::iter::FromIterator<T>,
 {
     move |reader, ro, args| {
@@ -136,7 +135,7 @@ where
 /// ```
 pub fn until_exclusive<Reader, T, CondFn, Arg, Ret>(
     cond: CondFn,
-) -> impl Fn(&mut Reader, &ReadOptions, Arg) -> BinResult<Ret>
+) -> impl Fn(&mut Reader, &ReadOptions<'_>, Arg) -> BinResult<Ret>
 where
     T: BinRead<Args = Arg>,
     Reader: Read + Seek,
@@ -144,7 +143,7 @@ where
     Arg: Clone,
     Ret: core::iter::FromIterator<T>,
 {
-    let read = |reader: &mut Reader, ro: &ReadOptions, args: Arg| {
+    let read = |reader: &mut Reader, ro: &ReadOptions<'_>, args: Arg| {
         let mut value = T::read_options(reader, ro, args.clone())?;
         value.after_parse(reader, ro, args)?;
         Ok(value)
@@ -173,12 +172,12 @@ where
 pub fn until_exclusive_with<Reader, T, CondFn, Arg, ReadFn, Ret>(
     cond: CondFn,
     read: ReadFn,
-) -> impl Fn(&mut Reader, &ReadOptions, Arg) -> BinResult<Ret>
+) -> impl Fn(&mut Reader, &ReadOptions<'_>, Arg) -> BinResult<Ret>
 where
     Reader: Read + Seek,
     CondFn: Fn(&T) -> bool,
     Arg: Clone,
-    ReadFn: Fn(&mut Reader, &ReadOptions, Arg) -> BinResult<T>,
+    ReadFn: Fn(&mut Reader, &ReadOptions<'_>, Arg) -> BinResult<T>,
     Ret: core::iter::FromIterator<T>,
 {
     move |reader, ro, args| {
@@ -215,7 +214,7 @@ where
 /// ```
 pub fn until_eof<Reader, T, Arg, Ret>(
     reader: &mut Reader,
-    ro: &ReadOptions,
+    ro: &ReadOptions<'_>,
     args: Arg,
 ) -> BinResult<Ret>
 where
@@ -224,7 +223,7 @@ where
     Arg: Clone,
     Ret: core::iter::FromIterator<T>,
 {
-    let read = |reader: &mut Reader, ro: &ReadOptions, args: Arg| {
+    let read = |reader: &mut Reader, ro: &ReadOptions<'_>, args: Arg| {
         let mut value = T::read_options(reader, ro, args.clone())?;
         value.after_parse(reader, ro, args)?;
         Ok(value)
@@ -252,11 +251,11 @@ where
 /// ```
 pub fn until_eof_with<Reader, T, Arg, ReadFn, Ret>(
     read: ReadFn,
-) -> impl Fn(&mut Reader, &ReadOptions, Arg) -> BinResult<Ret>
+) -> impl Fn(&mut Reader, &ReadOptions<'_>, Arg) -> BinResult<Ret>
 where
     Reader: Read + Seek,
     Arg: Clone,
-    ReadFn: Fn(&mut Reader, &ReadOptions, Arg) -> BinResult<T>,
+    ReadFn: Fn(&mut Reader, &ReadOptions<'_>, Arg) -> BinResult<T>,
     Ret: core::iter::FromIterator<T>,
 {
     move |reader, ro, args| {
@@ -277,13 +276,6 @@ where
     }
 }
 
-fn not_enough_bytes<T>(_: T) -> Error {
-    Error::Io(io::Error::new(
-        io::ErrorKind::UnexpectedEof,
-        "not enough bytes in reader",
-    ))
-}
-
 /// A helper similar to `#[br(count = N)]` which can be used with any collection.
 ///
 /// # Examples
@@ -303,7 +295,7 @@ fn not_enough_bytes<T>(_: T) -> Error {
 /// # let x: CountBytes = x.read_be().unwrap();
 /// # assert_eq!(x.data, &[1, 2, 3]);
 /// ```
-pub fn count<R, T, Arg, Ret>(n: usize) -> impl Fn(&mut R, &ReadOptions, Arg) -> BinResult<Ret>
+pub fn count<R, T, Arg, Ret>(n: usize) -> impl Fn(&mut R, &ReadOptions<'_>, Arg) -> BinResult<Ret>
 where
     T: BinRead<Args = Arg>,
     R: Read + Seek,
@@ -312,16 +304,10 @@ where
 {
     move |reader, ro, args| {
         let mut container: Ret = core::iter::empty::<T>().collect();
-        if let Some(bytes) = <dyn core::any::Any>::downcast_mut::<Vec<u8>>(&mut container) {
-            bytes.reserve(n);
-            let byte_count = reader
-                .take(n.try_into().map_err(not_enough_bytes)?)
-                .read_to_end(bytes)?;
-            (byte_count == n)
-                .then(|| container)
-                .ok_or_else(|| not_enough_bytes(()))
+        if let Some(result) = crate::bulk::read_vec(reader, ro.endian(), n, &mut container) {
+            result.map(|_| container)
         } else {
-            let read = |reader: &mut R, ro: &ReadOptions, args: Arg| {
+            let read = |reader: &mut R, ro: &ReadOptions<'_>, args: Arg| {
                 let mut value = T::read_options(reader, ro, args.clone())?;
                 value.after_parse(reader, ro, args)?;
                 Ok(value)
@@ -353,11 +339,11 @@ where
 pub fn count_with<R, T, Arg, ReadFn, Ret>(
     n: usize,
     read: ReadFn,
-) -> impl Fn(&mut R, &ReadOptions, Arg) -> BinResult<Ret>
+) -> impl Fn(&mut R, &ReadOptions<'_>, Arg) -> BinResult<Ret>
 where
     R: Read + Seek,
     Arg: Clone,
-    ReadFn: Fn(&mut R, &ReadOptions, Arg) -> BinResult<T>,
+    ReadFn: Fn(&mut R, &ReadOptions<'_>, Arg) -> BinResult<T>,
     Ret: core::iter::FromIterator<T> + 'static,
 {
     move |reader, ro, args| {
@@ -0,0 +1,433 @@
+//! Reading and writing values which are not a whole number of bytes wide.
+
+use super::{Error, ErrorKind, Read, Result, Write};
+
+/// The most bits which can be read or written by a single call.
+const MAX_BITS: u32 = 64;
+
+/// The order in which the bits of each byte are consumed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// Bits are read from the most significant bit of each byte to the least
+    /// significant bit, and multi-byte values are stored most significant bit
+    /// first. This is the order used by most network protocols and file
+    /// formats.
+    #[default]
+    MsbFirst,
+    /// Bits are read from the least significant bit of each byte to the most
+    /// significant bit, and multi-byte values are stored least significant bit
+    /// first. This is the order used by DEFLATE and C bitfields on
+    /// little-endian targets.
+    LsbFirst,
+}
+
+/// A type which can be stored in fewer bits than its full width.
+///
+/// Signed values are stored in two's complement, and are sign-extended when
+/// they are read.
+pub trait BitValue: Sized {
+    /// The number of bits in the type.
+    const BITS: u32;
+
+    /// Converts the low `count` bits of `bits` into a value.
+    fn from_bits(bits: u64, count: u32) -> Self;
+
+    /// Converts the value into its low `count` bits, or returns `None` if the
+    /// value cannot be represented in `count` bits.
+    fn to_bits(&self, count: u32) -> Option<u64>;
+}
+
+macro_rules! bit_value_unsigned_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl BitValue for $ty {
+                const BITS: u32 = <$ty>::BITS;
+
+                fn from_bits(bits: u64, _: u32) -> Self {
+                    bits as $ty
+                }
+
+                fn to_bits(&self, count: u32) -> Option<u64> {
+                    let bits = u64::from(*self);
+                    (bits & !mask(count) == 0).then_some(bits)
+                }
+            }
+        )*
+    };
+}
+
+bit_value_unsigned_impl!(u8, u16, u32, u64);
+
+macro_rules! bit_value_signed_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl BitValue for $ty {
+                const BITS: u32 = <$ty>::BITS;
+
+                fn from_bits(bits: u64, count: u32) -> Self {
+                    sign_extend(bits, count) as $ty
+                }
+
+                fn to_bits(&self, count: u32) -> Option<u64> {
+                    let bits = i64::from(*self) as u64 & mask(count);
+                    (sign_extend(bits, count) == i64::from(*self)).then_some(bits)
+                }
+            }
+        )*
+    };
+}
+
+bit_value_signed_impl!(i8, i16, i32, i64);
+
+impl BitValue for bool {
+    const BITS: u32 = 1;
+
+    fn from_bits(bits: u64, _: u32) -> Self {
+        bits != 0
+    }
+
+    fn to_bits(&self, count: u32) -> Option<u64> {
+        (count != 0 || !*self).then_some(u64::from(*self))
+    }
+}
+
+fn mask(count: u32) -> u64 {
+    u64::MAX.checked_shr(MAX_BITS - count).unwrap_or(0)
+}
+
+fn sign_extend(bits: u64, count: u32) -> i64 {
+    match count {
+        0 => 0,
+        _ => ((bits << (MAX_BITS - count)) as i64) >> (MAX_BITS - count),
+    }
+}
+
+pub(crate) fn check_count<T: BitValue>(count: u32) -> Result<()> {
+    if count > T::BITS.min(MAX_BITS) {
+        Err(Error::new(
+            ErrorKind::InvalidInput,
+            "bit count is wider than the type",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// The partially consumed byte of a bit stream.
+///
+/// This is an implementation detail of [`BitReader`], [`BitWriter`], and the
+/// `bits` directive, which keeps one of these per struct instead of wrapping
+/// the reader or writer. It is only reachable through `__private`.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct BitState {
+    order: BitOrder,
+    byte: u8,
+    // For reads, the number of bits of `byte` not yet consumed; for writes,
+    // the number of bits of `byte` already filled.
+    len: u32,
+}
+
+impl BitState {
+    /// Creates an empty bit state for the given bit order.
+    pub fn new(order: BitOrder) -> Self {
+        Self {
+            order,
+            byte: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns `true` if there is no partially consumed byte.
+    pub fn is_aligned(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Discards any bits remaining in the current byte.
+    pub fn clear(&mut self) {
+        self.byte = 0;
+        self.len = 0;
+    }
+
+    /// The number of whole bytes which must be read to take `count` more bits.
+    pub fn bytes_needed(&self, count: u32) -> usize {
+        (count.saturating_sub(self.len) as usize).div_ceil(8)
+    }
+
+    /// Takes `count` bits, reading new bytes from `bytes`, which must hold
+    /// exactly [`bytes_needed`](Self::bytes_needed) bytes.
+    pub fn take(&mut self, bytes: &[u8], count: u32) -> u64 {
+        let mut bytes = bytes.iter();
+        let mut value = 0;
+        let mut done = 0;
+        while done < count {
+            if self.len == 0 {
+                self.byte = *bytes.next().expect("not enough bytes for bit read");
+                self.len = 8;
+            }
+
+            let take = self.len.min(count - done);
+            let byte = u64::from(self.byte);
+            match self.order {
+                BitOrder::MsbFirst => {
+                    value = (value << take) | ((byte >> (self.len - take)) & mask(take));
+                }
+                BitOrder::LsbFirst => {
+                    value |= ((byte >> (8 - self.len)) & mask(take)) << done;
+                }
+            }
+            self.len -= take;
+            done += take;
+        }
+
+        value
+    }
+
+    /// Adds the low `count` bits of `value`, storing each completed byte in
+    /// `out`, and returns the number of completed bytes.
+    pub fn put(&mut self, value: u64, count: u32, out: &mut [u8; 8]) -> usize {
+        let mut written = 0;
+        let mut done = 0;
+        while done < count {
+            let free = 8 - self.len;
+            let take = free.min(count - done);
+            let bits = match self.order {
+                BitOrder::MsbFirst => {
+                    ((value >> (count - done - take)) & mask(take)) << (free - take)
+                }
+                BitOrder::LsbFirst => ((value >> done) & mask(take)) << self.len,
+            };
+            self.byte |= bits as u8;
+            self.len += take;
+            done += take;
+
+            if self.len == 8 {
+                out[written] = self.byte;
+                written += 1;
+                self.clear();
+            }
+        }
+
+        written
+    }
+
+    /// Returns the current partial byte padded with zeroes, if there is one,
+    /// and starts a new byte.
+    pub fn finish(&mut self) -> Option<u8> {
+        let byte = (!self.is_aligned()).then_some(self.byte);
+        self.clear();
+        byte
+    }
+
+    /// Reads `count` bits into a `T`.
+    pub fn read<T: BitValue, R: Read>(&mut self, reader: &mut R, count: u32) -> Result<T> {
+        check_count::<T>(count)?;
+        let mut buf = [0; 8];
+        let buf = &mut buf[..self.bytes_needed(count)];
+        reader.read_exact(buf)?;
+        Ok(T::from_bits(self.take(buf, count), count))
+    }
+
+    /// Writes `value` in `count` bits.
+    pub fn write<T: BitValue, W: Write>(
+        &mut self,
+        writer: &mut W,
+        value: &T,
+        count: u32,
+    ) -> Result<()> {
+        let bits = to_bits(value, count)?;
+        let mut buf = [0; 8];
+        let len = self.put(bits, count, &mut buf);
+        writer.write_all(&buf[..len])
+    }
+
+    /// Writes the current partial byte, if there is one.
+    pub fn flush<W: Write>(&mut self, writer: &mut W) -> Result<()> {
+        match self.finish() {
+            Some(byte) => writer.write_all(&[byte]),
+            None => Ok(()),
+        }
+    }
+}
+
+pub(crate) fn to_bits<T: BitValue>(value: &T, count: u32) -> Result<u64> {
+    check_count::<T>(count)?;
+    value.to_bits(count).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "value does not fit in the given number of bits",
+        )
+    })
+}
+
+/// A reader which reads values that are not a whole number of bytes wide.
+///
+/// Bits are taken from the inner reader one byte at a time. Bits left over in
+/// the current byte are kept for the next read until [`align()`](Self::align)
+/// discards them.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::io::{BitOrder, BitReader, Cursor};
+///
+/// let mut reader = BitReader::new(Cursor::new([0b1011_0010, 0xff]), BitOrder::MsbFirst);
+/// assert_eq!(reader.read_bits(3).unwrap(), 0b101);
+/// assert_eq!(reader.read::<i8>(5).unwrap(), -14);
+/// assert_eq!(reader.read::<bool>(1).unwrap(), true);
+/// reader.align();
+/// assert!(reader.read_bits(1).is_err());
+/// ```
+#[derive(Debug)]
+pub struct BitReader<R> {
+    inner: R,
+    state: BitState,
+}
+
+impl<R> BitReader<R> {
+    /// Creates a new bit reader which reads bits in the given order.
+    pub fn new(inner: R, order: BitOrder) -> Self {
+        Self {
+            inner,
+            state: BitState::new(order),
+        }
+    }
+
+    /// Returns `true` if there are no bits left over from the last byte read.
+    pub fn is_aligned(&self) -> bool {
+        self.state.is_aligned()
+    }
+
+    /// Discards the rest of the current byte, so the next read starts at a
+    /// byte boundary.
+    pub fn align(&mut self) {
+        self.state.clear();
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Reading directly from the underlying reader while the bit reader is
+    /// not aligned will skip bits.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes the bit reader, returning the underlying reader. Any bits left
+    /// over in the current byte are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> BitReader<R> {
+    /// Reads up to 64 bits.
+    ///
+    /// # Errors
+    ///
+    /// If more than 64 bits are requested, an
+    /// [`InvalidInput`](ErrorKind::InvalidInput) error is returned.
+    pub fn read_bits(&mut self, count: u32) -> Result<u64> {
+        self.read(count)
+    }
+
+    /// Reads `count` bits as a `T`.
+    ///
+    /// # Errors
+    ///
+    /// If `count` is wider than `T`, an
+    /// [`InvalidInput`](ErrorKind::InvalidInput) error is returned.
+    pub fn read<T: BitValue>(&mut self, count: u32) -> Result<T> {
+        self.state.read(&mut self.inner, count)
+    }
+}
+
+/// A writer which writes values that are not a whole number of bytes wide.
+///
+/// Completed bytes are written to the inner writer as soon as they are full.
+/// The last partial byte is only written, padded with zero bits, when
+/// [`align()`](Self::align) or [`into_inner()`](Self::into_inner) is called.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::io::{BitOrder, BitWriter};
+///
+/// let mut writer = BitWriter::new(Vec::new(), BitOrder::LsbFirst);
+/// writer.write_bits(0b101, 3).unwrap();
+/// writer.write(&-2i8, 4).unwrap();
+/// writer.write(&true, 1).unwrap();
+/// writer.write_bits(0x3, 2).unwrap();
+/// assert_eq!(writer.into_inner().unwrap(), [0b1111_0101, 0b11]);
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<W> {
+    inner: W,
+    state: BitState,
+}
+
+impl<W> BitWriter<W> {
+    /// Creates a new bit writer which writes bits in the given order.
+    pub fn new(inner: W, order: BitOrder) -> Self {
+        Self {
+            inner,
+            state: BitState::new(order),
+        }
+    }
+
+    /// Returns `true` if there is no partially written byte.
+    pub fn is_aligned(&self) -> bool {
+        self.state.is_aligned()
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Writing directly to the underlying writer while the bit writer is not
+    /// aligned will put bytes before the partially written byte.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+}
+
+impl<W: Write> BitWriter<W> {
+    /// Writes the low `count` bits of `value`, up to 64 bits.
+    ///
+    /// # Errors
+    ///
+    /// If more than 64 bits are requested, or `value` does not fit in `count`
+    /// bits, an [`InvalidInput`](ErrorKind::InvalidInput) error is returned.
+    pub fn write_bits(&mut self, value: u64, count: u32) -> Result<()> {
+        self.write(&value, count)
+    }
+
+    /// Writes `value` in `count` bits.
+    ///
+    /// # Errors
+    ///
+    /// If `count` is wider than `T`, or `value` does not fit in `count` bits,
+    /// an [`InvalidInput`](ErrorKind::InvalidInput) error is returned.
+    pub fn write<T: BitValue>(&mut self, value: &T, count: u32) -> Result<()> {
+        self.state.write(&mut self.inner, value, count)
+    }
+
+    /// Pads the current byte with zero bits and writes it, so the next write
+    /// starts at a byte boundary.
+    pub fn align(&mut self) -> Result<()> {
+        self.state.flush(&mut self.inner)
+    }
+
+    /// Aligns the bit writer and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.align()?;
+        Ok(self.inner)
+    }
+}
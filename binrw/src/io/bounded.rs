@@ -0,0 +1,116 @@
+use super::{Read, Result, Seek, SeekFrom};
+use core::convert::TryFrom;
+
+/// A wrapper which stops reading from a seekable stream at a fixed position.
+///
+/// `BoundedReader` limits reads to the `size` bytes after the position of the
+/// inner stream when it was created. Positions are not changed, so seeking
+/// works exactly as it does on the inner stream, but any read at or past the
+/// end of the bounded region returns fewer bytes than requested, as if the
+/// stream ended there. Whether a read was cut short by the bound is recorded,
+/// so an unexpected end of file can be told apart from data which was too
+/// large for its region.
+///
+/// This is used by the [`size`](crate::attribute::read#size) directive.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinReaderExt, io::{BoundedReader, Cursor}};
+///
+/// let mut stream = Cursor::new(b"\0\x01\0\x02");
+/// let mut bounded = BoundedReader::new(&mut stream, 3).unwrap();
+/// assert_eq!(bounded.read_be::<u16>().unwrap(), 1);
+/// assert!(!bounded.is_overread());
+/// assert!(bounded.read_be::<u16>().is_err());
+/// assert!(bounded.is_overread());
+/// ```
+#[derive(Debug)]
+pub struct BoundedReader<T> {
+    inner: T,
+    start: u64,
+    end: u64,
+    pos: u64,
+    overread: bool,
+}
+
+impl<T: Seek> BoundedReader<T> {
+    /// Creates a new reader which can read at most `size` bytes from the
+    /// current position of the given stream.
+    ///
+    /// # Errors
+    ///
+    /// If the position of the stream cannot be retrieved, an error is
+    /// returned.
+    pub fn new(mut inner: T, size: u64) -> Result<Self> {
+        let start = inner.stream_position()?;
+        Ok(Self {
+            inner,
+            start,
+            end: start.saturating_add(size),
+            pos: start,
+            overread: false,
+        })
+    }
+}
+
+impl<T> BoundedReader<T> {
+    /// Returns the position where the bounded region starts.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the position where the bounded region ends.
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Returns true if a read was cut short because it tried to read past the
+    /// end of the bounded region.
+    pub fn is_overread(&self) -> bool {
+        self.overread
+    }
+
+    /// Gets a reference to the underlying stream.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying stream.
+    ///
+    /// Reading from or seeking the underlying stream directly will make the
+    /// position of the `BoundedReader` incorrect.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consumes this wrapper, returning the underlying stream.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read> Read for BoundedReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let remaining = self.end.saturating_sub(self.pos);
+        let len = usize::try_from(remaining).map_or(buf.len(), |len| len.min(buf.len()));
+        if len < buf.len() {
+            self.overread = true;
+        }
+
+        let n = self.inner.read(&mut buf[..len])?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: Seek> Seek for BoundedReader<T> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        self.pos = self.inner.seek(pos)?;
+        Ok(self.pos)
+    }
+
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.pos)
+    }
+}
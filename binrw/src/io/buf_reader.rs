@@ -0,0 +1,180 @@
+use super::{add_offset, BufRead, Read, Result, Seek, SeekFrom};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec};
+
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// A buffered reader which keeps its buffer when seeking.
+///
+/// Parsers call [`Seek::stream_position`] and seek very often, and
+/// `std::io::BufReader` throws away its buffer every time it seeks, so
+/// reading through it is not much faster than reading directly from a `File`.
+/// This `BufReader` instead keeps its buffer when a seek lands inside of the
+/// buffered data, and keeps track of its position itself, so after the first
+/// call, getting the position never needs to ask the inner stream.
+///
+/// The inner stream must not be moved by anything other than this reader
+/// while it is buffered, or the position of the reader will be wrong.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinReaderExt, io::{BufReader, Cursor, Seek, SeekFrom}};
+///
+/// let mut reader = BufReader::new(Cursor::new(b"\0\x01\0\x02"));
+/// assert_eq!(reader.read_be::<u16>().unwrap(), 1);
+/// reader.seek(SeekFrom::Current(-2)).unwrap();
+/// assert_eq!(reader.read_be::<u32>().unwrap(), 0x0001_0002);
+/// ```
+#[derive(Debug)]
+pub struct BufReader<T> {
+    inner: T,
+    buf: Box<[u8]>,
+    filled: usize,
+    consumed: usize,
+    // The position of the inner stream, which is the position of the end of
+    // the buffered data, if it is known yet
+    pos: Option<u64>,
+}
+
+impl<T> BufReader<T> {
+    /// Creates a new `BufReader` with a default buffer capacity of 8 KiB.
+    pub fn new(inner: T) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    /// Creates a new `BufReader` with the given buffer capacity.
+    pub fn with_capacity(capacity: usize, inner: T) -> Self {
+        Self {
+            inner,
+            buf: vec![0; capacity].into_boxed_slice(),
+            filled: 0,
+            consumed: 0,
+            pos: None,
+        }
+    }
+
+    /// Returns the data which has been buffered but not read yet.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.consumed..self.filled]
+    }
+
+    /// Returns the number of bytes the internal buffer can hold at once.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Gets a reference to the underlying stream.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying stream.
+    ///
+    /// Reading from or seeking the underlying stream directly will make the
+    /// position of the `BufReader` incorrect.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consumes this `BufReader`, returning the underlying stream.
+    ///
+    /// Any data in the buffer is lost, so the underlying stream will usually
+    /// be positioned after the last byte read through this reader.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn discard_buffer(&mut self) {
+        self.filled = 0;
+        self.consumed = 0;
+    }
+}
+
+impl<T: Read> BufReader<T> {
+    fn fill_buf_inner(&mut self) -> Result<&[u8]> {
+        if self.consumed >= self.filled {
+            let n = self.inner.read(&mut self.buf)?;
+            self.filled = n;
+            self.consumed = 0;
+            if let Some(pos) = &mut self.pos {
+                *pos += n as u64;
+            }
+        }
+
+        Ok(self.buffer())
+    }
+}
+
+impl<T: Read> Read for BufReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        // Large reads skip the buffer entirely when it is empty, since
+        // copying through it would not save any calls to the inner stream
+        if self.consumed >= self.filled && buf.len() >= self.buf.len() {
+            self.discard_buffer();
+            let n = self.inner.read(buf)?;
+            if let Some(pos) = &mut self.pos {
+                *pos += n as u64;
+            }
+            return Ok(n);
+        }
+
+        let available = self.fill_buf_inner()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consumed += n;
+        Ok(n)
+    }
+}
+
+impl<T: Seek> Seek for BufReader<T> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(target) => target,
+            SeekFrom::Current(offset) => add_offset(self.stream_position()?, offset)?,
+            SeekFrom::End(_) => {
+                // The end of the stream is only known by the inner stream
+                self.discard_buffer();
+                let pos = self.inner.seek(pos)?;
+                self.pos = Some(pos);
+                return Ok(pos);
+            }
+        };
+
+        if let Some(end) = self.pos {
+            let start = end - self.filled as u64;
+            if (start..=end).contains(&target) {
+                self.consumed = (target - start) as usize;
+                return Ok(target);
+            }
+        }
+
+        self.discard_buffer();
+        let pos = self.inner.seek(SeekFrom::Start(target))?;
+        self.pos = Some(pos);
+        Ok(pos)
+    }
+
+    fn stream_position(&mut self) -> Result<u64> {
+        let end = match self.pos {
+            Some(pos) => pos,
+            None => {
+                let pos = self.inner.stream_position()?;
+                self.pos = Some(pos);
+                pos
+            }
+        };
+
+        Ok(end - (self.filled - self.consumed) as u64)
+    }
+}
+
+impl<T: Read> BufRead for BufReader<T> {
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        self.fill_buf_inner()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.consumed = (self.consumed + amt).min(self.filled);
+    }
+}
@@ -4,7 +4,13 @@
 //! used by binrw. In no_std environments, a compatible subset API is exposed
 //! instead.
 
+pub(crate) mod bits;
+mod bounded;
+mod buf_reader;
+mod no_seek;
 pub mod prelude;
+mod take_seek;
+pub mod transform;
 #[cfg(all(doc, not(feature = "std")))]
 extern crate std;
 #[cfg(not(feature = "std"))]
@@ -12,4 +18,30 @@ mod no_std;
 #[cfg(not(feature = "std"))]
 pub use no_std::*;
 #[cfg(feature = "std")]
-pub use std::io::{Bytes, Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+pub use std::io::{
+    copy, BufRead, Bytes, Chain, Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Take,
+    Write,
+};
+
+pub use bits::{BitOrder, BitReader, BitValue, BitWriter};
+pub use bounded::BoundedReader;
+pub use buf_reader::BufReader;
+pub(crate) use no_seek::is_seek_backwards;
+pub use no_seek::NoSeek;
+pub use take_seek::{TakeSeek, TakeSeekExt};
+
+/// Adds a signed seek offset to a position, returning an error if the result
+/// would be negative or overflow.
+fn add_offset(base: u64, offset: i64) -> Result<u64> {
+    if offset < 0 {
+        base.checked_sub(offset.unsigned_abs())
+    } else {
+        base.checked_add(offset as u64)
+    }
+    .ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "invalid seek to a negative or overflowing position",
+        )
+    })
+}
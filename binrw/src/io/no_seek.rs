@@ -0,0 +1,140 @@
+use super::{Error, ErrorKind, Read, Result, Seek, SeekFrom};
+use core::fmt;
+
+/// A wrapper which lets a stream that cannot seek, like a socket or a pipe, be
+/// used where a [`Seek`] implementation is required.
+///
+/// `NoSeek` keeps track of how many bytes have been read from the inner
+/// stream, so it can report its position. Seeking forward is emulated by
+/// reading and discarding bytes. Seeking backwards or relative to the end of
+/// the stream returns an [`InvalidInput`](ErrorKind::InvalidInput) error.
+///
+/// This is usually created for you by
+/// [`StreamReaderExt`](crate::StreamReaderExt), which also checks at compile
+/// time that the type being read never needs to seek backwards.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinReaderExt, io::{Cursor, NoSeek, Seek, SeekFrom}};
+///
+/// let mut stream = NoSeek::new(Cursor::new(b"\0\x01\0\x02"));
+/// stream.seek(SeekFrom::Current(2)).unwrap();
+/// assert_eq!(stream.read_be::<u16>().unwrap(), 2);
+/// assert!(stream.seek(SeekFrom::Start(0)).is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct NoSeek<T> {
+    inner: T,
+    pos: u64,
+}
+
+impl<T> NoSeek<T> {
+    /// Creates a new seekable wrapper for the given stream.
+    pub fn new(inner: T) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Gets a reference to the underlying stream.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying stream.
+    ///
+    /// Reading directly from the underlying stream will cause the position
+    /// reported by this wrapper to be wrong.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consumes this wrapper, returning the underlying stream.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns the number of bytes which have been read through this wrapper.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+}
+
+impl<T: Read> NoSeek<T> {
+    fn skip(&mut self, mut count: u64) -> Result<()> {
+        let mut buf = [0; 0x100];
+        while count != 0 {
+            let chunk = count.min(buf.len() as u64) as usize;
+            match self.read(&mut buf[..chunk]) {
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to skip to seek position",
+                    ))
+                }
+                Ok(n) => count -= n as u64,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Read> Read for NoSeek<T> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: Read> Seek for NoSeek<T> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(target) => Some(target),
+            SeekFrom::Current(offset) => {
+                if offset < 0 {
+                    None
+                } else {
+                    self.pos.checked_add(offset as u64)
+                }
+            }
+            SeekFrom::End(_) => None,
+        };
+
+        match target {
+            Some(target) if target >= self.pos => {
+                self.skip(target - self.pos)?;
+                Ok(self.pos)
+            }
+            _ => Err(Error::new(ErrorKind::InvalidInput, SeekBackwards)),
+        }
+    }
+}
+
+/// The payload of the error returned when a [`NoSeek`] is asked to seek
+/// backwards or from the end of the stream.
+#[derive(Debug)]
+struct SeekBackwards;
+
+impl fmt::Display for SeekBackwards {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot seek backwards or from the end of a stream")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SeekBackwards {}
+
+/// Returns whether `error` was returned because a [`NoSeek`] cannot seek
+/// backwards.
+pub(crate) fn is_seek_backwards(error: &Error) -> bool {
+    #[cfg(feature = "std")]
+    let is_seek_backwards = error
+        .get_ref()
+        .is_some_and(|payload| payload.is::<SeekBackwards>());
+    #[cfg(not(feature = "std"))]
+    let is_seek_backwards = error.payload_is::<SeekBackwards>();
+    is_seek_backwards
+}
@@ -1,4 +1,4 @@
-use super::{Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+use super::{super::add_offset, BufRead, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
 use crate::alloc::{boxed::Box, vec::Vec};
 use core::{cmp, convert::TryInto};
 
@@ -55,36 +55,26 @@ impl<T: AsRef<[u8]>> Read for Cursor<T> {
     }
 }
 
+impl<T: AsRef<[u8]>> BufRead for Cursor<T> {
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        let slice = self.inner.as_ref();
+        let start = cmp::min(self.pos, slice.len() as u64) as usize;
+        Ok(&slice[start..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+
 impl<T: AsRef<[u8]>> Seek for Cursor<T> {
     fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
-        match pos {
-            SeekFrom::Current(x) => {
-                if (self.pos as i64) + x < 0 {
-                    Err(Error::new(
-                        ErrorKind::InvalidInput,
-                        "invalid seek to a negative or overflowing position",
-                    ))
-                } else {
-                    self.pos = ((self.pos as i64) + x) as u64;
-                    Ok(self.pos)
-                }
-            }
-            SeekFrom::Start(x) => {
-                self.pos = x;
-                Ok(self.pos)
-            }
-            SeekFrom::End(x) => {
-                let end = self.inner.as_ref().len() as i64;
-                if (self.pos as i64) + end + x < 0 {
-                    Err(Error::new(
-                        ErrorKind::InvalidInput,
-                        "invalid seek to a negative or overflowing position",
-                    ))
-                } else {
-                    Ok(self.pos)
-                }
-            }
-        }
+        self.pos = match pos {
+            SeekFrom::Start(x) => x,
+            SeekFrom::Current(x) => add_offset(self.pos, x)?,
+            SeekFrom::End(x) => add_offset(self.inner.as_ref().len() as u64, x)?,
+        };
+        Ok(self.pos)
     }
 }
 
@@ -1,4 +1,5 @@
-use core::fmt;
+use crate::alloc::boxed::Box;
+use core::{any::TypeId, fmt};
 
 /// The error type for I/O operations of the [`Read`], [`Write`], [`Seek`], and
 /// associated traits.
@@ -18,13 +19,32 @@ impl fmt::Debug for Error {
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(&self, f)
+        match &self.repr {
+            Repr::Simple(_) => fmt::Debug::fmt(&self, f),
+            Repr::Custom(custom) => fmt::Display::fmt(&custom.error, f),
+        }
     }
 }
 
 #[derive(Debug)]
 enum Repr {
     Simple(ErrorKind),
+    Custom(Box<Custom>),
+}
+
+struct Custom {
+    kind: ErrorKind,
+    error: Box<dyn fmt::Display + Send + Sync>,
+    type_id: TypeId,
+}
+
+impl fmt::Debug for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Custom")
+            .field("kind", &self.kind)
+            .field("error", &format_args!("{}", self.error))
+            .finish()
+    }
 }
 
 /// A list specifying general categories of I/O error.
@@ -77,16 +97,73 @@ pub enum ErrorKind {
 impl Error {
     /// Creates a new I/O error from a known kind of error as well as an
     /// arbitrary error payload.
-    pub fn new<A>(kind: ErrorKind, _: A) -> Self {
+    ///
+    /// The payload is usually a message describing the error, but any type
+    /// which implements [`Display`](fmt::Display) can be used. It can be
+    /// retrieved later with [`get_ref`](Self::get_ref) or
+    /// [`into_inner`](Self::into_inner).
+    pub fn new<E>(kind: ErrorKind, error: E) -> Self
+    where
+        E: fmt::Display + Send + Sync + 'static,
+    {
         Self {
-            repr: Repr::Simple(kind),
+            repr: Repr::Custom(Box::new(Custom {
+                kind,
+                error: Box::new(error),
+                type_id: TypeId::of::<E>(),
+            })),
         }
     }
 
+    /// Creates a new I/O error of kind [`ErrorKind::Other`] from an arbitrary
+    /// error payload.
+    pub fn other<E>(error: E) -> Self
+    where
+        E: fmt::Display + Send + Sync + 'static,
+    {
+        Self::new(ErrorKind::Other, error)
+    }
+
     /// Returns the corresponding [`ErrorKind`] for this error.
     pub fn kind(&self) -> ErrorKind {
+        match &self.repr {
+            Repr::Simple(kind) => *kind,
+            Repr::Custom(custom) => custom.kind,
+        }
+    }
+
+    /// Returns a reference to the payload of this error, if it was created
+    /// with [`new`](Self::new).
+    pub fn get_ref(&self) -> Option<&(dyn fmt::Display + Send + Sync + 'static)> {
+        match &self.repr {
+            Repr::Simple(_) => None,
+            Repr::Custom(custom) => Some(&*custom.error),
+        }
+    }
+
+    /// Returns a mutable reference to the payload of this error, if it was
+    /// created with [`new`](Self::new).
+    pub fn get_mut(&mut self) -> Option<&mut (dyn fmt::Display + Send + Sync + 'static)> {
+        match &mut self.repr {
+            Repr::Simple(_) => None,
+            Repr::Custom(custom) => Some(&mut *custom.error),
+        }
+    }
+
+    /// Returns whether the payload of this error is of type `T`.
+    pub(crate) fn payload_is<T: 'static>(&self) -> bool {
+        match &self.repr {
+            Repr::Simple(_) => false,
+            Repr::Custom(custom) => custom.type_id == TypeId::of::<T>(),
+        }
+    }
+
+    /// Consumes this error, returning its payload, if it was created with
+    /// [`new`](Self::new).
+    pub fn into_inner(self) -> Option<Box<dyn fmt::Display + Send + Sync>> {
         match self.repr {
-            Repr::Simple(kind) => kind,
+            Repr::Simple(_) => None,
+            Repr::Custom(custom) => Some(custom.error),
         }
     }
 }
@@ -0,0 +1,147 @@
+use super::{add_offset, Error, ErrorKind, Read, Result, Seek, SeekFrom};
+
+/// A reader which exposes a window of a seekable stream as its own seekable
+/// stream.
+///
+/// Unlike [`Read::take`], `TakeSeek` implements [`Seek`], so it can be passed
+/// to [`BinRead::read_options`](crate::BinRead::read_options). Positions are
+/// relative to the start of the window, which is the position of the inner
+/// stream when the `TakeSeek` was created, and seeking from the end seeks
+/// from the end of the window. Reading stops at the end of the window.
+/// Seeking past the end of the window is allowed, but reading from there
+/// returns no data.
+///
+/// To limit reads without changing positions, use a
+/// [`BoundedReader`](super::BoundedReader) instead.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinReaderExt, io::{Cursor, Seek, SeekFrom, TakeSeekExt}};
+///
+/// let mut stream = Cursor::new(b"\xff\0\x01\0\x02\xff");
+/// stream.seek(SeekFrom::Start(1)).unwrap();
+/// let mut window = (&mut stream).take_seek(4).unwrap();
+/// window.seek(SeekFrom::End(-2)).unwrap();
+/// assert_eq!(window.read_be::<u16>().unwrap(), 2);
+/// assert_eq!(window.stream_position().unwrap(), 4);
+/// assert!(window.read_be::<u8>().is_err());
+/// ```
+#[derive(Debug)]
+pub struct TakeSeek<T> {
+    inner: T,
+    start: u64,
+    len: u64,
+    pos: u64,
+}
+
+impl<T: Seek> TakeSeek<T> {
+    /// Creates a new window of `limit` bytes which starts at the current
+    /// position of the given stream.
+    ///
+    /// # Errors
+    ///
+    /// If the position of the stream cannot be retrieved, an error is
+    /// returned.
+    pub fn new(mut inner: T, limit: u64) -> Result<Self> {
+        let start = inner.stream_position()?;
+        Ok(Self {
+            inner,
+            start,
+            len: limit,
+            pos: 0,
+        })
+    }
+}
+
+impl<T> TakeSeek<T> {
+    /// Returns the number of bytes that can be read before this instance will
+    /// return EOF.
+    pub fn limit(&self) -> u64 {
+        self.len.saturating_sub(self.pos)
+    }
+
+    /// Sets the number of bytes that can be read from the current position
+    /// before this instance will return EOF. The start of the window does not
+    /// change.
+    pub fn set_limit(&mut self, limit: u64) {
+        self.len = self.pos.saturating_add(limit);
+    }
+
+    /// Gets a reference to the underlying stream.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying stream.
+    ///
+    /// Reading from or seeking the underlying stream directly will make the
+    /// position of the `TakeSeek` incorrect.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consumes this wrapper, returning the underlying stream.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read> Read for TakeSeek<T> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let limit = self.limit();
+
+        // Don't call into inner reader at all at EOF because it may still block
+        if limit == 0 {
+            return Ok(0);
+        }
+
+        let max = core::cmp::min(buf.len() as u64, limit) as usize;
+        let n = self.inner.read(&mut buf[..max])?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: Seek> Seek for TakeSeek<T> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(target) => target,
+            SeekFrom::Current(offset) => add_offset(self.pos, offset)?,
+            SeekFrom::End(offset) => add_offset(self.len, offset)?,
+        };
+
+        let absolute = self.start.checked_add(target).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        self.inner.seek(SeekFrom::Start(absolute))?;
+        self.pos = target;
+        Ok(target)
+    }
+
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+/// An extension trait for creating a [`TakeSeek`] from a seekable reader.
+pub trait TakeSeekExt: Read + Seek {
+    /// Creates an adaptor which exposes the next `limit` bytes of this stream
+    /// as a seekable stream whose positions start at zero.
+    ///
+    /// # Errors
+    ///
+    /// If the position of the stream cannot be retrieved, an error is
+    /// returned.
+    fn take_seek(self, limit: u64) -> Result<TakeSeek<Self>>
+    where
+        Self: Sized,
+    {
+        TakeSeek::new(self, limit)
+    }
+}
+
+impl<T: Read + Seek> TakeSeekExt for T {}
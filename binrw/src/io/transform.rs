@@ -0,0 +1,221 @@
+//! Transforms for encoded regions of a stream, used by the
+//! [`transform`](crate::attribute::read#transforms) directive.
+//!
+//! A [`Transform`] converts between the bytes stored in a stream and the
+//! bytes a field is actually parsed from, for formats which compress or
+//! obfuscate part of their data. Any type implementing the trait can be used
+//! with the `transform` directive.
+//!
+//! The [`Xor`] and [`Rle`] transforms are always available. The `Deflate` and
+//! `Zlib` transforms require the `deflate` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use binrw::io::transform::{Rle, Transform, Xor};
+//!
+//! let key = Xor(b"key");
+//! let encoded = key.encode(b"secret").unwrap();
+//! assert_eq!(encoded, b"\x18\x00\x1a\x19\x00\x0d");
+//! assert_eq!(key.decode(&encoded).unwrap(), b"secret");
+//!
+//! let encoded = Rle.encode(b"aaaaabc").unwrap();
+//! assert_eq!(encoded, b"\xfca\x01bc");
+//! assert_eq!(Rle.decode(&encoded).unwrap(), b"aaaaabc");
+//! ```
+
+use super::{Error, ErrorKind, Result};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A reversible conversion between the bytes stored in a stream and the bytes
+/// of the data they represent.
+pub trait Transform {
+    /// Converts bytes read from a stream into the data they represent.
+    ///
+    /// # Errors
+    ///
+    /// If `data` is not validly encoded, an
+    /// [`InvalidData`](ErrorKind::InvalidData) error should be returned.
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>>;
+
+    /// Converts data into the bytes which should be written to a stream.
+    ///
+    /// # Errors
+    ///
+    /// If `data` cannot be encoded, an error should be returned.
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// A transform which XORs data with a repeating key.
+///
+/// XOR is its own inverse, so decoding and encoding are the same operation.
+/// An empty key leaves the data unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::io::transform::{Transform, Xor};
+///
+/// assert_eq!(Xor([0xff]).decode(b"\x0f\xf0").unwrap(), b"\xf0\x0f");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Xor<K>(pub K);
+
+impl<K: AsRef<[u8]>> Xor<K> {
+    fn apply(&self, data: &[u8]) -> Vec<u8> {
+        let key = self.0.as_ref();
+        if key.is_empty() {
+            data.to_vec()
+        } else {
+            data.iter()
+                .zip(key.iter().cycle())
+                .map(|(byte, key)| byte ^ key)
+                .collect()
+        }
+    }
+}
+
+impl<K: AsRef<[u8]>> Transform for Xor<K> {
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(self.apply(data))
+    }
+
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(self.apply(data))
+    }
+}
+
+/// A run-length encoding transform using the PackBits scheme from TIFF and
+/// Apple MacPaint.
+///
+/// Encoded data is a sequence of packets, each starting with a signed header
+/// byte `n`:
+///
+/// * `0..=127`: the next `n + 1` bytes are copied literally.
+/// * `-127..=-1`: the next byte is repeated `1 - n` times.
+/// * `-128`: no operation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Rle;
+
+impl Rle {
+    const MAX_PACKET: usize = 128;
+}
+
+impl Transform for Rle {
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let truncated = || Error::new(ErrorKind::InvalidData, "truncated PackBits packet");
+
+        let mut out = Vec::with_capacity(data.len());
+        let mut data = data.iter();
+        while let Some(&header) = data.next() {
+            match header as i8 {
+                -128 => {}
+                n @ 0..=127 => {
+                    let len = n as usize + 1;
+                    let literal = data.as_slice().get(..len).ok_or_else(truncated)?;
+                    out.extend_from_slice(literal);
+                    data.nth(len - 1);
+                }
+                n => {
+                    let byte = *data.next().ok_or_else(truncated)?;
+                    out.resize(out.len() + (1 - isize::from(n)) as usize, byte);
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut out = Vec::with_capacity(data.len() + data.len() / Self::MAX_PACKET + 1);
+        let mut rest = data;
+        while let Some(&byte) = rest.first() {
+            let run = rest
+                .iter()
+                .take(Self::MAX_PACKET)
+                .take_while(|&&next| next == byte)
+                .count();
+
+            let len = if run > 1 {
+                out.push((1 - run as isize) as u8);
+                out.push(byte);
+                run
+            } else {
+                // A literal packet ends where the next run begins
+                let len = rest
+                    .windows(2)
+                    .take(Self::MAX_PACKET)
+                    .position(|pair| pair[0] == pair[1])
+                    .unwrap_or_else(|| rest.len().min(Self::MAX_PACKET));
+                out.push((len - 1) as u8);
+                out.extend_from_slice(&rest[..len]);
+                len
+            };
+
+            rest = &rest[len..];
+        }
+
+        Ok(out)
+    }
+}
+
+/// A transform for raw DEFLATE compressed data, as described in RFC 1951.
+///
+/// This requires the `deflate` feature.
+#[cfg(feature = "deflate")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Deflate {
+    /// The compression level used when encoding, from 0 (no compression) to
+    /// 10 (best compression).
+    pub level: u8,
+}
+
+#[cfg(feature = "deflate")]
+impl Default for Deflate {
+    fn default() -> Self {
+        Self { level: 6 }
+    }
+}
+
+#[cfg(feature = "deflate")]
+impl Transform for Deflate {
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        miniz_oxide::inflate::decompress_to_vec(data)
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "invalid DEFLATE data"))
+    }
+
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(miniz_oxide::deflate::compress_to_vec(data, self.level))
+    }
+}
+
+/// A transform for zlib compressed data, as described in RFC 1950.
+///
+/// This requires the `deflate` feature.
+#[cfg(feature = "deflate")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Zlib {
+    /// The compression level used when encoding, from 0 (no compression) to
+    /// 10 (best compression).
+    pub level: u8,
+}
+
+#[cfg(feature = "deflate")]
+impl Default for Zlib {
+    fn default() -> Self {
+        Self { level: 6 }
+    }
+}
+
+#[cfg(feature = "deflate")]
+impl Transform for Zlib {
+    fn decode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        miniz_oxide::inflate::decompress_to_vec_zlib(data)
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "invalid zlib data"))
+    }
+
+    fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(miniz_oxide::deflate::compress_to_vec_zlib(data, self.level))
+    }
+}
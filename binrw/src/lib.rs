@@ -130,7 +130,10 @@
 //! Implementations for all primitive data types, arrays, tuples, and standard
 //! Rust types like [`Vec`] are included, along with parsers for other
 //! frequently used binary data patterns like
-//! [null-terminated strings](NullString) and
+//! [null-terminated](NullString), [fixed-length](FixedString), and
+//! [length-prefixed](PrefixedString) strings,
+//! [length-prefixed lists](PrefixedVec),
+//! [variable-length integers](ULeb128), and
 //! [indirect addressing using offsets](FilePtr). Convenient access into
 //! bitfields is possible using crates like
 //! [modular-bitfield](attribute#using-map-on-a-struct-to-create-a-bit-field).
@@ -170,7 +173,12 @@ use alloc::vec::Vec;
 #[path = "private.rs"]
 pub mod __private;
 
+#[cfg(feature = "async")]
+pub mod asynchronous;
 pub mod attribute;
+mod bulk;
+pub mod checksum;
+pub mod encoding;
 pub mod endian;
 pub mod error;
 pub mod file_ptr;
@@ -181,9 +189,13 @@ pub mod io;
 
 #[doc(hidden)]
 pub mod pos_value;
+#[doc(hidden)]
+pub mod prefixed;
 pub mod punctuated;
 #[doc(hidden)]
 pub mod strings;
+#[doc(hidden)]
+pub mod varint;
 
 #[doc(inline)]
 pub use {
@@ -193,9 +205,18 @@ pub use {
     has_magic::HasMagic,
     helpers::{count, until, until_eof, until_exclusive},
     pos_value::PosValue,
-    strings::{NullString, NullWideString},
+    prefixed::{LengthPrefix, PrefixedArgs, PrefixedVec},
+    strings::{
+        EncodedString, EncodedStringArgs, FixedString, FixedStringArgs, FixedWideString,
+        FixedWideStringArgs, NullString, NullWideString, PrefixedString, RawString,
+    },
+    varint::{SLeb128, ULeb128, VarInt, ZigZag, ZigZagInt},
 };
 
+#[cfg(feature = "async")]
+#[doc(inline)]
+pub use asynchronous::{AsyncBinRead, AsyncBinReaderExt, AsyncBinWrite, AsyncBinWriterExt};
+
 /// The derive macro for [`BinRead`].
 pub use binrw_derive::BinRead;
 
@@ -206,6 +227,9 @@ pub use binrw_derive::binread;
 /// The derive macro for [`BinWrite`].
 pub use binrw_derive::BinWrite;
 
+/// The derive macro for [`BinSize`].
+pub use binrw_derive::BinSize;
+
 /// The attribute version of the derive macro for [`BinRead`]. Use this instead
 /// of `#[derive(BinRead)]` to enable [temporary variables](attribute#temp).
 pub use binrw_derive::binwrite;
@@ -1,8 +1,10 @@
 use crate::{
-    io::{Read, Seek},
-    BinRead, BinResult, ReadOptions,
+    io::{Read, Seek, Write},
+    BinRead, BinResult, BinWrite, Error, ReadOptions, StreamRead, WriteOptions,
 };
-use core::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::format;
+use core::{cell::Cell, fmt};
 
 /// A wrapper that stores a value’s position alongside the value.
 ///
@@ -21,6 +23,11 @@ use core::fmt;
 /// assert_eq!(val.b.pos, 2);
 /// assert_eq!(*val.b, 0xFD);
 /// ```
+///
+/// When written, only the inner value is written. Use
+/// [`write_checked`](Self::write_checked) to also verify that the value ends
+/// up at its stored position, or [`write_recorded`](Self::write_recorded) to
+/// record the position it was written at.
 pub struct PosValue<T> {
     /// The read value.
     pub val: T,
@@ -34,7 +41,7 @@ impl<T: BinRead> BinRead for PosValue<T> {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: T::Args,
     ) -> BinResult<Self> {
         let pos = reader.stream_position()?;
@@ -48,13 +55,122 @@ impl<T: BinRead> BinRead for PosValue<T> {
     fn after_parse<R: Read + Seek>(
         &mut self,
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
         self.val.after_parse(reader, options, args)
     }
 }
 
+impl<T: StreamRead> StreamRead for PosValue<T> {}
+
+impl<T: BinWrite> BinWrite for PosValue<T> {
+    type Args = T::Args;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        self.val.write_options(writer, options, args)
+    }
+}
+
+impl<T: BinWrite> PosValue<T> {
+    /// Writes the inner value, returning an error if the writer is not at the
+    /// stored position.
+    ///
+    /// This can be used with [`write_with`] to check that a value written
+    /// back out ends up where it was originally read from.
+    ///
+    /// [`write_with`]: crate::attribute::write#custom-writers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use binrw::{binrw, io::Cursor, BinReaderExt, BinWriterExt, PosValue};
+    /// #[binrw]
+    /// struct MyType {
+    ///     a: u16,
+    ///     #[bw(write_with = PosValue::write_checked)]
+    ///     b: PosValue<u8>,
+    /// }
+    ///
+    /// let mut val = Cursor::new(b"\xFF\xFE\xFD").read_be::<MyType>().unwrap();
+    /// let mut out = Cursor::new(Vec::new());
+    /// out.write_be(&val).unwrap();
+    /// assert_eq!(out.into_inner(), b"\xFF\xFE\xFD");
+    ///
+    /// val.b.pos = 0;
+    /// assert!(Cursor::new(Vec::new()).write_be(&val).is_err());
+    /// ```
+    pub fn write_checked<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: T::Args,
+    ) -> BinResult<()> {
+        self.check_pos(writer.stream_position()?)?;
+        self.val.write_options(writer, options, args)
+    }
+
+    /// Writes the inner value, storing the position it was written at in the
+    /// given [`Cell`].
+    ///
+    /// Since writers only borrow the value being written, the position is
+    /// stored outside of it. This can be used with [`write_with`] by passing
+    /// the `Cell` and the arguments for the inner value as `args`, for example
+    /// from the [`context`].
+    ///
+    /// [`write_with`]: crate::attribute::write#custom-writers
+    /// [`context`]: crate::attribute::write#context
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use binrw::{binwrite, io::Cursor, BinWrite, Endian, PosValue, WriteOptions};
+    /// # use core::cell::Cell;
+    /// #[binwrite]
+    /// #[bw(context(b_pos: Cell<u64>))]
+    /// struct MyType {
+    ///     a: u16,
+    ///     #[bw(write_with = PosValue::write_recorded, args(b_pos, ()))]
+    ///     b: PosValue<u8>,
+    /// }
+    ///
+    /// let val = MyType { a: 1, b: PosValue { val: 2, pos: 0 } };
+    /// let b_pos = Cell::new(0u64);
+    /// let mut out = Cursor::new(Vec::new());
+    /// let options = WriteOptions::new(Endian::Big).with_context(&b_pos);
+    /// val.write_options(&mut out, &options, ()).unwrap();
+    /// assert_eq!(b_pos.get(), 2);
+    /// ```
+    pub fn write_recorded<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        (pos, args): (&Cell<u64>, T::Args),
+    ) -> BinResult<()> {
+        pos.set(writer.stream_position()?);
+        self.val.write_options(writer, options, args)
+    }
+}
+
+impl<T> PosValue<T> {
+    /// Returns an error if `pos` is not the stored position.
+    pub(crate) fn check_pos(&self, pos: u64) -> BinResult<()> {
+        if pos == self.pos {
+            Ok(())
+        } else {
+            Err(Error::AssertFail {
+                pos,
+                message: format!("expected value to be written at {:#x}", self.pos),
+            })
+        }
+    }
+}
+
 impl<T> core::ops::Deref for PosValue<T> {
     type Target = T;
 
@@ -0,0 +1,367 @@
+//! Type definitions for length-prefixed values.
+
+use crate::{
+    __private::finish_bounded,
+    binwrite::size::in_place_size,
+    bulk,
+    io::{BoundedReader, Read, Seek, Write},
+    BinRead, BinResult, BinWrite, Error, ReadOptions, VecArgs, WriteOptions,
+};
+use binrw_derive::BinrwNamedArgs;
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+/// A type which can be used as the length prefix of a [`PrefixedVec`] or
+/// [`PrefixedString`](crate::PrefixedString).
+pub trait LengthPrefix: BinRead<Args = ()> + BinWrite<Args = ()> {
+    /// Converts a length into a prefix, or returns `None` if the length is too
+    /// large to be stored in this type.
+    fn from_len(len: usize) -> Option<Self>;
+
+    /// Converts this prefix into a length, or returns `None` if it is too
+    /// large to be a `usize`.
+    fn to_len(&self) -> Option<usize>;
+}
+
+macro_rules! length_prefix_impl {
+    ($($type_name:ty),*) => {
+        $(
+            impl LengthPrefix for $type_name {
+                fn from_len(len: usize) -> Option<Self> {
+                    core::convert::TryFrom::try_from(len).ok()
+                }
+
+                fn to_len(&self) -> Option<usize> {
+                    core::convert::TryFrom::try_from(*self).ok()
+                }
+            }
+        )*
+    };
+}
+
+length_prefix_impl!(u8, u16, u32, u64, u128);
+
+/// Arguments for reading and writing a [`PrefixedVec`] or
+/// [`PrefixedString`](crate::PrefixedString).
+#[derive(BinrwNamedArgs, Clone)]
+pub struct PrefixedArgs<B> {
+    /// Whether the length prefix counts bytes instead of elements. Defaults to
+    /// `false`. Strings always count bytes.
+    #[named_args(default = false)]
+    pub count_bytes: bool,
+
+    /// Whether the length prefix also counts the bytes of the prefix itself.
+    /// This can only be used when counting bytes. Defaults to `false`.
+    #[named_args(default = false)]
+    pub includes_prefix: bool,
+
+    /// Arguments to pass to the inner type
+    #[named_args(try_optional)]
+    pub inner: B,
+}
+
+impl<B: Default> Default for PrefixedArgs<B> {
+    fn default() -> Self {
+        Self {
+            count_bytes: false,
+            includes_prefix: false,
+            inner: B::default(),
+        }
+    }
+}
+
+/// A list of values of type `T` which is preceded by its length, stored as a
+/// value of type `L`.
+///
+/// By default, the length is the number of elements in the list. With
+/// [`PrefixedArgs`], the length can instead count the bytes of the list, and
+/// can also include the size of the length prefix itself. When counting bytes,
+/// elements are read until exactly that many bytes have been read, and an
+/// element which would continue past the end of the list returns a
+/// [`SizeExceeded`](Error::SizeExceeded) error.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{binrw, BinReaderExt, BinWriterExt, PrefixedVec, io::Cursor};
+///
+/// #[binrw]
+/// #[brw(big)]
+/// struct Message {
+///     ids: PrefixedVec<u8, u16>,
+///     #[brw(args { count_bytes: true, includes_prefix: true })]
+///     data: PrefixedVec<u16, u16>,
+/// }
+///
+/// let mut data = Cursor::new(b"\x02\0\x01\0\x02\0\x06\0\x03\0\x04");
+/// let message: Message = data.read_be().unwrap();
+/// assert_eq!(*message.ids, [1, 2]);
+/// assert_eq!(*message.data, [3, 4]);
+///
+/// let mut out = Cursor::new(Vec::new());
+/// out.write_be(&message).unwrap();
+/// assert_eq!(out.into_inner(), data.into_inner());
+/// ```
+pub struct PrefixedVec<L, T> {
+    /// The values in the list.
+    pub value: Vec<T>,
+    prefix: PhantomData<L>,
+}
+
+impl<L, T> PrefixedVec<L, T> {
+    /// Creates a new list with the given values.
+    pub fn new(value: Vec<T>) -> Self {
+        Self {
+            value,
+            prefix: PhantomData,
+        }
+    }
+
+    /// Consumes this list, returning the values in it.
+    pub fn into_inner(self) -> Vec<T> {
+        self.value
+    }
+}
+
+impl<L, T> From<Vec<T>> for PrefixedVec<L, T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<L, T> From<PrefixedVec<L, T>> for Vec<T> {
+    fn from(list: PrefixedVec<L, T>) -> Self {
+        list.value
+    }
+}
+
+impl<L, T: Clone> Clone for PrefixedVec<L, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<L, T: PartialEq> PartialEq for PrefixedVec<L, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<L, T> Default for PrefixedVec<L, T> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<L, T: fmt::Debug> fmt::Debug for PrefixedVec<L, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PrefixedVec").field(&self.value).finish()
+    }
+}
+
+impl<L, T> Deref for PrefixedVec<L, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<L, T> DerefMut for PrefixedVec<L, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<L: LengthPrefix, T: BinRead> BinRead for PrefixedVec<L, T> {
+    type Args = PrefixedArgs<T::Args>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        read_prefixed::<L, T, R>(reader, options, args).map(Self::new)
+    }
+}
+
+impl<L: LengthPrefix, T: BinWrite + 'static> BinWrite for PrefixedVec<L, T> {
+    type Args = PrefixedArgs<T::Args>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        write_prefixed::<L, T, _, _>(&self.value, self.value.len(), writer, options, args)
+    }
+}
+
+/// Returns whether the length prefix of a list of `T` counts bytes, or an
+/// error if the arguments are invalid.
+pub(crate) fn counts_bytes<T: 'static, B>(args: &PrefixedArgs<B>, pos: u64) -> BinResult<bool> {
+    let count_bytes = args.count_bytes || bulk::primitive_size::<T>() == Some(1);
+    if args.includes_prefix && !count_bytes {
+        return Err(Error::AssertFail {
+            pos,
+            message: "a length prefix can only include itself when counting bytes".into(),
+        });
+    }
+
+    Ok(count_bytes)
+}
+
+/// Reads a length prefix of type `L` followed by the list of `T` it describes.
+pub(crate) fn read_prefixed<L, T, R>(
+    reader: &mut R,
+    options: &ReadOptions<'_>,
+    args: PrefixedArgs<T::Args>,
+) -> BinResult<Vec<T>>
+where
+    L: LengthPrefix,
+    T: BinRead,
+    R: Read + Seek,
+{
+    let pos = reader.stream_position()?;
+    let count_bytes = counts_bytes::<T, _>(&args, pos)?;
+    let prefix = L::read_options(reader, options, ())?;
+    let prefix_size = reader.stream_position()? - pos;
+    let len = prefix_len(&prefix, prefix_size, args.includes_prefix, pos)?;
+
+    if !count_bytes {
+        return <Vec<T>>::read_options(
+            reader,
+            options,
+            VecArgs {
+                count: len,
+                inner: args.inner,
+            },
+        );
+    }
+
+    let mut reader = BoundedReader::new(reader, len as u64)?;
+    let result = match bulk::primitive_size::<T>() {
+        Some(size) => <Vec<T>>::read_options(
+            &mut reader,
+            options,
+            VecArgs {
+                count: len / size,
+                inner: args.inner.clone(),
+            },
+        )
+        .and_then(|list| {
+            // Reading the partial element that is left over reports the error
+            if len % size == 0 {
+                Ok(list)
+            } else {
+                T::read_options(&mut reader, options, args.inner).map(|_| list)
+            }
+        }),
+        None => (|| {
+            let mut list = Vec::new();
+            while reader.stream_position()? < reader.end() {
+                list.push(T::read_options(&mut reader, options, args.inner.clone())?);
+            }
+            Ok(list)
+        })(),
+    };
+    finish_bounded(reader, result)
+}
+
+/// Returns the length of the list described by a prefix of `prefix_size`
+/// bytes which was read from `pos`.
+pub(crate) fn prefix_len<L: LengthPrefix>(
+    prefix: &L,
+    prefix_size: u64,
+    includes_prefix: bool,
+    pos: u64,
+) -> BinResult<usize> {
+    let len = prefix.to_len().ok_or_else(|| Error::AssertFail {
+        pos,
+        message: "length prefix is too large".into(),
+    })?;
+
+    if !includes_prefix {
+        return Ok(len);
+    }
+
+    let prefix_size = prefix_size as usize;
+    len.checked_sub(prefix_size)
+        .ok_or_else(|| Error::AssertFail {
+            pos,
+            message: format!(
+                "length {} is smaller than its {} byte prefix",
+                len, prefix_size
+            ),
+        })
+}
+
+/// Writes a length prefix of type `L` followed by `value`, which is a list of
+/// `count` elements of type `T`.
+pub(crate) fn write_prefixed<L, T, V, W>(
+    value: &V,
+    count: usize,
+    writer: &mut W,
+    options: &WriteOptions<'_>,
+    args: PrefixedArgs<T::Args>,
+) -> BinResult<()>
+where
+    L: LengthPrefix,
+    T: BinWrite + 'static,
+    V: BinWrite<Args = T::Args> + ?Sized,
+    W: Write + Seek,
+{
+    let pos = writer.stream_position()?;
+    let prefix = length_prefix::<L, T, V>(value, count, pos, options, &args)?;
+    prefix.write_options(writer, options, ())?;
+    value.write_options(writer, options, args.inner)
+}
+
+/// Returns the length prefix of type `L` for `value`, which is a list of
+/// `count` elements of type `T` that will be written at `pos`.
+pub(crate) fn length_prefix<L, T, V>(
+    value: &V,
+    count: usize,
+    pos: u64,
+    options: &WriteOptions<'_>,
+    args: &PrefixedArgs<T::Args>,
+) -> BinResult<L>
+where
+    L: LengthPrefix,
+    T: BinWrite + 'static,
+    V: BinWrite<Args = T::Args> + ?Sized,
+{
+    let len = if counts_bytes::<T, _>(args, pos)? {
+        in_place_size(value, pos, options, args.inner.clone())? as usize
+    } else {
+        count
+    };
+
+    // The size of a variable-length prefix depends on its value, so keep
+    // adding it to the length until it stops changing
+    let mut prefix_size = 0;
+    loop {
+        let total = len.saturating_add(prefix_size);
+        let prefix = L::from_len(total).ok_or_else(|| Error::AssertFail {
+            pos,
+            message: format!("length {} is too large for its prefix", total),
+        })?;
+
+        if !args.includes_prefix {
+            return Ok(prefix);
+        }
+
+        let size = in_place_size(&prefix, pos, options, ())? as usize;
+        if size == prefix_size {
+            return Ok(prefix);
+        }
+        prefix_size = size;
+    }
+}
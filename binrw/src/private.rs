@@ -1,10 +1,12 @@
 use crate::{
+    checksum::Checksum,
     error::CustomError,
-    io::{self, Seek, Write},
-    BinRead, BinResult, Error, ReadOptions, WriteOptions,
+    io::{self, transform::Transform, BitValue, BoundedReader, Read, Seek, SeekFrom, Write},
+    BinRead, BinResult, Endian, Error, ReadOptions, WriteOptions,
 };
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, string::String};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{convert::TryFrom, ops::Range};
 
 pub enum AssertErrorFn<M, E> {
     Message(M),
@@ -38,6 +40,245 @@ where
     }
 }
 
+pub fn require_context<T: core::any::Any>(context: Option<&T>, pos: u64) -> BinResult<&T> {
+    context.ok_or(Error::MissingContext {
+        pos,
+        type_name: core::any::type_name::<T>(),
+    })
+}
+
+pub fn version_in_range<V: PartialOrd>(version: V, since: Option<V>, until: Option<V>) -> bool {
+    since.is_none_or(|since| version >= since) && until.is_none_or(|until| version < until)
+}
+
+/// A value which can be used in a `count` directive.
+pub trait IntoCount {
+    fn into_count(self) -> usize;
+}
+
+macro_rules! into_count_impl {
+    ($($type_name:ty),*) => {
+        $(
+            impl IntoCount for $type_name {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                fn into_count(self) -> usize {
+                    self as usize
+                }
+            }
+        )*
+    };
+}
+
+into_count_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: IntoCount + Copy> IntoCount for &T {
+    fn into_count(self) -> usize {
+        (*self).into_count()
+    }
+}
+
+pub fn into_count<T: IntoCount>(count: T) -> usize {
+    count.into_count()
+}
+
+pub fn check_backpatch(pos: u64, reserved_end: u64, written_end: u64) -> BinResult<()> {
+    if written_end == reserved_end {
+        Ok(())
+    } else {
+        Err(Error::BackpatchSize {
+            pos,
+            reserved: reserved_end - pos,
+            written: written_end - pos,
+        })
+    }
+}
+
+/// Skips the rest of the bounded region after a field was read successfully,
+/// or reports a read past its end as an error for the whole field.
+pub fn finish_bounded<R, T>(mut reader: BoundedReader<R>, result: BinResult<T>) -> BinResult<T>
+where
+    R: Seek,
+{
+    match result {
+        Ok(value) => {
+            let end = reader.end();
+            reader.seek(SeekFrom::Start(end))?;
+            Ok(value)
+        }
+        Err(error) if error.is_eof() && reader.is_overread() => Err(Error::SizeExceeded {
+            pos: reader.start(),
+            size: reader.end() - reader.start(),
+        }),
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the number of bytes of padding needed after a field which was
+/// written from `start` to `end` to make it `size` bytes long.
+pub fn size_padding(start: u64, size: u64, end: u64) -> BinResult<u64> {
+    size.checked_sub(end.saturating_sub(start))
+        .ok_or(Error::SizeExceeded { pos: start, size })
+}
+
+const CHECKSUM_BUF_SIZE: usize = 0x100;
+
+pub fn verify_checksum<C, R>(
+    reader: &mut R,
+    range: Range<u64>,
+    pos: u64,
+    found: &C::Output,
+) -> BinResult<()>
+where
+    C: Checksum,
+    R: Read + Seek,
+{
+    let restore = reader.stream_position()?;
+    reader.seek(SeekFrom::Start(range.start))?;
+    let mut checksum = C::default();
+    let mut buf = [0; CHECKSUM_BUF_SIZE];
+    let mut remaining = range.end.saturating_sub(range.start);
+    while remaining != 0 {
+        let len = usize::try_from(remaining).map_or(buf.len(), |len| len.min(buf.len()));
+        reader.read_exact(&mut buf[..len])?;
+        checksum.update(&buf[..len]);
+        remaining -= len as u64;
+    }
+    reader.seek(SeekFrom::Start(restore))?;
+
+    let expected = checksum.finish();
+    if expected == *found {
+        Ok(())
+    } else {
+        Err(Error::BadChecksum {
+            pos,
+            expected: expected.into(),
+            found: (*found).into(),
+        })
+    }
+}
+
+/// A writer which keeps a copy of everything written at or after its starting
+/// position, so checksums can be computed over data which was already written
+/// to a writer that cannot be read back.
+pub struct ChecksumWriter<'a, W> {
+    inner: &'a mut W,
+    start: u64,
+    pos: u64,
+    data: Vec<u8>,
+}
+
+impl<'a, W: Write + Seek> ChecksumWriter<'a, W> {
+    pub fn new(inner: &'a mut W) -> io::Result<Self> {
+        let start = inner.stream_position()?;
+        Ok(Self {
+            inner,
+            start,
+            pos: start,
+            data: Vec::new(),
+        })
+    }
+
+    pub fn checksum<C: Checksum>(&self, range: Range<u64>) -> C::Output {
+        let index =
+            |pos: u64| usize::try_from(pos.saturating_sub(self.start)).unwrap_or(usize::MAX);
+        let (start, end) = (index(range.start), index(range.end));
+        let mut checksum = C::default();
+        checksum.update(&self.data[start.min(self.data.len())..end.min(self.data.len())]);
+
+        // Anything skipped over without being written reads back as zeroes
+        let mut skipped = end.saturating_sub(start.max(self.data.len()));
+        while skipped != 0 {
+            let len = skipped.min(CHECKSUM_BUF_SIZE);
+            checksum.update(&[0; CHECKSUM_BUF_SIZE][..len]);
+            skipped -= len;
+        }
+
+        checksum.finish()
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        let skip = usize::try_from(self.start.saturating_sub(self.pos))
+            .unwrap_or(usize::MAX)
+            .min(written);
+        let offset = usize::try_from(self.pos.saturating_sub(self.start)).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "checksummed data is too large")
+        })?;
+        let data = &buf[skip..written];
+        let end = offset + data.len();
+        if self.data.len() < end {
+            self.data.resize(end, 0);
+        }
+        self.data[offset..end].copy_from_slice(data);
+        self.pos += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Seek> Seek for ChecksumWriter<'_, W> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.pos = self.inner.seek(pos)?;
+        Ok(self.pos)
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+/// The stream a transformed field is read from, or written to before it is
+/// encoded.
+pub type TransformStream = io::Cursor<Vec<u8>>;
+
+pub fn decode_transform<R, T>(
+    reader: &mut R,
+    transform: &T,
+    size: Option<u64>,
+) -> BinResult<TransformStream>
+where
+    R: Read,
+    T: Transform + ?Sized,
+{
+    let mut data = Vec::new();
+    if let Some(size) = size {
+        reader.take(size).read_to_end(&mut data)?;
+        if data.len() as u64 != size {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "not enough data for transformed region",
+            )
+            .into());
+        }
+    } else {
+        reader.read_to_end(&mut data)?;
+    }
+
+    Ok(io::Cursor::new(transform.decode(&data)?))
+}
+
+pub fn transform_stream() -> TransformStream {
+    io::Cursor::new(Vec::new())
+}
+
+pub fn encode_transform<W, T>(
+    writer: &mut W,
+    transform: &T,
+    stream: TransformStream,
+) -> BinResult<()>
+where
+    W: Write,
+    T: Transform + ?Sized,
+{
+    writer.write_all(&transform.encode(&stream.into_inner())?)?;
+    Ok(())
+}
+
 // This validates the map function return value by trying to coerce it into
 // a function with the expected return type. If this is not done, the
 // compiler will emit the diagnostic on the `#[derive(BinRead)]` attribute
@@ -52,7 +293,17 @@ where
     f
 }
 
-pub fn magic<R, B>(reader: &mut R, expected: B, options: &ReadOptions) -> BinResult<()>
+// Used by `stream` readers to require that the type read before a `map` or
+// `try_map` function is applied can also be read from a stream.
+pub fn stream_map_fn_type_hint<Input, Output, MapFn>(func: MapFn) -> MapFn
+where
+    MapFn: Fn(Input) -> Output,
+    Input: crate::StreamRead,
+{
+    func
+}
+
+pub fn magic<R, B>(reader: &mut R, expected: B, options: &ReadOptions<'_>) -> BinResult<()>
 where
     B: BinRead<Args = ()> + core::fmt::Debug + PartialEq + Sync + Send + 'static,
     R: io::Read + io::Seek,
@@ -69,18 +320,121 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+pub use crate::asynchronous::io::{seek as async_seek, stream_position as async_stream_position};
+
+#[cfg(feature = "async")]
+pub async fn magic_async<R, B>(
+    reader: &mut R,
+    expected: B,
+    options: &ReadOptions<'_>,
+) -> BinResult<()>
+where
+    B: crate::AsyncBinRead<Args = ()> + core::fmt::Debug + PartialEq + Sync + Send + 'static,
+    R: crate::asynchronous::AsyncRead + crate::asynchronous::AsyncSeek + Unpin,
+{
+    let pos = async_stream_position(reader).await?;
+    let val = B::read_options_async(reader, options, ()).await?;
+    if val == expected {
+        Ok(())
+    } else {
+        Err(Error::BadMagic {
+            pos,
+            found: Box::new(val) as _,
+        })
+    }
+}
+
+/// Reads a magic number which may be stored in either byte order, returning
+/// the byte order it was found in.
+pub fn magic_endian<R, const N: usize>(
+    reader: &mut R,
+    little: [u8; N],
+    big: [u8; N],
+    options: &ReadOptions<'_>,
+) -> BinResult<Endian>
+where
+    R: io::Read + io::Seek,
+{
+    let pos = reader.stream_position()?;
+    let found = <[u8; N]>::read_options(reader, options, ())?;
+    endian_from_magic(pos, found, little, big)
+}
+
+#[cfg(feature = "async")]
+pub async fn magic_endian_async<R, const N: usize>(
+    reader: &mut R,
+    little: [u8; N],
+    big: [u8; N],
+    options: &ReadOptions<'_>,
+) -> BinResult<Endian>
+where
+    R: crate::asynchronous::AsyncRead + crate::asynchronous::AsyncSeek + Unpin,
+{
+    let pos = async_stream_position(reader).await?;
+    let found = <[u8; N] as crate::AsyncBinRead>::read_options_async(reader, options, ()).await?;
+    endian_from_magic(pos, found, little, big)
+}
+
+fn endian_from_magic<const N: usize>(
+    pos: u64,
+    found: [u8; N],
+    little: [u8; N],
+    big: [u8; N],
+) -> BinResult<Endian> {
+    if found == little {
+        Ok(Endian::Little)
+    } else if found == big {
+        Ok(Endian::Big)
+    } else {
+        Err(Error::BadMagic {
+            pos,
+            found: Box::new(found) as _,
+        })
+    }
+}
+
+/// Returns the bytes of a magic number which may be stored in either byte
+/// order for the given byte order.
+pub fn magic_for_endian<const N: usize>(endian: Endian, little: [u8; N], big: [u8; N]) -> [u8; N] {
+    match endian {
+        Endian::Big => big,
+        Endian::Little => little,
+        Endian::Native if cfg!(target_endian = "big") => big,
+        Endian::Native => little,
+    }
+}
+
+/// Reads a `T` whose type is inferred from how the result is used, which is
+/// not possible when calling [`AsyncBinRead::read_options_async`] directly
+/// because the returned future must be awaited before its type is known.
+///
+/// [`AsyncBinRead::read_options_async`]: crate::AsyncBinRead::read_options_async
+#[cfg(feature = "async")]
+pub async fn read_async<T, R>(
+    reader: &mut R,
+    options: &ReadOptions<'_>,
+    args: T::Args,
+) -> BinResult<T>
+where
+    T: crate::AsyncBinRead,
+    R: crate::asynchronous::AsyncRead + crate::asynchronous::AsyncSeek + Unpin,
+{
+    T::read_options_async(reader, options, args).await
+}
+
 pub fn parse_function_args_type_hint<R, Res, Args, F>(_: F, a: Args) -> Args
 where
     R: crate::io::Read + Seek,
-    F: FnOnce(&mut R, &crate::ReadOptions, Args) -> crate::BinResult<Res>,
+    F: FnOnce(&mut R, &crate::ReadOptions<'_>, Args) -> crate::BinResult<Res>,
 {
     a
 }
 
-pub fn write_function_args_type_hint<T, W, Args, F>(_: F, a: Args) -> Args
+pub fn write_function_args_type_hint<T, W, Args, F>(_: F, _: &W, a: Args) -> Args
 where
     W: Write + Seek,
-    F: FnOnce(&T, &mut W, &crate::WriteOptions, Args) -> crate::BinResult<()>,
+    F: FnOnce(&T, &mut W, &crate::WriteOptions<'_>, Args) -> crate::BinResult<()>,
 {
     a
 }
@@ -93,11 +447,19 @@ where
     args
 }
 
+pub fn borrowed_map_args_type_hint<'a, Input, Output, MapFn, Args>(_: &MapFn, args: Args) -> Args
+where
+    MapFn: FnOnce(Input) -> Output,
+    Input: crate::BorrowedBinRead<'a, Args = Args>,
+{
+    args
+}
+
 pub fn write_fn_type_hint<T, WriterFn, Writer, Args>(x: WriterFn) -> WriterFn
 where
     Args: Clone,
     Writer: Write + Seek,
-    WriterFn: Fn(&T, &mut Writer, &WriteOptions, Args) -> BinResult<()>,
+    WriterFn: Fn(&T, &mut Writer, &WriteOptions<'_>, Args) -> BinResult<()>,
 {
     x
 }
@@ -133,7 +495,7 @@ where
     MapFn: FnOnce(Input) -> Output,
     Args: Clone,
     Writer: Write + Seek,
-    WriteFn: Fn(&Output, &mut Writer, &WriteOptions, Args) -> BinResult<()>,
+    WriteFn: Fn(&Output, &mut Writer, &WriteOptions<'_>, Args) -> BinResult<()>,
 {
     func
 }
@@ -146,11 +508,24 @@ where
     MapFn: FnOnce(Input) -> BinResult<Output>,
     Args: Clone,
     Writer: Write + Seek,
-    WriteFn: Fn(&Output, &mut Writer, &WriteOptions, Args) -> BinResult<()>,
+    WriteFn: Fn(&Output, &mut Writer, &WriteOptions<'_>, Args) -> BinResult<()>,
 {
     func
 }
 
+/// Creates the data section for a type using the `data_section` directive
+/// when it is written asynchronously, which cannot go through
+/// [`DataSection::write_after`](crate::file_ptr::DataSection::write_after).
+#[cfg(feature = "async")]
+pub fn data_section_after<T: crate::BinWrite + ?Sized>(
+    value: &T,
+    start: u64,
+    options: &WriteOptions<'_>,
+    args: T::Args,
+) -> BinResult<crate::file_ptr::DataSection> {
+    crate::file_ptr::DataSection::after(value, start, options, args)
+}
+
 pub fn write_zeroes<W: Write>(writer: &mut W, count: u64) -> BinResult<()> {
     const BUF_SIZE: u64 = 0x20;
     const ZEROES: [u8; BUF_SIZE as usize] = [0u8; BUF_SIZE as usize];
@@ -170,3 +545,90 @@ pub fn write_zeroes<W: Write>(writer: &mut W, count: u64) -> BinResult<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "async")]
+pub async fn write_zeroes_async<W>(writer: &mut W, count: u64) -> BinResult<()>
+where
+    W: crate::asynchronous::AsyncWrite + Unpin,
+{
+    const BUF_SIZE: u64 = 0x20;
+    const ZEROES: [u8; BUF_SIZE as usize] = [0u8; BUF_SIZE as usize];
+
+    let mut remaining = count;
+    while remaining != 0 {
+        let chunk = remaining.min(BUF_SIZE);
+        crate::asynchronous::io::write_all(writer, &ZEROES[..chunk as usize]).await?;
+        remaining -= chunk;
+    }
+
+    Ok(())
+}
+
+pub use crate::binwrite::size::{same_fixed_size, sum_fixed_sizes};
+pub use crate::io::bits::BitState;
+
+pub fn read_bits<R, T>(reader: &mut R, state: &mut BitState, count: u32) -> BinResult<T>
+where
+    R: Read,
+    T: BitValue,
+{
+    Ok(state.read(reader, count)?)
+}
+
+#[cfg(feature = "async")]
+pub async fn read_bits_async<R, T>(reader: &mut R, state: &mut BitState, count: u32) -> BinResult<T>
+where
+    R: crate::asynchronous::AsyncRead + Unpin,
+    T: BitValue,
+{
+    crate::io::bits::check_count::<T>(count)?;
+    let mut buf = [0; 8];
+    let buf = &mut buf[..state.bytes_needed(count)];
+    crate::asynchronous::io::read_exact(reader, buf).await?;
+    Ok(T::from_bits(state.take(buf, count), count))
+}
+
+pub fn write_bits<W, T>(
+    value: &T,
+    writer: &mut W,
+    state: &mut BitState,
+    count: u32,
+) -> BinResult<()>
+where
+    W: Write,
+    T: BitValue,
+{
+    Ok(state.write(writer, value, count)?)
+}
+
+#[cfg(feature = "async")]
+pub async fn write_bits_async<W, T>(
+    value: &T,
+    writer: &mut W,
+    state: &mut BitState,
+    count: u32,
+) -> BinResult<()>
+where
+    W: crate::asynchronous::AsyncWrite + Unpin,
+    T: BitValue,
+{
+    let bits = crate::io::bits::to_bits(value, count)?;
+    let mut buf = [0; 8];
+    let len = state.put(bits, count, &mut buf);
+    Ok(crate::asynchronous::io::write_all(writer, &buf[..len]).await?)
+}
+
+pub fn flush_bits<W: Write>(writer: &mut W, state: &mut BitState) -> BinResult<()> {
+    Ok(state.flush(writer)?)
+}
+
+#[cfg(feature = "async")]
+pub async fn flush_bits_async<W>(writer: &mut W, state: &mut BitState) -> BinResult<()>
+where
+    W: crate::asynchronous::AsyncWrite + Unpin,
+{
+    match state.finish() {
+        Some(byte) => Ok(crate::asynchronous::io::write_all(writer, &[byte]).await?),
+        None => Ok(()),
+    }
+}
@@ -1,9 +1,9 @@
 //! Type definitions for wrappers which parse interleaved data.
 
-use crate::io::{Read, Seek};
-use crate::{BinRead, BinResult, ReadOptions, VecArgs};
+use crate::io::{Read, Seek, Write};
+use crate::{BinRead, BinResult, BinWrite, Error, ReadOptions, VecArgs, WriteOptions};
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 use core::fmt;
 
 /// A parser for data which consists of values of type `T` interleaved with
@@ -37,7 +37,22 @@ use core::fmt;
 /// # assert_eq!(*y.x, vec![3, 2, 1]);
 /// # assert_eq!(y.x.separators, vec![0, 1]);
 /// ```
-pub struct Punctuated<T: BinRead, P: BinRead> {
+///
+/// When written, each value is followed by the separator stored at the same
+/// index, so values which were parsed with either strategy are written back
+/// out in the same layout. Writing fails if there is not exactly one separator
+/// between each value, plus an optional trailing separator.
+///
+/// ```rust
+/// # use binrw::{*, io::*};
+/// use binrw::punctuated::Punctuated;
+///
+/// let list = Punctuated::<u16, u8>::new(vec![3, 2, 1], vec![0, 1]);
+/// let mut out = Cursor::new(Vec::new());
+/// out.write_be(&list).unwrap();
+/// assert_eq!(out.into_inner(), b"\0\x03\0\0\x02\x01\0\x01");
+/// ```
+pub struct Punctuated<T, P> {
     /// The data values.
     data: Vec<T>,
 
@@ -45,6 +60,25 @@ pub struct Punctuated<T: BinRead, P: BinRead> {
     pub separators: Vec<P>,
 }
 
+impl<T, P> Punctuated<T, P> {
+    /// Creates a new list from the given values and separators.
+    pub fn new(data: Vec<T>, separators: Vec<P>) -> Self {
+        Self { data, separators }
+    }
+
+    /// Consumes this object, returning the data values while dropping the
+    /// separator values.
+    ///
+    /// If you never use the separator values, consider using the [`pad_after`]
+    /// directive to skip over data while parsing instead of reading it into
+    /// memory and then discarding it.
+    ///
+    /// [`pad_after`]: crate::attribute#padding-and-alignment
+    pub fn into_values(self) -> Vec<T> {
+        self.data
+    }
+}
+
 impl<T: BinRead, P: BinRead<Args = ()>> Punctuated<T, P> {
     /// Parses values of type `T` separated by values of type `P` without a
     /// trailing separator value.
@@ -71,7 +105,7 @@ impl<T: BinRead, P: BinRead<Args = ()>> Punctuated<T, P> {
     /// ```
     pub fn separated<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: VecArgs<T::Args>,
     ) -> BinResult<Self> {
         let mut data = Vec::with_capacity(args.count);
@@ -93,7 +127,7 @@ impl<T: BinRead, P: BinRead<Args = ()>> Punctuated<T, P> {
     /// Requires a count to be passed via `#[br(count)]`.
     pub fn separated_trailing<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: VecArgs<T::Args>,
     ) -> BinResult<Self> {
         let mut data = Vec::with_capacity(args.count);
@@ -106,27 +140,47 @@ impl<T: BinRead, P: BinRead<Args = ()>> Punctuated<T, P> {
 
         Ok(Self { data, separators })
     }
+}
 
-    /// Consumes this object, returning the data values while dropping the
-    /// separator values.
-    ///
-    /// If you never use the separator values, consider using the [`pad_after`]
-    /// directive to skip over data while parsing instead of reading it into
-    /// memory and then discarding it.
-    ///
-    /// [`pad_after`]: crate::attribute#padding-and-alignment
-    pub fn into_values(self) -> Vec<T> {
-        self.data
+impl<T: BinWrite, P: BinWrite<Args = ()>> BinWrite for Punctuated<T, P> {
+    type Args = T::Args;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        let separated = self.data.len().max(1) - 1;
+        if self.separators.len() != separated && self.separators.len() != self.data.len() {
+            return Err(Error::AssertFail {
+                pos: writer.stream_position()?,
+                message: format!(
+                    "cannot write {} values with {} separators",
+                    self.data.len(),
+                    self.separators.len()
+                ),
+            });
+        }
+
+        for (i, value) in self.data.iter().enumerate() {
+            value.write_options(writer, options, args.clone())?;
+            if let Some(separator) = self.separators.get(i) {
+                separator.write_options(writer, options, ())?;
+            }
+        }
+
+        Ok(())
     }
 }
 
-impl<T: BinRead + fmt::Debug, P: BinRead> fmt::Debug for Punctuated<T, P> {
+impl<T: fmt::Debug, P> fmt::Debug for Punctuated<T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.data.fmt(f)
     }
 }
 
-impl<T: BinRead, P: BinRead> core::ops::Deref for Punctuated<T, P> {
+impl<T, P> core::ops::Deref for Punctuated<T, P> {
     type Target = Vec<T>;
 
     fn deref(&self) -> &Self::Target {
@@ -134,7 +188,7 @@ impl<T: BinRead, P: BinRead> core::ops::Deref for Punctuated<T, P> {
     }
 }
 
-impl<T: BinRead, P: BinRead> core::ops::DerefMut for Punctuated<T, P> {
+impl<T, P> core::ops::DerefMut for Punctuated<T, P> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.data
     }
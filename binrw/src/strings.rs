@@ -1,10 +1,16 @@
 //! Type definitions for string readers.
 
 use crate::{
-    alloc::string::{FromUtf16Error, FromUtf8Error},
+    alloc::{
+        borrow::Cow,
+        string::{FromUtf16Error, FromUtf8Error},
+    },
+    encoding::{Encoding, ErrorMode},
     io::{Read, Seek, Write},
-    BinRead, BinResult, BinWrite, ReadOptions,
+    prefixed::{read_prefixed, write_prefixed, LengthPrefix, PrefixedArgs},
+    BinRead, BinResult, BinWrite, Endian, Error, ReadOptions, StreamRead, VecArgs, WriteOptions,
 };
+use binrw_derive::BinrwNamedArgs;
 
 #[cfg(not(feature = "std"))]
 use alloc::{
@@ -15,6 +21,7 @@ use alloc::{
 
 use core::{
     fmt,
+    marker::PhantomData,
     num::{NonZeroU16, NonZeroU8},
 };
 
@@ -23,7 +30,7 @@ impl BinRead for Vec<NonZeroU8> {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        _: &ReadOptions,
+        _: &ReadOptions<'_>,
         _: Self::Args,
     ) -> BinResult<Self> {
         reader
@@ -148,7 +155,7 @@ impl BinRead for Vec<NonZeroU16> {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         _: Self::Args,
     ) -> BinResult<Self> {
         let mut values = vec![];
@@ -168,7 +175,7 @@ impl BinRead for NullWideString {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<Self> {
         // https://github.com/rust-lang/rust-clippy/issues/6447
@@ -177,13 +184,15 @@ impl BinRead for NullWideString {
     }
 }
 
+impl StreamRead for NullWideString {}
+
 impl BinWrite for NullWideString {
     type Args = ();
 
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &crate::WriteOptions,
+        options: &crate::WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
         self.0.write_options(writer, options, args)?;
@@ -198,7 +207,7 @@ impl BinRead for NullString {
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
-        options: &ReadOptions,
+        options: &ReadOptions<'_>,
         args: Self::Args,
     ) -> BinResult<Self> {
         // https://github.com/rust-lang/rust-clippy/issues/6447
@@ -207,13 +216,15 @@ impl BinRead for NullString {
     }
 }
 
+impl StreamRead for NullString {}
+
 impl BinWrite for NullString {
     type Args = ();
 
     fn write_options<W: Write + Seek>(
         &self,
         writer: &mut W,
-        options: &crate::WriteOptions,
+        options: &crate::WriteOptions<'_>,
         args: Self::Args,
     ) -> BinResult<()> {
         self.0.write_options(writer, options, args)?;
@@ -262,3 +273,803 @@ impl ToString for NullWideString {
         String::from_utf16_lossy(self)
     }
 }
+
+/// A fixed-length 8-bit string.
+///
+/// Exactly `N` bytes are always read and written. By default, the string ends
+/// at the first null byte, and shorter strings are padded with null bytes when
+/// written. Both the terminator and the padding byte can be changed with
+/// [`FixedStringArgs`]; for example, space-padded fields which have no
+/// terminator can be read with `args { terminator: None, pad: b' ' }`.
+///
+/// The bytes after the end of the string are kept in
+/// [`trailing`](Self::trailing), and are written back as-is as long as the
+/// string and the trailing bytes still add up to `N` bytes, so reading and
+/// writing a string reproduces the original data exactly. Otherwise, the
+/// trailing bytes are ignored and new padding is written instead.
+///
+/// If a string is longer than `N` bytes, writing it returns a
+/// [`SizeExceeded`](Error::SizeExceeded) error, unless `truncate` is set, in
+/// which case only the first `N` bytes are written.
+///
+/// ```
+/// use binrw::{BinReaderExt, BinWriterExt, FixedString, io::Cursor};
+///
+/// let mut reader = Cursor::new(b"name\0abc");
+/// let name = reader.read_be::<FixedString<8>>().unwrap();
+/// assert_eq!(name.clone().into_string(), "name");
+/// assert_eq!(name.trailing, b"\0abc");
+///
+/// let mut writer = Cursor::new(Vec::new());
+/// writer.write_be(&name).unwrap();
+/// writer.write_be(&FixedString::<8>::from_string("new".into())).unwrap();
+/// assert_eq!(writer.into_inner(), b"name\0abcnew\0\0\0\0\0");
+/// ```
+#[derive(Clone, PartialEq, Default)]
+pub struct FixedString<const N: usize> {
+    /// The raw byte string, not including the terminator or padding.
+    pub value: Vec<u8>,
+
+    /// The bytes which came after the string when it was read, including the
+    /// terminator.
+    pub trailing: Vec<u8>,
+}
+
+/// A fixed-length 16-bit string.
+///
+/// Exactly `N` 16-bit characters are always read and written, in the byte
+/// order of the reader or writer. Otherwise, this works the same way as
+/// [`FixedString`], and is configured with [`FixedWideStringArgs`].
+///
+/// ```
+/// use binrw::{BinReaderExt, FixedWideString, io::Cursor};
+///
+/// let mut reader = Cursor::new(b"h\0i\0\0\0\0\0");
+/// let name = reader.read_le::<FixedWideString<4>>().unwrap();
+/// assert_eq!(name.into_string(), "hi");
+/// ```
+#[derive(Clone, PartialEq, Default)]
+pub struct FixedWideString<const N: usize> {
+    /// The raw wide string, not including the terminator or padding.
+    pub value: Vec<u16>,
+
+    /// The characters which came after the string when it was read, including
+    /// the terminator.
+    pub trailing: Vec<u16>,
+}
+
+/// Arguments for reading and writing a [`FixedString`].
+#[derive(BinrwNamedArgs, Clone)]
+pub struct FixedStringArgs {
+    /// The byte which ends the string, or `None` if the string only ends where
+    /// the padding starts. Defaults to a null byte.
+    #[named_args(default = Some(0))]
+    pub terminator: Option<u8>,
+
+    /// The byte used to fill the rest of the field after the terminator.
+    /// Defaults to a null byte.
+    #[named_args(default = 0)]
+    pub pad: u8,
+
+    /// Whether strings which are too long are truncated when written, instead
+    /// of returning an error. Truncating may split a multi-byte character.
+    #[named_args(default = false)]
+    pub truncate: bool,
+}
+
+impl Default for FixedStringArgs {
+    fn default() -> Self {
+        Self {
+            terminator: Some(0),
+            pad: 0,
+            truncate: false,
+        }
+    }
+}
+
+/// Arguments for reading and writing a [`FixedWideString`].
+#[derive(BinrwNamedArgs, Clone)]
+pub struct FixedWideStringArgs {
+    /// The character which ends the string, or `None` if the string only ends
+    /// where the padding starts. Defaults to a null character.
+    #[named_args(default = Some(0))]
+    pub terminator: Option<u16>,
+
+    /// The character used to fill the rest of the field after the terminator.
+    /// Defaults to a null character.
+    #[named_args(default = 0)]
+    pub pad: u16,
+
+    /// Whether strings which are too long are truncated when written, instead
+    /// of returning an error. Truncating may split a surrogate pair.
+    #[named_args(default = false)]
+    pub truncate: bool,
+}
+
+impl Default for FixedWideStringArgs {
+    fn default() -> Self {
+        Self {
+            terminator: Some(0),
+            pad: 0,
+            truncate: false,
+        }
+    }
+}
+
+impl<const N: usize> FixedString<N> {
+    pub fn from_string(s: String) -> Self {
+        Self {
+            value: s.into_bytes(),
+            trailing: Vec::new(),
+        }
+    }
+
+    pub fn into_string(self) -> String {
+        String::from_utf8_lossy(&self.value).into()
+    }
+
+    pub fn into_string_lossless(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.value)
+    }
+}
+
+impl<const N: usize> FixedWideString<N> {
+    pub fn from_string(s: String) -> Self {
+        Self {
+            value: s.encode_utf16().collect(),
+            trailing: Vec::new(),
+        }
+    }
+
+    pub fn into_string(self) -> String {
+        String::from_utf16_lossy(&self.value)
+    }
+
+    pub fn into_string_lossless(self) -> Result<String, FromUtf16Error> {
+        String::from_utf16(&self.value)
+    }
+}
+
+impl<const N: usize> From<FixedString<N>> for Vec<u8> {
+    fn from(s: FixedString<N>) -> Self {
+        s.value
+    }
+}
+
+impl<const N: usize> From<FixedWideString<N>> for Vec<u16> {
+    fn from(s: FixedWideString<N>) -> Self {
+        s.value
+    }
+}
+
+/// Reads `N` characters and splits them into the string and its trailing
+/// characters.
+fn read_fixed<C, R, const N: usize>(
+    reader: &mut R,
+    options: &ReadOptions<'_>,
+    terminator: Option<C>,
+    pad: C,
+) -> BinResult<(Vec<C>, Vec<C>)>
+where
+    C: BinRead<Args = ()> + PartialEq,
+    R: Read + Seek,
+{
+    let value = <Vec<C>>::read_options(
+        reader,
+        options,
+        VecArgs {
+            count: N,
+            inner: (),
+        },
+    )?;
+    Ok(split_fixed(value, terminator, pad))
+}
+
+/// Splits the characters of a fixed-length string into the string and its
+/// trailing characters.
+pub(crate) fn split_fixed<C: PartialEq>(
+    mut value: Vec<C>,
+    terminator: Option<C>,
+    pad: C,
+) -> (Vec<C>, Vec<C>) {
+    let len = match terminator {
+        Some(terminator) => value
+            .iter()
+            .position(|c| *c == terminator)
+            .unwrap_or(value.len()),
+        None => value.iter().rposition(|c| *c != pad).map_or(0, |i| i + 1),
+    };
+    let trailing = value.split_off(len);
+    (value, trailing)
+}
+
+/// Writes a string and its trailing characters as exactly `N` characters.
+fn write_fixed<C, W, const N: usize>(
+    value: &[C],
+    trailing: &[C],
+    writer: &mut W,
+    options: &WriteOptions<'_>,
+    terminator: Option<C>,
+    pad: C,
+    truncate: bool,
+) -> BinResult<()>
+where
+    C: BinWrite<Args = ()> + Copy + 'static,
+    W: Write + Seek,
+{
+    let (value, trailing) = match fixed_parts::<C, N>(value, trailing, terminator, pad, truncate) {
+        Some(parts) => parts,
+        None => return Err(fixed_size_exceeded::<C, N>(writer.stream_position()?)),
+    };
+
+    value.write_options(writer, options, ())?;
+    trailing.write_options(writer, options, ())
+}
+
+/// Returns the string and the trailing characters to write for a fixed-length
+/// string of `N` characters, or `None` if the string is too long and cannot be
+/// truncated.
+pub(crate) fn fixed_parts<'a, C: Copy, const N: usize>(
+    value: &'a [C],
+    trailing: &'a [C],
+    terminator: Option<C>,
+    pad: C,
+    truncate: bool,
+) -> Option<(&'a [C], Cow<'a, [C]>)> {
+    if value.len() + trailing.len() == N {
+        return Some((value, Cow::Borrowed(trailing)));
+    }
+
+    let value = if value.len() <= N {
+        value
+    } else if truncate {
+        &value[..N]
+    } else {
+        return None;
+    };
+
+    let mut padding = vec![pad; N - value.len()];
+    if let (Some(terminator), Some(first)) = (terminator, padding.first_mut()) {
+        *first = terminator;
+    }
+
+    Some((value, Cow::Owned(padding)))
+}
+
+/// Returns the error for a string written at `pos` which is too long for a
+/// fixed-length string of `N` characters.
+pub(crate) fn fixed_size_exceeded<C, const N: usize>(pos: u64) -> Error {
+    Error::SizeExceeded {
+        pos,
+        size: (N * core::mem::size_of::<C>()) as u64,
+    }
+}
+
+impl<const N: usize> BinRead for FixedString<N> {
+    type Args = FixedStringArgs;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        let (value, trailing) = read_fixed::<_, _, N>(reader, options, args.terminator, args.pad)?;
+        Ok(Self { value, trailing })
+    }
+}
+
+impl<const N: usize> StreamRead for FixedString<N> {}
+
+impl<const N: usize> BinWrite for FixedString<N> {
+    type Args = FixedStringArgs;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        write_fixed::<_, _, N>(
+            &self.value,
+            &self.trailing,
+            writer,
+            options,
+            args.terminator,
+            args.pad,
+            args.truncate,
+        )
+    }
+}
+
+impl<const N: usize> BinRead for FixedWideString<N> {
+    type Args = FixedWideStringArgs;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        let (value, trailing) = read_fixed::<_, _, N>(reader, options, args.terminator, args.pad)?;
+        Ok(Self { value, trailing })
+    }
+}
+
+impl<const N: usize> StreamRead for FixedWideString<N> {}
+
+impl<const N: usize> BinWrite for FixedWideString<N> {
+    type Args = FixedWideStringArgs;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        write_fixed::<_, _, N>(
+            &self.value,
+            &self.trailing,
+            writer,
+            options,
+            args.terminator,
+            args.pad,
+            args.truncate,
+        )
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FixedString<{}>({:?})", N, self.clone().into_string())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedWideString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "FixedWideString<{}>({:?})",
+            N,
+            self.clone().into_string()
+        )
+    }
+}
+
+impl<const N: usize> core::ops::Deref for FixedString<N> {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<const N: usize> core::ops::Deref for FixedWideString<N> {
+    type Target = Vec<u16>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// An 8-bit string which is preceded by its length in bytes, stored as a value
+/// of type `L`.
+///
+/// The length can also include the size of the length prefix itself by
+/// passing `args { includes_prefix: true }`; see [`PrefixedArgs`].
+///
+/// ```
+/// use binrw::{BinReaderExt, BinWriterExt, PrefixedString, io::Cursor};
+///
+/// let mut reader = Cursor::new(b"\x05hello\0\x02hi");
+/// let hello = reader.read_be::<PrefixedString<u8>>().unwrap();
+/// assert_eq!(hello.clone().into_string(), "hello");
+/// let hi = reader.read_be::<PrefixedString<u16>>().unwrap();
+/// assert_eq!(hi.into_string(), "hi");
+///
+/// let mut writer = Cursor::new(Vec::new());
+/// writer.write_be(&hello).unwrap();
+/// assert_eq!(writer.into_inner(), b"\x05hello");
+/// ```
+pub struct PrefixedString<L> {
+    /// The raw byte string.
+    pub value: Vec<u8>,
+    prefix: PhantomData<L>,
+}
+
+impl<L> PrefixedString<L> {
+    pub fn from_string(s: String) -> Self {
+        Self {
+            value: s.into_bytes(),
+            prefix: PhantomData,
+        }
+    }
+
+    pub fn into_string(self) -> String {
+        String::from_utf8_lossy(&self.value).into()
+    }
+
+    pub fn into_string_lossless(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.value)
+    }
+}
+
+impl<L> From<Vec<u8>> for PrefixedString<L> {
+    fn from(value: Vec<u8>) -> Self {
+        Self {
+            value,
+            prefix: PhantomData,
+        }
+    }
+}
+
+impl<L> From<PrefixedString<L>> for Vec<u8> {
+    fn from(s: PrefixedString<L>) -> Self {
+        s.value
+    }
+}
+
+impl<L> Clone for PrefixedString<L> {
+    fn clone(&self) -> Self {
+        self.value.clone().into()
+    }
+}
+
+impl<L> PartialEq for PrefixedString<L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<L> Default for PrefixedString<L> {
+    fn default() -> Self {
+        Vec::new().into()
+    }
+}
+
+impl<L: LengthPrefix> BinRead for PrefixedString<L> {
+    type Args = PrefixedArgs<()>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        read_prefixed::<L, u8, R>(reader, options, args).map(Into::into)
+    }
+}
+
+impl<L: LengthPrefix> BinWrite for PrefixedString<L> {
+    type Args = PrefixedArgs<()>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        write_prefixed::<L, u8, _, _>(&self.value, self.value.len(), writer, options, args)
+    }
+}
+
+impl<L> fmt::Debug for PrefixedString<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PrefixedString({:?})", self.clone().into_string())
+    }
+}
+
+impl<L> core::ops::Deref for PrefixedString<L> {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// A raw string type which stores code units, and can be used by an
+/// [`EncodedString`].
+pub trait RawString: Sized {
+    /// The type of the code units of the string.
+    type Unit;
+
+    /// Creates a string from code units.
+    fn from_units(units: Vec<Self::Unit>) -> Self;
+
+    /// Returns the code units of the string.
+    fn units(&self) -> &[Self::Unit];
+}
+
+impl RawString for NullString {
+    type Unit = u8;
+
+    fn from_units(units: Vec<u8>) -> Self {
+        Self(units)
+    }
+
+    fn units(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl RawString for NullWideString {
+    type Unit = u16;
+
+    fn from_units(units: Vec<u16>) -> Self {
+        Self(units)
+    }
+
+    fn units(&self) -> &[u16] {
+        &self.0
+    }
+}
+
+impl<const N: usize> RawString for FixedString<N> {
+    type Unit = u8;
+
+    fn from_units(units: Vec<u8>) -> Self {
+        Self {
+            value: units,
+            trailing: Vec::new(),
+        }
+    }
+
+    fn units(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl<const N: usize> RawString for FixedWideString<N> {
+    type Unit = u16;
+
+    fn from_units(units: Vec<u16>) -> Self {
+        Self {
+            value: units,
+            trailing: Vec::new(),
+        }
+    }
+
+    fn units(&self) -> &[u16] {
+        &self.value
+    }
+}
+
+impl<L> RawString for PrefixedString<L> {
+    type Unit = u8;
+
+    fn from_units(units: Vec<u8>) -> Self {
+        units.into()
+    }
+
+    fn units(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+/// A string which is stored as a raw string of type `S`, in the character
+/// encoding `E`.
+///
+/// The raw string type decides how the string is stored, like whether it ends
+/// with a null terminator or has a fixed length, and the
+/// [encoding](crate::encoding) decides how its code units are converted to and
+/// from a [`String`]. Errors in the encoding are handled according to the
+/// [`ErrorMode`] given in [`EncodedStringArgs`], and a byte order mark can be
+/// enabled with `bom`. Any other arguments are passed to the raw string type
+/// as `inner`.
+///
+/// ```
+/// use binrw::{
+///     binrw, encoding::{Utf16, Windows1252}, BinReaderExt, BinWriterExt, EncodedString,
+///     FixedWideString, NullString, io::Cursor,
+/// };
+///
+/// #[binrw]
+/// #[brw(little)]
+/// struct Track {
+///     artist: EncodedString<NullString, Windows1252>,
+///     #[brw(args { bom: true })]
+///     title: EncodedString<FixedWideString<4>, Utf16>,
+/// }
+///
+/// let data = b"Beyonc\xe9\0\xfe\xff\0h\0i\0\0";
+/// let track: Track = Cursor::new(data).read_le().unwrap();
+/// assert_eq!(*track.artist, "Beyoncé");
+/// assert_eq!(*track.title, "hi");
+///
+/// let mut out = Cursor::new(Vec::new());
+/// out.write_le(&track).unwrap();
+/// assert_eq!(out.into_inner(), b"Beyonc\xe9\0\xff\xfeh\0i\0\0\0");
+/// ```
+pub struct EncodedString<S, E> {
+    /// The decoded string.
+    pub value: String,
+    raw: PhantomData<fn() -> (S, E)>,
+}
+
+/// Arguments for reading and writing an [`EncodedString`].
+#[derive(BinrwNamedArgs, Clone)]
+pub struct EncodedStringArgs<B> {
+    /// How invalid data and characters which cannot be encoded are handled.
+    /// Defaults to [`ErrorMode::Strict`].
+    #[named_args(default = ErrorMode::Strict)]
+    pub errors: ErrorMode,
+
+    /// Whether the string starts with a byte order mark. Defaults to `false`.
+    ///
+    /// When reading, a byte order mark is removed if it is present, and for
+    /// UTF-16, decides the byte order of the string. When writing, a byte
+    /// order mark is always written. Encodings which have no byte order mark
+    /// ignore this.
+    #[named_args(default = false)]
+    pub bom: bool,
+
+    /// Arguments to pass to the raw string type
+    #[named_args(try_optional)]
+    pub inner: B,
+}
+
+impl<B: Default> Default for EncodedStringArgs<B> {
+    fn default() -> Self {
+        Self {
+            errors: ErrorMode::Strict,
+            bom: false,
+            inner: B::default(),
+        }
+    }
+}
+
+impl<S, E> EncodedString<S, E> {
+    /// Creates a new string with the given value.
+    pub fn new(value: String) -> Self {
+        Self {
+            value,
+            raw: PhantomData,
+        }
+    }
+
+    /// Consumes this wrapper, returning the decoded string.
+    pub fn into_string(self) -> String {
+        self.value
+    }
+}
+
+impl<S, E> From<String> for EncodedString<S, E> {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<S, E> From<&str> for EncodedString<S, E> {
+    fn from(value: &str) -> Self {
+        Self::new(value.into())
+    }
+}
+
+impl<S, E> From<EncodedString<S, E>> for String {
+    fn from(s: EncodedString<S, E>) -> Self {
+        s.value
+    }
+}
+
+impl<S, E> Clone for EncodedString<S, E> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<S, E> PartialEq for EncodedString<S, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<S, E> Default for EncodedString<S, E> {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+impl<S, E> EncodedString<S, E>
+where
+    S: RawString,
+    E: Encoding<Unit = S::Unit>,
+{
+    /// Decodes a raw string which was read from `pos`.
+    pub(crate) fn decode(
+        raw: &S,
+        endian: Endian,
+        bom: bool,
+        errors: ErrorMode,
+        pos: u64,
+    ) -> BinResult<Self> {
+        E::decode(raw.units(), endian, bom, errors)
+            .map(Self::new)
+            .ok_or(Error::BadEncoding {
+                pos,
+                encoding: E::NAME,
+            })
+    }
+
+    /// Encodes this string as a raw string to be written at `pos`.
+    pub(crate) fn encode(
+        &self,
+        endian: Endian,
+        bom: bool,
+        errors: ErrorMode,
+        pos: u64,
+    ) -> BinResult<S> {
+        E::encode(&self.value, endian, bom, errors)
+            .map(S::from_units)
+            .ok_or(Error::BadEncoding {
+                pos,
+                encoding: E::NAME,
+            })
+    }
+}
+
+impl<S, E> BinRead for EncodedString<S, E>
+where
+    S: BinRead + RawString,
+    E: Encoding<Unit = S::Unit>,
+{
+    type Args = EncodedStringArgs<S::Args>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ReadOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+        let raw = S::read_options(reader, options, args.inner)?;
+        Self::decode(&raw, options.endian(), args.bom, args.errors, pos)
+    }
+}
+
+impl<S, E> StreamRead for EncodedString<S, E>
+where
+    S: StreamRead + RawString,
+    E: Encoding<Unit = S::Unit>,
+{
+}
+
+impl<S, E> BinWrite for EncodedString<S, E>
+where
+    S: BinWrite + RawString,
+    E: Encoding<Unit = S::Unit>,
+{
+    type Args = EncodedStringArgs<S::Args>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions<'_>,
+        args: Self::Args,
+    ) -> BinResult<()> {
+        let pos = writer.stream_position()?;
+        self.encode(options.endian(), args.bom, args.errors, pos)?
+            .write_options(writer, options, args.inner)
+    }
+}
+
+impl<S, E> fmt::Debug for EncodedString<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EncodedString").field(&self.value).finish()
+    }
+}
+
+impl<S, E> fmt::Display for EncodedString<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl<S, E> core::ops::Deref for EncodedString<S, E> {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<S, E> core::ops::DerefMut for EncodedString<S, E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
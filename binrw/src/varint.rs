@@ -0,0 +1,419 @@
+//! Type definitions for variable-length integers.
+
+use crate::{
+    __private::IntoCount,
+    io::{Read, Seek, Write},
+    prefixed::LengthPrefix,
+    BinRead, BinResult, BinWrite, Error, ReadOptions, StreamRead, WriteOptions,
+};
+use core::convert::TryFrom;
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+/// The maximum number of bytes in the LEB128 encoding of a 64-bit value.
+pub(crate) const MAX_LEN: usize = 10;
+
+/// An unsigned integer stored as
+/// [unsigned LEB128](https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128),
+/// as used by DWARF and WebAssembly.
+///
+/// Each byte stores 7 bits of the value, starting with the least significant
+/// bits, and has its high bit set if more bytes follow. The encoding does not
+/// depend on the byte order.
+///
+/// Reading a value which does not fit in a `u64`, or which is encoded with
+/// more bytes than needed, returns an [`AssertFail`](Error::AssertFail) error
+/// with the position of the start of the value.
+///
+/// `ULeb128` can be used as the source of a [`count`](crate::attribute#count)
+/// directive, and as the length prefix of a [`PrefixedVec`](crate::PrefixedVec)
+/// or [`PrefixedString`](crate::PrefixedString).
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, BinReaderExt, ULeb128, io::Cursor};
+///
+/// #[derive(BinRead)]
+/// struct Section {
+///     len: ULeb128,
+///     #[br(count = len)]
+///     data: Vec<u8>,
+/// }
+///
+/// let section: Section = Cursor::new(b"\x03\x01\x02\x03").read_le().unwrap();
+/// assert_eq!(section.data, [1, 2, 3]);
+///
+/// let value: ULeb128 = Cursor::new(b"\xe5\x8e\x26").read_le().unwrap();
+/// assert_eq!(value.0, 624485);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ULeb128(pub u64);
+
+/// A signed integer stored as
+/// [signed LEB128](https://en.wikipedia.org/wiki/LEB128#Signed_LEB128), as
+/// used by DWARF and WebAssembly.
+///
+/// This is encoded like [`ULeb128`], except that the last byte is
+/// sign-extended. Reading a value which does not fit in an `i64`, or which is
+/// encoded with more bytes than needed, returns an
+/// [`AssertFail`](Error::AssertFail) error with the position of the start of
+/// the value.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinReaderExt, SLeb128, io::Cursor};
+///
+/// let value: SLeb128 = Cursor::new(b"\xc0\xbb\x78").read_le().unwrap();
+/// assert_eq!(value.0, -123456);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SLeb128(pub i64);
+
+/// An unsigned integer stored as a
+/// [Protocol Buffers varint](https://developers.google.com/protocol-buffers/docs/encoding#varints).
+///
+/// This encoding is identical to unsigned LEB128. Signed values can be stored
+/// as a [`ZigZag`] varint.
+pub type VarInt = ULeb128;
+
+/// A signed integer stored as a
+/// [zigzag-encoded](https://developers.google.com/protocol-buffers/docs/encoding#signed-ints)
+/// [`VarInt`], as used by the `sint32` and `sint64` types of Protocol Buffers.
+///
+/// Zigzag encoding maps signed integers to unsigned integers so that values
+/// with a small magnitude have a short encoding: 0 is stored as 0, -1 as 1, 1
+/// as 2, and so on. Reading a value which does not fit in `T` returns an
+/// [`AssertFail`](Error::AssertFail) error with the position of the start of
+/// the value.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinReaderExt, BinWriterExt, ZigZag, io::Cursor};
+///
+/// let value: ZigZag<i32> = Cursor::new(b"\x03").read_le().unwrap();
+/// assert_eq!(value.0, -2);
+///
+/// let mut out = Cursor::new(Vec::new());
+/// out.write_le(&ZigZag(-65i32)).unwrap();
+/// assert_eq!(out.into_inner(), b"\x81\x01");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZigZag<T>(pub T);
+
+/// A signed integer type which can be stored as a [`ZigZag`] varint.
+pub trait ZigZagInt: Copy + 'static {
+    /// Converts this value to its zigzag encoding.
+    fn to_zigzag(self) -> u64;
+
+    /// Converts a zigzag encoding back to a value, or returns `None` if it is
+    /// out of range for this type.
+    fn from_zigzag(value: u64) -> Option<Self>;
+}
+
+macro_rules! zigzag_int_impl {
+    ($($type_name:ty),*) => {
+        $(
+            impl ZigZagInt for $type_name {
+                #[allow(clippy::cast_sign_loss)]
+                fn to_zigzag(self) -> u64 {
+                    let value = i64::from(self);
+                    ((value << 1) ^ (value >> 63)) as u64
+                }
+
+                #[allow(clippy::cast_possible_wrap)]
+                fn from_zigzag(value: u64) -> Option<Self> {
+                    let value = ((value >> 1) as i64) ^ -((value & 1) as i64);
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+zigzag_int_impl!(i8, i16, i32, i64);
+
+/// Returns the error for a value at `pos` which does not fit in `type_name`.
+fn overflow_error(pos: u64, type_name: &str) -> Error {
+    Error::AssertFail {
+        pos,
+        message: format!("variable-length integer is too large for {}", type_name),
+    }
+}
+
+/// Returns the error for a value at `pos` which uses more bytes than needed.
+fn overlong_error(pos: u64) -> Error {
+    Error::AssertFail {
+        pos,
+        message: "variable-length integer has an overlong encoding".into(),
+    }
+}
+
+fn read_byte<R: Read>(reader: &mut R) -> BinResult<u8> {
+    let mut byte = [0];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+/// Reads an unsigned LEB128 value which starts at `pos`.
+pub(crate) fn read_unsigned<R: Read>(reader: &mut R, pos: u64) -> BinResult<u64> {
+    let mut value = 0_u64;
+    let mut shift = 0;
+    loop {
+        let byte = read_byte(reader)?;
+        let bits = u64::from(byte & 0x7f);
+        if shift >= 64 {
+            // Everything that fits in a u64 fits in the first 10 bytes
+            return Err(if bits == 0 {
+                overlong_error(pos)
+            } else {
+                overflow_error(pos, "u64")
+            });
+        } else if (bits << shift) >> shift != bits {
+            return Err(overflow_error(pos, "u64"));
+        }
+
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return if byte == 0 && shift != 0 {
+                Err(overlong_error(pos))
+            } else {
+                Ok(value)
+            };
+        }
+        shift += 7;
+    }
+}
+
+/// Reads a signed LEB128 value which starts at `pos`.
+pub(crate) fn read_signed<R: Read>(reader: &mut R, pos: u64) -> BinResult<i64> {
+    let mut value = 0_i64;
+    let mut shift = 0;
+    let mut last = 0;
+    loop {
+        let byte = read_byte(reader)?;
+        let bits = i64::from(byte & 0x7f);
+        if shift >= 64 {
+            return Err(if bits == 0 || bits == 0x7f {
+                overlong_error(pos)
+            } else {
+                overflow_error(pos, "i64")
+            });
+        } else if shift == 63 && bits != 0 && bits != 0x7f {
+            // Only the sign bit is left, so the rest must be sign extension
+            return Err(overflow_error(pos, "i64"));
+        }
+
+        value |= bits << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            // The last byte is redundant if it only repeats the sign bit of
+            // the byte before it
+            let redundant = (byte == 0 && last & 0x40 == 0) || (byte == 0x7f && last & 0x40 != 0);
+            if shift != 7 && redundant {
+                return Err(overlong_error(pos));
+            }
+
+            if shift < 64 && byte & 0x40 != 0 {
+                value |= -1 << shift;
+            }
+            return Ok(value);
+        }
+        last = byte;
+    }
+}
+
+/// Encodes `value` as unsigned LEB128, returning the buffer and the number of
+/// bytes used.
+pub(crate) fn encode_unsigned(mut value: u64) -> ([u8; MAX_LEN], usize) {
+    let mut buf = [0; MAX_LEN];
+    let mut len = 0;
+    loop {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf[len] = byte;
+        len += 1;
+        if value == 0 {
+            return (buf, len);
+        }
+    }
+}
+
+/// Encodes `value` as signed LEB128, returning the buffer and the number of
+/// bytes used.
+pub(crate) fn encode_signed(mut value: i64) -> ([u8; MAX_LEN], usize) {
+    let mut buf = [0; MAX_LEN];
+    let mut len = 0;
+    loop {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if !done {
+            byte |= 0x80;
+        }
+        buf[len] = byte;
+        len += 1;
+        if done {
+            return (buf, len);
+        }
+    }
+}
+
+impl BinRead for ULeb128 {
+    type Args = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+        read_unsigned(reader, pos).map(Self)
+    }
+}
+
+impl StreamRead for ULeb128 {}
+
+impl BinWrite for ULeb128 {
+    type Args = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()> {
+        let (buf, len) = encode_unsigned(self.0);
+        writer.write_all(&buf[..len])?;
+        Ok(())
+    }
+}
+
+impl BinRead for SLeb128 {
+    type Args = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+        read_signed(reader, pos).map(Self)
+    }
+}
+
+impl StreamRead for SLeb128 {}
+
+impl BinWrite for SLeb128 {
+    type Args = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()> {
+        let (buf, len) = encode_signed(self.0);
+        writer.write_all(&buf[..len])?;
+        Ok(())
+    }
+}
+
+impl<T: ZigZagInt> ZigZag<T> {
+    /// Converts a zigzag varint which was read from `pos` back to a value.
+    pub(crate) fn decode(value: u64, pos: u64) -> BinResult<Self> {
+        T::from_zigzag(value)
+            .map(Self)
+            .ok_or_else(|| overflow_error(pos, core::any::type_name::<T>()))
+    }
+}
+
+impl<T: ZigZagInt> BinRead for ZigZag<T> {
+    type Args = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        _: &ReadOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+        read_unsigned(reader, pos).and_then(|value| Self::decode(value, pos))
+    }
+}
+
+impl<T: ZigZagInt> StreamRead for ZigZag<T> {}
+
+impl<T: ZigZagInt> BinWrite for ZigZag<T> {
+    type Args = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        _: &WriteOptions<'_>,
+        _: Self::Args,
+    ) -> BinResult<()> {
+        let (buf, len) = encode_unsigned(self.0.to_zigzag());
+        writer.write_all(&buf[..len])?;
+        Ok(())
+    }
+}
+
+impl From<u64> for ULeb128 {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ULeb128> for u64 {
+    fn from(value: ULeb128) -> Self {
+        value.0
+    }
+}
+
+impl From<i64> for SLeb128 {
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SLeb128> for i64 {
+    fn from(value: SLeb128) -> Self {
+        value.0
+    }
+}
+
+impl LengthPrefix for ULeb128 {
+    fn from_len(len: usize) -> Option<Self> {
+        u64::try_from(len).ok().map(Self)
+    }
+
+    fn to_len(&self) -> Option<usize> {
+        usize::try_from(self.0).ok()
+    }
+}
+
+impl IntoCount for ULeb128 {
+    fn into_count(self) -> usize {
+        self.0.into_count()
+    }
+}
+
+impl IntoCount for SLeb128 {
+    fn into_count(self) -> usize {
+        self.0.into_count()
+    }
+}
+
+impl<T: IntoCount> IntoCount for ZigZag<T> {
+    fn into_count(self) -> usize {
+        self.0.into_count()
+    }
+}
@@ -0,0 +1,507 @@
+#![cfg(feature = "async")]
+
+use binrw::{
+    binrw, io::SeekFrom, punctuated::Punctuated, AsyncBinRead, AsyncBinReaderExt, AsyncBinWrite,
+    AsyncBinWriterExt, BinRead, BinReaderExt, BinWrite, BinWriterExt, FilePtr, NullString,
+    PosValue,
+};
+use futures::{executor::block_on, io::Cursor};
+
+#[test]
+fn read_primitives() {
+    let mut data = Cursor::new(b"\x01\x02\x03\x04\x05\x06\x07".to_vec());
+    assert_eq!(block_on(data.read_be::<u16>()).unwrap(), 0x0102);
+    assert_eq!(block_on(data.read_le::<u32>()).unwrap(), 0x0605_0403);
+    assert!(block_on(data.read_be::<u16>()).is_err());
+    assert_eq!(data.position(), 6);
+}
+
+#[test]
+fn read_struct() {
+    #[binrw]
+    #[brw(async, big, magic = b"TEST")]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        len: u16,
+        #[br(count = len)]
+        data: Vec<u8>,
+        #[brw(seek_before = SeekFrom::Current(1), align_after = 4)]
+        name: NullString,
+        #[brw(pad_before = 2)]
+        tail: (u8, i8),
+    }
+
+    let bytes = b"TEST\0\x03abc\xffhi\0\0\0\0\0\0\x05\xff";
+    let value = block_on(Cursor::new(bytes.to_vec()).read_ne::<Test>()).unwrap();
+    let expected = Test {
+        len: 3,
+        data: b"abc".to_vec(),
+        name: NullString(b"hi".to_vec()),
+        tail: (5, -1),
+    };
+    assert_eq!(value, expected);
+
+    let mut sync = binrw::io::Cursor::new(&bytes[..]);
+    assert_eq!(sync.read_ne::<Test>().unwrap(), expected);
+}
+
+#[test]
+fn read_failure_rewinds() {
+    #[binrw]
+    #[brw(async, little)]
+    struct Test {
+        a: u8,
+        b: u32,
+    }
+
+    let mut data = Cursor::new(b"\x01\x02\x03".to_vec());
+    assert!(block_on(data.read_ne::<Test>()).is_err());
+    assert_eq!(data.position(), 0);
+}
+
+#[test]
+fn read_file_ptr() {
+    #[binrw::binread]
+    #[br(async, big)]
+    struct Test {
+        ptr: FilePtr<u8, u16>,
+        #[br(restore_position)]
+        peek: u8,
+        value: u8,
+    }
+
+    let value = block_on(Cursor::new(b"\x02\x07\x12\x34".to_vec()).read_ne::<Test>()).unwrap();
+    assert_eq!(*value.ptr, 0x1234);
+    assert_eq!(value.peek, 7);
+    assert_eq!(value.value, 7);
+}
+
+#[test]
+fn write_file_ptr() {
+    #[binrw]
+    #[brw(async, big)]
+    #[bw(data_section)]
+    struct Inner {
+        ptr: FilePtr<u8, u16>,
+    }
+
+    #[binrw]
+    #[brw(async, big)]
+    #[bw(data_section)]
+    struct Test {
+        first: FilePtr<u8, u16>,
+        inner: Inner,
+        second: FilePtr<u8, [u8; 3]>,
+        value: u8,
+    }
+
+    let value = Test {
+        first: FilePtr {
+            ptr: 0,
+            value: Some(0x1234),
+        },
+        inner: Inner {
+            ptr: FilePtr {
+                ptr: 0,
+                value: Some(0x5678),
+            },
+        },
+        second: FilePtr {
+            ptr: 0,
+            value: Some([7, 8, 9]),
+        },
+        value: 1,
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_ne(&value)).unwrap();
+    assert_eq!(out.position(), 11);
+    let bytes = out.into_inner();
+
+    let mut sync = binrw::io::Cursor::new(Vec::new());
+    sync.write_ne(&value).unwrap();
+    assert_eq!(bytes, sync.into_inner());
+    assert_eq!(bytes, b"4Vx	");
+
+    let test = block_on(Cursor::new(bytes).read_ne::<Test>()).unwrap();
+    assert_eq!(*test.first, 0x1234);
+    assert_eq!(*test.inner.ptr, 0x5678);
+    assert_eq!(*test.second, [7, 8, 9]);
+    assert_eq!(test.value, 1);
+}
+
+#[test]
+fn read_enum() {
+    #[binrw]
+    #[brw(async, big)]
+    #[derive(Debug, PartialEq)]
+    enum Test {
+        #[brw(magic = 0u8)]
+        A(u16),
+        #[brw(magic = 1u8)]
+        B {
+            #[br(assert(a != 0))]
+            a: u8,
+        },
+        C(u8, u8),
+    }
+
+    #[binrw]
+    #[brw(async, repr = u8)]
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        One = 1,
+        Two = 2,
+    }
+
+    let read = |bytes: &[u8]| block_on(Cursor::new(bytes.to_vec()).read_ne::<Test>());
+    assert_eq!(read(b"\0\x01\x02").unwrap(), Test::A(0x0102));
+    assert_eq!(read(b"\x01\x05").unwrap(), Test::B { a: 5 });
+    assert_eq!(read(b"\x01\0").unwrap(), Test::C(1, 0));
+    assert!(read(b"\x02").is_err());
+
+    let mut data = Cursor::new(b"\x02\x03".to_vec());
+    assert_eq!(block_on(data.read_ne::<Kind>()).unwrap(), Kind::Two);
+    assert!(block_on(data.read_ne::<Kind>()).is_err());
+}
+
+#[test]
+fn write_round_trip() {
+    #[binrw]
+    #[brw(async, little, magic = b"RT")]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[bw(calc = items.len() as u16)]
+        len: u16,
+        #[br(count = len)]
+        items: Vec<u32>,
+        #[brw(pad_size_to = 4)]
+        flag: u8,
+        #[brw(align_before = 8)]
+        end: [u8; 2],
+    }
+
+    let value = Test {
+        items: vec![1, 2],
+        flag: 9,
+        end: [0xa, 0xb],
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_ne(&value)).unwrap();
+    let bytes = out.into_inner();
+
+    let mut sync = binrw::io::Cursor::new(Vec::new());
+    sync.write_ne(&value).unwrap();
+    assert_eq!(bytes, sync.into_inner());
+
+    assert_eq!(bytes, b"RT\x02\0\x01\0\0\0\x02\0\0\0\x09\0\0\0\x0a\x0b");
+    assert_eq!(
+        block_on(Cursor::new(bytes).read_ne::<Test>()).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn char_matches_sync() {
+    #[binrw]
+    #[brw(async, big)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        c: char,
+    }
+
+    let value = Test { c: '\u{1F980}' };
+
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_ne(&value)).unwrap();
+    let bytes = out.into_inner();
+
+    let mut sync = binrw::io::Cursor::new(Vec::new());
+    sync.write_ne(&value).unwrap();
+    assert_eq!(bytes, sync.into_inner());
+
+    assert_eq!(bytes, b"\0\x01\xf9\x80");
+    assert_eq!(
+        block_on(Cursor::new(bytes.clone()).read_ne::<Test>()).unwrap(),
+        binrw::io::Cursor::new(bytes).read_ne::<Test>().unwrap()
+    );
+}
+
+#[test]
+fn write_enum() {
+    #[binrw]
+    #[brw(async, big)]
+    enum Test {
+        #[brw(magic = 1u8)]
+        A(u16),
+        #[brw(magic = 2u8)]
+        B,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_ne(&Test::A(0x0304))).unwrap();
+    block_on(out.write_ne(&Test::B)).unwrap();
+    assert_eq!(out.into_inner(), b"\x01\x03\x04\x02");
+}
+
+#[test]
+fn bits_round_trip() {
+    #[binrw]
+    #[brw(async, big)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[brw(bits = 3)]
+        kind: u8,
+        #[brw(bits = 6)]
+        delta: i8,
+        tail: u8,
+    }
+
+    let value = Test {
+        kind: 0b101,
+        delta: -2,
+        tail: 7,
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_ne(&value)).unwrap();
+    let bytes = out.into_inner();
+    assert_eq!(bytes, b"\xbf\x00\x07");
+    assert_eq!(
+        block_on(Cursor::new(bytes).read_ne::<Test>()).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn magic_endian_round_trip() {
+    #[binrw]
+    #[brw(async, magic_endian = 0xfeffu16)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        value: u32,
+    }
+
+    let value = Test { value: 0x0102_0304 };
+    let read = |bytes: &[u8]| block_on(Cursor::new(bytes.to_vec()).read_ne::<Test>());
+    assert_eq!(read(b"\xff\xfe\x04\x03\x02\x01").unwrap(), value);
+    assert_eq!(read(b"\xfe\xff\x01\x02\x03\x04").unwrap(), value);
+    assert!(read(b"\xfe\xfe\x01\x02\x03\x04").is_err());
+
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_be(&value)).unwrap();
+    assert_eq!(out.into_inner(), b"\xfe\xff\x01\x02\x03\x04");
+}
+
+#[test]
+fn backpatch() {
+    #[binrw::binwrite]
+    #[bw(async, big)]
+    struct Test {
+        #[bw(calc = 0, backpatch = (data_range.end - data_range.start) as u16)]
+        len: u16,
+        #[bw(byte_range = data_range)]
+        data: Vec<u8>,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_be(&Test {
+        data: vec![1, 2, 3],
+    }))
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\0\x03\x01\x02\x03");
+}
+
+#[test]
+fn pos_value_and_punctuated() {
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_be(&PosValue { val: 1u16, pos: 0 })).unwrap();
+    block_on(out.write_be(&Punctuated::<u8, u8>::new(vec![2, 4], vec![3]))).unwrap();
+    assert!(block_on(out.write_be(&Punctuated::<u8, u8>::new(vec![2], vec![3, 3]))).is_err());
+    assert_eq!(out.into_inner(), b"\0\x01\x02\x03\x04");
+
+    let options = binrw::WriteOptions::new(binrw::Endian::Big);
+    let value = PosValue { val: 5u8, pos: 1 };
+    let mut out = Cursor::new(vec![0]);
+    out.set_position(1);
+    block_on(value.write_checked_async(&mut out, &options, ())).unwrap();
+    assert!(block_on(value.write_checked_async(&mut out, &options, ())).is_err());
+
+    let pos = core::cell::Cell::new(0);
+    block_on(value.write_recorded_async(&mut out, &options, (&pos, ()))).unwrap();
+    assert_eq!(pos.get(), 2);
+    assert_eq!(out.into_inner(), b"\0\x05\x05");
+}
+
+/// Writes `value` with both the async and the blocking impls, checks that they
+/// agree, and returns the value read back by the async impl.
+fn async_matches_sync<T>(value: &T) -> T
+where
+    T: AsyncBinRead + AsyncBinWrite + PartialEq + core::fmt::Debug,
+    <T as BinRead>::Args: Default,
+    <T as BinWrite>::Args: Default,
+{
+    let mut out = Cursor::new(Vec::new());
+    block_on(out.write_ne(value)).unwrap();
+    let bytes = out.into_inner();
+
+    let mut sync = binrw::io::Cursor::new(Vec::new());
+    sync.write_ne(value).unwrap();
+    assert_eq!(bytes, sync.into_inner());
+
+    let async_value = block_on(Cursor::new(bytes.clone()).read_ne::<T>()).unwrap();
+    let sync_value = binrw::io::Cursor::new(bytes).read_ne::<T>().unwrap();
+    assert_eq!(async_value, sync_value);
+    async_value
+}
+
+#[test]
+fn fixed_strings_match_sync() {
+    use binrw::{FixedString, FixedWideString};
+
+    #[binrw]
+    #[brw(async, big)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        name: FixedString<6>,
+        #[brw(args { terminator: None, pad: 0x20 })]
+        wide: FixedWideString<3>,
+    }
+
+    let value = async_matches_sync(&Test {
+        name: FixedString::from_string("abc".into()),
+        wide: FixedWideString::from_string("x".into()),
+    });
+    assert_eq!(value.name.trailing, b"\0\0\0");
+
+    let long = FixedString::<2>::from_string("abc".into());
+    let error = block_on(Cursor::new(Vec::new()).write_be(&long)).unwrap_err();
+    let sync_error = binrw::io::Cursor::new(Vec::new())
+        .write_be(&long)
+        .unwrap_err();
+    assert_eq!(error.to_string(), sync_error.to_string());
+}
+
+/// Checks that reading `data` fails the same way with the async and the
+/// blocking impls.
+fn read_errors_match_sync<T>(data: &[u8], args: <T as BinRead>::Args)
+where
+    T: AsyncBinRead + core::fmt::Debug,
+    <T as BinRead>::Args: Clone,
+{
+    let async_error = block_on(T::read_args_async(&mut Cursor::new(data), args.clone()))
+        .unwrap_err()
+        .to_string();
+    let sync_error = T::read_args(&mut binrw::io::Cursor::new(data), args)
+        .unwrap_err()
+        .to_string();
+    assert_eq!(async_error, sync_error);
+}
+
+#[test]
+fn prefixed_match_sync() {
+    use binrw::{prefixed::PrefixedArgs, PrefixedString, PrefixedVec};
+
+    #[binrw]
+    #[brw(async, big)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        text: PrefixedString<u16>,
+        ids: PrefixedVec<u8, u16>,
+        #[brw(args { count_bytes: true, includes_prefix: true })]
+        names: PrefixedVec<u16, NullString>,
+    }
+
+    async_matches_sync(&Test {
+        text: PrefixedString::from_string("hello".into()),
+        ids: vec![1, 2].into(),
+        names: vec![NullString(b"a".to_vec()), NullString(b"bc".to_vec())].into(),
+    });
+
+    let count_bytes = PrefixedArgs::builder().count_bytes(true).finalize();
+    read_errors_match_sync::<PrefixedVec<u8, u16>>(b"\x03\0\x01\0", count_bytes);
+}
+
+#[test]
+fn encoded_string_matches_sync() {
+    use binrw::{
+        encoding::{Utf16, Windows1252},
+        EncodedString, FixedWideString,
+    };
+
+    #[binrw]
+    #[brw(async, little)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        artist: EncodedString<NullString, Windows1252>,
+        #[brw(args { bom: true })]
+        title: EncodedString<FixedWideString<4>, Utf16>,
+    }
+
+    let value = async_matches_sync(&Test {
+        artist: "Beyoncé".into(),
+        title: "hé".into(),
+    });
+    assert_eq!(*value.title, "hé");
+}
+
+#[test]
+fn varints_match_sync() {
+    use binrw::{SLeb128, ULeb128, ZigZag};
+
+    #[binrw]
+    #[brw(async, big)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        len: ULeb128,
+        #[br(count = len)]
+        data: Vec<u8>,
+        signed: SLeb128,
+        zigzag: ZigZag<i32>,
+    }
+
+    async_matches_sync(&Test {
+        len: ULeb128(2),
+        data: vec![1, 2],
+        signed: SLeb128(-123_456),
+        zigzag: ZigZag(-65),
+    });
+
+    read_errors_match_sync::<ULeb128>(b"\x80\x00", ());
+    read_errors_match_sync::<ULeb128>(b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x7f", ());
+    read_errors_match_sync::<SLeb128>(b"\xff\x7f", ());
+    read_errors_match_sync::<ZigZag<i8>>(b"\x80\x02", ());
+}
+
+#[test]
+fn primitive_wrappers_match_sync() {
+    use binrw::BoolArgs;
+    use core::{cmp::Reverse, num::NonZeroU32, num::Wrapping};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[binrw]
+    #[brw(async, big)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[brw(args { size: 2 })]
+        flag: bool,
+        non_zero: NonZeroU32,
+        wrapping: Wrapping<u8>,
+        reverse: Reverse<u16>,
+        v4: Ipv4Addr,
+        v6: Ipv6Addr,
+    }
+
+    async_matches_sync(&Test {
+        flag: true,
+        non_zero: NonZeroU32::new(7).unwrap(),
+        wrapping: Wrapping(8),
+        reverse: Reverse(9),
+        v4: Ipv4Addr::new(127, 0, 0, 1),
+        v6: Ipv6Addr::LOCALHOST,
+    });
+
+    read_errors_match_sync::<bool>(b"\x02", BoolArgs::builder().strict(true).finalize());
+    read_errors_match_sync::<NonZeroU32>(b"\0\0\0\0", ());
+}
@@ -24,3 +24,254 @@ fn clone_args() {
 
     TestCloneArray::read(&mut binrw::io::Cursor::new(b"")).unwrap();
 }
+
+#[test]
+fn primitive_collections() {
+    use binrw::{helpers::count, io::Cursor, BinReaderExt, BinWriterExt, Endian};
+    use std::collections::VecDeque;
+
+    #[derive(BinRead, Debug)]
+    struct Collections {
+        #[br(count = 3)]
+        words: Vec<u16>,
+        floats: [f32; 2],
+        #[br(parse_with = count(2))]
+        longs: Vec<i64>,
+        #[br(parse_with = count(2))]
+        deque: VecDeque<u8>,
+    }
+
+    let data = b"\0\x01\0\x02\x01\0\x3f\x80\0\0\xc0\0\0\0\
+        \xff\xff\xff\xff\xff\xff\xff\xfe\0\0\0\0\0\0\0\x07\x08\x09";
+    let value = Cursor::new(data).read_be::<Collections>().unwrap();
+    assert_eq!(value.words, [1, 2, 0x100]);
+    assert_eq!(value.floats, [1.0, -2.0]);
+    assert_eq!(value.longs, [-2, 7]);
+    assert_eq!(value.deque, [8, 9]);
+
+    let value = Cursor::new(b"\x01\0\x02\0\x03\0\0\0\x80\x3f\0\0\0\xc0")
+        .read_le_args::<Vec<u16>>(binrw::VecArgs {
+            count: 3,
+            inner: (),
+        })
+        .unwrap();
+    assert_eq!(value, [1, 2, 3]);
+
+    let error = Cursor::new(b"\0\x01\0")
+        .read_be_args::<Vec<u16>>(binrw::VecArgs {
+            count: 2,
+            inner: (),
+        })
+        .unwrap_err();
+    assert!(error.is_eof());
+    assert!(Cursor::new(b"\0\x01\0")
+        .read_be::<[u32; 1]>()
+        .unwrap_err()
+        .is_eof());
+
+    for endian in [Endian::Big, Endian::Little, Endian::Native] {
+        let words = (0..0x500).map(|i| i * 3).collect::<Vec<u32>>();
+        let mut out = Cursor::new(Vec::new());
+        out.write_type(&words, endian).unwrap();
+        out.write_type(&[1.5f64, -0.25], endian).unwrap();
+        let expected = words
+            .iter()
+            .flat_map(|word| match endian {
+                Endian::Big => word.to_be_bytes(),
+                Endian::Little => word.to_le_bytes(),
+                Endian::Native => word.to_ne_bytes(),
+            })
+            .collect::<Vec<u8>>();
+        assert_eq!(out.get_ref()[..expected.len()], expected);
+
+        out.set_position(0);
+        let read_words = out
+            .read_type_args::<Vec<u32>>(
+                endian,
+                binrw::VecArgs {
+                    count: words.len(),
+                    inner: (),
+                },
+            )
+            .unwrap();
+        assert_eq!(read_words, words);
+        assert_eq!(out.read_type::<[f64; 2]>(endian).unwrap(), [1.5, -0.25]);
+    }
+}
+
+#[test]
+fn primitive_collection_large_count() {
+    use binrw::{io::Cursor, BinReaderExt, PrefixedVec};
+
+    // A huge count from untrusted input fails at the end of the data instead
+    // of allocating memory for every element first
+    let error = Cursor::new(b"\0\x01\0\x02")
+        .read_be_args::<Vec<u32>>(binrw::VecArgs {
+            count: u32::MAX as usize,
+            inner: (),
+        })
+        .unwrap_err();
+    assert!(error.is_eof());
+
+    let error = Cursor::new(b"\xff\xff\xff\xff\0\x01")
+        .read_be::<PrefixedVec<u32, u32>>()
+        .unwrap_err();
+    assert!(error.is_eof());
+
+    // Reads which span several chunks are still byte-swapped correctly
+    let values = (0..0x3000u64).collect::<Vec<_>>();
+    let data = values
+        .iter()
+        .flat_map(|value| value.to_be_bytes())
+        .collect::<Vec<u8>>();
+    let read = Cursor::new(data)
+        .read_be_args::<Vec<u64>>(binrw::VecArgs {
+            count: values.len(),
+            inner: (),
+        })
+        .unwrap();
+    assert_eq!(read, values);
+}
+
+#[test]
+fn bool() {
+    use binrw::{args, io::Cursor, BinReaderExt, BinSize, BinWriterExt, BoolArgs, Error};
+
+    #[binrw::binrw]
+    #[derive(BinSize, Debug, PartialEq)]
+    #[brw(big)]
+    struct Flags {
+        a: bool,
+        b: bool,
+        #[brw(args { size: 2 })]
+        c: bool,
+        #[brw(args { strict: true, size: 4 })]
+        d: bool,
+    }
+
+    let flags: Flags = Cursor::new(b"\0\xff\x10\0\0\0\0\x01").read_be().unwrap();
+    assert_eq!(
+        flags,
+        Flags {
+            a: false,
+            b: true,
+            c: true,
+            d: true
+        }
+    );
+    assert_eq!(flags.bin_size().unwrap(), 8);
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&flags).unwrap();
+    assert_eq!(out.into_inner(), b"\0\x01\0\x01\0\0\0\x01");
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le_args(&true, args! { size: 2 }).unwrap();
+    assert_eq!(out.into_inner(), b"\x01\0");
+
+    for data in [&b"\0\0\0\x02"[..], b"\x01\0\0\x01"] {
+        let error = Cursor::new(data)
+            .read_be_args::<bool>(args! { strict: true, size: 4 })
+            .unwrap_err();
+        assert!(matches!(error, Error::AssertFail { pos: 0, .. }));
+    }
+
+    let error = Cursor::new(b"\x01")
+        .read_le_args::<bool>(BoolArgs {
+            strict: false,
+            size: 0,
+        })
+        .unwrap_err();
+    assert!(matches!(error, Error::AssertFail { pos: 0, .. }));
+}
+
+#[test]
+fn char() {
+    use binrw::{io::Cursor, BinReaderExt, BinWriterExt, Error};
+
+    assert_eq!(
+        Cursor::new(b"\0\x01\xf6\x00").read_be::<char>().unwrap(),
+        '\u{1f600}'
+    );
+    assert_eq!(Cursor::new(b"a\0\0\0").read_le::<char>().unwrap(), 'a');
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&'é').unwrap();
+    assert_eq!(out.into_inner(), b"\xe9\0\0\0");
+
+    let mut data = Cursor::new(b"\0\0\0\0\0\xd8\0\0");
+    data.set_position(4);
+    let error = data.read_le::<char>().unwrap_err();
+    assert!(matches!(error, Error::AssertFail { pos: 4, .. }));
+}
+
+#[test]
+fn non_zero() {
+    use binrw::{io::Cursor, BinReaderExt, BinSize, BinWriterExt, Error};
+    use core::num::{NonZeroI16, NonZeroU32};
+
+    #[derive(BinRead, Debug)]
+    #[br(little)]
+    struct Test {
+        _a: u8,
+        b: NonZeroU32,
+    }
+
+    let test: Test = Cursor::new(b"\0\x05\0\0\0").read_le().unwrap();
+    assert_eq!(test.b.get(), 5);
+
+    let error = Cursor::new(b"\0\0\0\0\0").read_le::<Test>().unwrap_err();
+    match error.root_cause() {
+        Error::AssertFail { pos, message } => {
+            assert_eq!(*pos, 1);
+            assert!(message.contains("NonZeroU32"));
+        }
+        _ => panic!("unexpected error {:?}", error),
+    }
+
+    let value = NonZeroI16::new(-2).unwrap();
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&value).unwrap();
+    assert_eq!(out.into_inner(), b"\xff\xfe");
+    assert_eq!(NonZeroI16::FIXED_SIZE, Some(2));
+}
+
+#[test]
+fn wrappers() {
+    use binrw::{io::Cursor, BinReaderExt, BinSize, BinWriterExt};
+    use core::{cmp::Reverse, num::Wrapping};
+
+    let value: Wrapping<u16> = Cursor::new(b"\xff\xff").read_be().unwrap();
+    assert_eq!(value + Wrapping(1), Wrapping(0));
+
+    let value: Reverse<[u8; 2]> = Cursor::new(b"\x01\x02").read_be().unwrap();
+    assert_eq!(value, Reverse([1, 2]));
+    assert_eq!(<Reverse<[u8; 2]>>::FIXED_SIZE, Some(2));
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&(Wrapping(1u16), Reverse(2u8))).unwrap();
+    assert_eq!(out.into_inner(), b"\x01\0\x02");
+}
+
+#[test]
+fn ip_addr() {
+    use binrw::{io::Cursor, BinReaderExt, BinWriterExt};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[binrw::binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(little)]
+    struct Addrs {
+        v4: Ipv4Addr,
+        v6: Ipv6Addr,
+    }
+
+    let data = [&[127, 0, 0, 1][..], &Ipv6Addr::LOCALHOST.octets()].concat();
+    let addrs: Addrs = Cursor::new(&data).read_le().unwrap();
+    assert_eq!(addrs.v4, Ipv4Addr::LOCALHOST);
+    assert_eq!(addrs.v6, Ipv6Addr::LOCALHOST);
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&addrs).unwrap();
+    assert_eq!(out.into_inner(), data);
+}
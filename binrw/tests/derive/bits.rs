@@ -0,0 +1,140 @@
+use binrw::{io::Cursor, BinRead, BinReaderExt};
+
+#[test]
+fn bits_msb_first() {
+    #[derive(BinRead, Debug)]
+    #[br(big)]
+    struct Test {
+        #[br(bits = 4)]
+        version: u8,
+        #[br(bits = 4)]
+        len: u8,
+        #[br(bits = 3)]
+        flags: u8,
+        #[br(bits = 13)]
+        offset: u16,
+        tail: u8,
+    }
+
+    let value: Test = Cursor::new(b"\x45\xa0\x10\xff").read_ne().unwrap();
+    assert_eq!(value.version, 4);
+    assert_eq!(value.len, 5);
+    assert_eq!(value.flags, 0b101);
+    assert_eq!(value.offset, 0x10);
+    assert_eq!(value.tail, 0xff);
+}
+
+#[test]
+fn bits_lsb_first() {
+    #[derive(BinRead, Debug)]
+    #[br(bit_order = binrw::io::BitOrder::LsbFirst)]
+    struct Test {
+        #[br(bits = 3)]
+        kind: u8,
+        #[br(bits = 1)]
+        last: bool,
+        #[br(bits = 12)]
+        len: u16,
+    }
+
+    let value: Test = Cursor::new(b"\x2d\x01").read_ne().unwrap();
+    assert_eq!(value.kind, 0b101);
+    assert!(value.last);
+    assert_eq!(value.len, 0x12);
+}
+
+#[test]
+fn bits_realign() {
+    #[derive(BinRead, Debug)]
+    struct Test {
+        #[br(bits = 3)]
+        a: u8,
+        b: u8,
+        #[br(bits = 1)]
+        c: bool,
+        #[br(bits = 2, if(!c))]
+        d: u8,
+        #[br(bits = 2)]
+        e: i8,
+        f: u8,
+    }
+
+    let value: Test = Cursor::new(b"\xff\x01\xe0\x02").read_ne().unwrap();
+    assert_eq!(value.a, 0b111);
+    assert_eq!(value.b, 1);
+    assert!(value.c);
+    assert_eq!(value.d, 0);
+    assert_eq!(value.e, -1);
+    assert_eq!(value.f, 2);
+}
+
+#[test]
+fn bits_map_and_calc() {
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    #[derive(BinRead, Debug)]
+    struct Test {
+        #[br(bits = 1, map = |x: u8| if x == 0 { Kind::Small } else { Kind::Large })]
+        kind: Kind,
+        #[br(bits = 7, try_map = |x: u8| (x < 100).then_some(x).ok_or("too big"))]
+        count: u8,
+        #[br(bits = count as u32 % 8)]
+        rest: u8,
+        #[br(calc = rest + 1)]
+        next: u8,
+    }
+
+    let value: Test = Cursor::new(b"\x8a\xc0").read_ne().unwrap();
+    assert_eq!(value.kind, Kind::Large);
+    assert_eq!(value.count, 10);
+    assert_eq!(value.rest, 0b11);
+    assert_eq!(value.next, 4);
+
+    let mut data = Cursor::new(b"\xff\xff");
+    assert!(data.read_ne::<Test>().is_err());
+    assert_eq!(data.position(), 0);
+}
+
+#[test]
+fn bits_enum() {
+    #[derive(BinRead, Debug, PartialEq)]
+    enum Test {
+        #[br(magic = 0u8)]
+        A {
+            #[br(bits = 4)]
+            x: u8,
+            #[br(bits = 4)]
+            y: u8,
+        },
+        #[br(magic = 1u8, bit_order = binrw::io::BitOrder::LsbFirst)]
+        B(#[br(bits = 4)] u8, #[br(bits = 4)] u8),
+    }
+
+    assert_eq!(
+        Cursor::new(b"\0\x12").read_ne::<Test>().unwrap(),
+        Test::A { x: 1, y: 2 }
+    );
+    assert_eq!(
+        Cursor::new(b"\x01\x12").read_ne::<Test>().unwrap(),
+        Test::B(2, 1)
+    );
+}
+
+#[test]
+fn bits_too_wide() {
+    #[derive(BinRead, Debug)]
+    struct Test {
+        #[br(bits = 9)]
+        a: u8,
+    }
+
+    let error = Cursor::new(b"\0\0").read_ne::<Test>().unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::Io(err) if err.kind() == binrw::io::ErrorKind::InvalidInput
+    ));
+}
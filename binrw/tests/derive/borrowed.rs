@@ -0,0 +1,123 @@
+use binrw::{
+    endian::{U16Be, U32Le},
+    BinRead, BorrowedBinRead,
+};
+
+#[test]
+fn borrowed_struct() {
+    #[derive(BinRead, Debug)]
+    #[br(borrow, little, magic = b"BR")]
+    struct Test<'a> {
+        len: u8,
+        #[br(count = len)]
+        bytes: &'a [u8],
+        #[br(count = 2, align_after = 4)]
+        name: &'a str,
+        #[br(count = 2)]
+        values: &'a [U32Le],
+        tag: &'a [u8; 2],
+        #[br(assert(big.get() == 0x0102))]
+        big: U16Be,
+        owned: u16,
+    }
+
+    let bytes = b"BR\x02\xaa\xbbab\0\x01\0\0\0\x02\0\0\0XY\x01\x02\x03\0";
+    let value = Test::read_borrowed(bytes).unwrap();
+    assert_eq!(value.bytes, b"\xaa\xbb");
+    assert_eq!(value.bytes.as_ptr(), bytes[3..].as_ptr());
+    assert_eq!(value.name, "ab");
+    assert_eq!(value.name.as_ptr(), bytes[5..].as_ptr());
+    assert_eq!(value.values, [U32Le::new(1), U32Le::new(2)]);
+    assert_eq!(value.values.as_ptr().cast(), bytes[8..].as_ptr());
+    assert_eq!(value.tag, b"XY");
+    assert_eq!(value.big.get(), 0x0102);
+    assert_eq!(value.owned, 3);
+}
+
+#[test]
+fn borrowed_nested() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(borrow, big)]
+    struct Entry<'a> {
+        len: u16,
+        #[br(count = len)]
+        data: &'a [u8],
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(borrow, import(count: usize))]
+    struct Table<'a, T: BinRead<Args = ()>> {
+        header: T,
+        first: Entry<'a>,
+        #[br(count = count, map = |s: &'a str| s.len())]
+        name_len: usize,
+        second: Entry<'a>,
+    }
+
+    let bytes = b"\x07\0\x01aname\0\x02bc";
+    let value = Table::<u8>::read_borrowed_args(bytes, (4,)).unwrap();
+    assert_eq!(value.header, 7);
+    assert_eq!(value.first, Entry { len: 1, data: b"a" });
+    assert_eq!(value.name_len, 4);
+    assert_eq!(
+        value.second,
+        Entry {
+            len: 2,
+            data: b"bc"
+        }
+    );
+}
+
+#[test]
+fn borrowed_enum() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(borrow)]
+    enum Test<'a> {
+        #[br(magic = 1u8)]
+        Bytes(#[br(count = 2)] &'a [u8]),
+        #[br(magic = 2u8)]
+        Text {
+            len: u8,
+            #[br(count = len)]
+            text: &'a str,
+        },
+    }
+
+    assert_eq!(Test::read_borrowed(b"\x01ab").unwrap(), Test::Bytes(b"ab"));
+    assert_eq!(
+        Test::read_borrowed(b"\x02\x02hi").unwrap(),
+        Test::Text { len: 2, text: "hi" }
+    );
+    assert!(Test::read_borrowed(b"\x03").is_err());
+}
+
+#[test]
+fn borrowed_errors() {
+    #[derive(BinRead, Debug)]
+    #[br(borrow)]
+    struct Test<'a> {
+        len: u8,
+        #[br(count = len)]
+        text: &'a str,
+    }
+
+    let error = Test::read_borrowed(b"\x04abc").unwrap_err();
+    assert!(error.is_eof());
+
+    let error = Test::read_borrowed(b"\x02\xff\xfe").unwrap_err();
+    match error.root_cause() {
+        binrw::Error::Custom { pos, err } => {
+            assert_eq!(*pos, 1);
+            assert!(err.is::<core::str::Utf8Error>());
+        }
+        error => panic!("unexpected error: {:?}", error),
+    }
+
+    let mut reader = binrw::io::Cursor::new(&b"\x02\0"[..]);
+    let options = binrw::ReadOptions::default();
+    assert!(
+        <&[U32Le]>::read_options_borrowed(&mut reader, &options, binrw::args! { count: 1 })
+            .is_err()
+    );
+    assert_eq!(reader.position(), 0);
+}
@@ -0,0 +1,143 @@
+use binrw::{binrw, io::Cursor, BinRead, BinReaderExt, BinWrite, BinWriterExt, Error, FilePtr8};
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+struct Entry {
+    id: u16,
+    flags: u8,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+struct Record {
+    len: u8,
+    #[br(size = len)]
+    #[bw(size = *len)]
+    entry: Entry,
+    kind: u8,
+}
+
+#[test]
+fn size_read() {
+    // Unread bytes at the end of the region are skipped
+    let value = Cursor::new(b"\x05\x01\0\x02\xff\xff\x09")
+        .read_le::<Record>()
+        .unwrap();
+    assert_eq!(
+        value,
+        Record {
+            len: 5,
+            entry: Entry { id: 1, flags: 2 },
+            kind: 9,
+        }
+    );
+
+    // Reading past the end of the region is an error at the start of the field
+    let error = Cursor::new(b"\x02\x01\0\x02\x09")
+        .read_le::<Record>()
+        .unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        Error::SizeExceeded { pos: 1, size: 2 }
+    ));
+
+    // Running out of data is still an ordinary end of file
+    let error = Cursor::new(b"\x05\x01\0").read_le::<Record>().unwrap_err();
+    assert!(error.is_eof());
+}
+
+#[test]
+fn size_write() {
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&Record {
+        len: 5,
+        entry: Entry { id: 1, flags: 2 },
+        kind: 9,
+    })
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\x05\x01\0\x02\0\0\x09");
+
+    let mut out = Cursor::new(Vec::new());
+    let error = out
+        .write_le(&Record {
+            len: 2,
+            entry: Entry { id: 1, flags: 2 },
+            kind: 9,
+        })
+        .unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        Error::SizeExceeded { pos: 1, size: 2 }
+    ));
+}
+
+#[test]
+fn size_write_field_named_padding() {
+    #[derive(BinWrite)]
+    #[bw(big)]
+    struct Test {
+        #[bw(size = 4)]
+        a: u8,
+        padding: u16,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&Test {
+        a: 1,
+        padding: 0xabcd,
+    })
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\x01\0\0\0\xab\xcd");
+}
+
+#[test]
+fn size_nested() {
+    #[derive(BinRead, Debug)]
+    struct Outer {
+        #[br(size = 4)]
+        inner: Inner,
+        tail: u8,
+    }
+
+    #[derive(BinRead, Debug)]
+    struct Inner {
+        #[br(size = 1)]
+        a: u16,
+        b: u8,
+    }
+
+    let error = Cursor::new(b"\x01\x02\x03\x04\x05")
+        .read_le::<Outer>()
+        .unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        Error::SizeExceeded { pos: 0, size: 1 }
+    ));
+}
+
+#[test]
+fn size_seek() {
+    #[derive(BinRead, Debug)]
+    struct Table {
+        first: FilePtr8<u8>,
+        #[br(seek_before = binrw::io::SeekFrom::Start(3))]
+        last: u8,
+    }
+
+    #[derive(BinRead, Debug)]
+    struct File {
+        #[br(size = 4)]
+        table: Table,
+        tail: u8,
+    }
+
+    // Positions inside the field are not relative to the region
+    let file = Cursor::new(b"\x02\0\x07\x08\x09")
+        .read_le::<File>()
+        .unwrap();
+    assert_eq!(*file.table.first, 7);
+    assert_eq!(file.table.last, 8);
+    assert_eq!(file.tail, 9);
+}
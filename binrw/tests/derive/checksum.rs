@@ -0,0 +1,215 @@
+use binrw::{
+    binrw,
+    checksum::{Adler32, Checksum, Crc16, Crc32, Sum8, Xor8},
+    io::{Cursor, Seek, Write},
+    BinRead, BinReaderExt, BinResult, BinWrite, BinWriterExt, WriteOptions,
+};
+
+#[test]
+fn checksum_algorithms() {
+    assert_eq!(Crc16::checksum(b"123456789"), 0xbb3d);
+    assert_eq!(Crc32::checksum(b"123456789"), 0xcbf4_3926);
+    assert_eq!(Crc32::checksum(b""), 0);
+    assert_eq!(Adler32::checksum(b"Wikipedia"), 0x11e6_0398);
+    assert_eq!(Adler32::checksum(&[0xff; 0x10000]), 0x7797_0ef2);
+    assert_eq!(Sum8::checksum(b"\x80\x81\x02"), 3);
+    assert_eq!(Xor8::checksum(b"\x0f\xf0\x01"), 0xfe);
+
+    let mut crc = Crc32::default();
+    crc.update(b"1234");
+    crc.update(b"56789");
+    assert_eq!(crc.finish(), 0xcbf4_3926);
+}
+
+#[binrw]
+#[brw(big)]
+#[derive(Debug, PartialEq)]
+struct Packet {
+    len: u8,
+    #[br(count = len)]
+    data: Vec<u8>,
+    #[brw(checksum(Crc32, from = len, to = data))]
+    crc: u32,
+}
+
+#[test]
+fn checksum_read() {
+    let packet = Cursor::new(b"\x09123456789\x32\x62\x6e\x34")
+        .read_ne::<Packet>()
+        .unwrap();
+    assert_eq!(packet.data, b"123456789");
+    assert_eq!(packet.crc, 0x3262_6e34);
+
+    let mut data = Cursor::new(b"\x09123456780\x32\x62\x6e\x34");
+    let error = data.read_ne::<Packet>().unwrap_err();
+    assert!(matches!(
+        error,
+        binrw::Error::BadChecksum {
+            pos: 10,
+            found: 0x3262_6e34,
+            ..
+        }
+    ));
+    assert_eq!(data.position(), 0);
+}
+
+#[test]
+fn checksum_write() {
+    let packet = Packet {
+        data: b"123456789".to_vec(),
+        len: 9,
+        crc: 0,
+    };
+    let mut out = Cursor::new(vec![0xff, 0xff]);
+    out.set_position(2);
+    out.write_ne(&packet).unwrap();
+    assert_eq!(out.position(), 16);
+    let out = out.into_inner();
+    assert_eq!(out, b"\xff\xff\x09123456789\x32\x62\x6e\x34");
+    assert_eq!(
+        Cursor::new(&out[2..]).read_ne::<Packet>().unwrap().crc,
+        0x3262_6e34
+    );
+}
+
+#[test]
+fn checksum_before_range() {
+    #[binrw]
+    #[brw(little, magic = b"HD")]
+    #[derive(Debug, PartialEq)]
+    struct Header {
+        #[br(temp)]
+        #[bw(calc = 0)]
+        #[brw(checksum(Crc16, from = version, to = flags))]
+        crc: u16,
+        #[brw(magic = b"V", pad_after = 1)]
+        version: u8,
+        #[br(temp)]
+        #[bw(calc = names.len() as u8)]
+        count: u8,
+        #[br(count = count)]
+        names: Vec<u8>,
+        #[brw(align_before = 4)]
+        flags: u16,
+        trailer: u8,
+    }
+
+    let value = Header {
+        version: 1,
+        names: vec![7],
+        flags: 0x8000,
+        trailer: 9,
+    };
+    let mut out = Cursor::new(Vec::new());
+    out.write_ne(&value).unwrap();
+    let out = out.into_inner();
+    let crc = Crc16::checksum(b"V\x01\0\x01\x07\0\0\0\0\x80");
+    assert_eq!(out[2..4], crc.to_le_bytes());
+    assert_eq!(out[4..], b"V\x01\0\x01\x07\0\0\0\0\x80\x09"[..]);
+
+    assert_eq!(Cursor::new(&out).read_ne::<Header>().unwrap(), value);
+
+    let mut corrupt = out.clone();
+    corrupt[8] = 0;
+    assert!(matches!(
+        Cursor::new(corrupt).read_ne::<Header>().unwrap_err(),
+        binrw::Error::BadChecksum { pos: 2, .. }
+    ));
+
+    // Bytes outside of the range are not checked
+    let mut trailer = out;
+    trailer[14] = 0;
+    assert_eq!(Cursor::new(trailer).read_ne::<Header>().unwrap().trailer, 0);
+}
+
+#[test]
+fn checksum_nested() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Block {
+        a: u8,
+        b: u8,
+        #[brw(checksum(Xor8, from = a, to = b))]
+        inner: u8,
+        #[brw(checksum(Sum8, from = b, to = inner))]
+        outer: u8,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_ne(&Block {
+        a: 0x0f,
+        b: 0xf1,
+        inner: 0,
+        outer: 0,
+    })
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\x0f\xf1\xfe\xef");
+    assert_eq!(
+        Cursor::new(b"\x0f\xf1\xfe\xef").read_ne::<Block>().unwrap(),
+        Block {
+            a: 0x0f,
+            b: 0xf1,
+            inner: 0xfe,
+            outer: 0xef,
+        }
+    );
+}
+
+#[test]
+fn checksum_conditional() {
+    #[binrw]
+    #[br(import(has_crc: bool))]
+    #[bw(import(has_crc: bool))]
+    #[derive(Debug, PartialEq)]
+    struct Record {
+        data: [u8; 3],
+        #[brw(if(has_crc), checksum(Adler32, from = data))]
+        crc: u32,
+    }
+
+    let value = Record {
+        data: *b"abc",
+        crc: 0x1234,
+    };
+    let mut out = Cursor::new(Vec::new());
+    value.write_with_args(&mut out, (false,)).unwrap();
+    assert_eq!(out.into_inner(), b"abc");
+    assert_eq!(
+        Record::read_args(&mut Cursor::new(b"abc"), (false,)).unwrap(),
+        Record {
+            data: *b"abc",
+            crc: 0,
+        }
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le_args(&value, (true,)).unwrap();
+    assert_eq!(out.into_inner(), b"abc\x27\x01\x4d\x02");
+}
+
+#[test]
+fn checksum_custom_writer() {
+    fn write_twice<W: Write + Seek>(
+        value: &u8,
+        writer: &mut W,
+        options: &WriteOptions,
+        _: (),
+    ) -> BinResult<()> {
+        value.write_options(writer, options, ())?;
+        value.write_options(writer, options, ())
+    }
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Doubled {
+        #[br(map = |value: [u8; 2]| value[0])]
+        #[bw(write_with = write_twice)]
+        value: u8,
+        #[brw(checksum(Sum8, from = value))]
+        sum: u8,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_ne(&Doubled { value: 3, sum: 0 }).unwrap();
+    assert_eq!(out.into_inner(), b"\x03\x03\x06");
+}
@@ -0,0 +1,131 @@
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, Endian, ReadOptions, WriteOptions};
+
+struct Version(u8);
+
+#[binrw]
+#[brw(context(version: Version))]
+#[derive(Debug, PartialEq)]
+struct Inner {
+    a: u8,
+    #[br(count = version.0)]
+    #[bw(assert(b.len() == version.0.into(), "wrong length for version"))]
+    b: Vec<u8>,
+}
+
+// The outer type passes the context along without declaring it
+#[binrw]
+#[derive(Debug, PartialEq)]
+struct Outer {
+    len: u8,
+    #[br(count = len)]
+    items: Vec<Inner>,
+}
+
+#[test]
+fn context_read() {
+    let read = |bytes: &[u8], version| {
+        let context = Version(version);
+        let options = ReadOptions::new(Endian::Big).with_context(&context);
+        Outer::read_options(&mut Cursor::new(bytes), &options, ())
+    };
+
+    let value = read(b"\x02\x01\x02\x03\x04", 1).unwrap();
+    let expected = [Inner { a: 1, b: vec![2] }, Inner { a: 3, b: vec![4] }];
+    assert_eq!(value.items, expected);
+
+    let value = read(b"\x01\x01\x02\x03", 2).unwrap();
+    assert_eq!(
+        value.items,
+        [Inner {
+            a: 1,
+            b: vec![2, 3]
+        }]
+    );
+}
+
+#[test]
+fn context_write() {
+    let value = Outer {
+        len: 1,
+        items: vec![Inner {
+            a: 1,
+            b: vec![2, 3],
+        }],
+    };
+
+    let write = |version| {
+        let context = Version(version);
+        let options = WriteOptions::new(Endian::Big).with_context(&context);
+        let mut out = Cursor::new(Vec::new());
+        value
+            .write_options(&mut out, &options, ())
+            .map(|_| out.into_inner())
+    };
+
+    assert_eq!(write(2).unwrap(), b"\x01\x01\x02\x03");
+    assert!(matches!(
+        write(1).unwrap_err().root_cause(),
+        binrw::Error::AssertFail { .. }
+    ));
+}
+
+#[test]
+fn context_missing() {
+    let error = Outer::read(&mut Cursor::new(b"\x01\x01")).unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::MissingContext { pos: 1, type_name } if type_name.ends_with("Version")
+    ));
+
+    // A context of some other type is not used
+    let options = ReadOptions::default().with_context(&1u32);
+    let error = Outer::read_options(&mut Cursor::new(b"\x01\x01"), &options, ()).unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::MissingContext { .. }
+    ));
+
+    let mut out = Cursor::new(Vec::new());
+    assert!(matches!(
+        Inner { a: 1, b: vec![] }.write_to(&mut out),
+        Err(binrw::Error::MissingContext { pos: 0, .. })
+    ));
+}
+
+#[test]
+fn context_options() {
+    let context = 5u16;
+    let options = ReadOptions::default()
+        .with_context(&context)
+        .with_endian(Endian::Little);
+    assert_eq!(options.context::<u16>(), Some(&5));
+    assert_eq!(options.context::<u8>(), None);
+    assert_eq!(ReadOptions::default().context::<u16>(), None);
+
+    let options = WriteOptions::new(Endian::Big).with_context(&context);
+    assert_eq!(
+        options.with_endian(Endian::Little).context::<u16>(),
+        Some(&5)
+    );
+}
+
+#[test]
+fn context_not_sync() {
+    use core::cell::Cell;
+
+    // Contexts do not need to be shareable between threads, so they can be
+    // used to collect state while reading
+    #[derive(BinRead)]
+    #[br(context(seen: Cell<u32>))]
+    struct Counted {
+        #[br(map = |value: u8| { seen.set(seen.get() + 1); value })]
+        value: u8,
+    }
+
+    let seen = Cell::new(0u32);
+    let options = ReadOptions::new(Endian::Big).with_context(&seen);
+    let value =
+        <[Counted; 3]>::read_options(&mut Cursor::new(b"\x01\x02\x03"), &options, ()).unwrap();
+    assert_eq!(value[2].value, 3);
+    assert_eq!(seen.get(), 3);
+}
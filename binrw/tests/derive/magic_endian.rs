@@ -0,0 +1,106 @@
+use binrw::{
+    binrw, io::Cursor, BinRead, BinReaderExt, BinSize, BinWrite, BinWriterExt, Endian, WriteOptions,
+};
+
+#[binrw]
+#[derive(BinSize, Debug, PartialEq)]
+struct Version {
+    major: u16,
+    minor: u16,
+}
+
+#[binrw]
+#[brw(magic_endian = 0xa1b2c3d4u32)]
+#[derive(BinSize, Debug, PartialEq)]
+struct PcapHeader {
+    version: Version,
+    snap_len: u32,
+}
+
+#[test]
+fn magic_endian_read() {
+    let expected = PcapHeader {
+        version: Version { major: 2, minor: 4 },
+        snap_len: 0x10000,
+    };
+
+    let little = b"\xd4\xc3\xb2\xa1\x02\0\x04\0\0\0\x01\0";
+    assert_eq!(
+        PcapHeader::read(&mut Cursor::new(little)).unwrap(),
+        expected
+    );
+
+    // The detected byte order overrides the one passed by the caller
+    let big = b"\xa1\xb2\xc3\xd4\0\x02\0\x04\0\x01\0\0";
+    assert_eq!(Cursor::new(big).read_le::<PcapHeader>().unwrap(), expected);
+
+    let mut data = Cursor::new(b"\xd4\xc3\xb2\xa0\x02\0\x04\0\0\0\x01\0");
+    let error = data.read_ne::<PcapHeader>().unwrap_err();
+    assert!(matches!(error, binrw::Error::BadMagic { pos: 0, .. }));
+    assert_eq!(data.position(), 0);
+}
+
+#[test]
+fn magic_endian_write() {
+    let value = PcapHeader {
+        version: Version { major: 2, minor: 4 },
+        snap_len: 0x10000,
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&value).unwrap();
+    out.write_be(&value).unwrap();
+    assert_eq!(
+        out.into_inner(),
+        b"\xd4\xc3\xb2\xa1\x02\0\x04\0\0\0\x01\0\xa1\xb2\xc3\xd4\0\x02\0\x04\0\x01\0\0"
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    value
+        .write_options(&mut out, &WriteOptions::new(Endian::Native), ())
+        .unwrap();
+    let native = out.into_inner();
+    assert_eq!(native[..4], 0xa1b2c3d4u32.to_ne_bytes());
+    assert_eq!(Cursor::new(native).read_be::<PcapHeader>().unwrap(), value);
+
+    assert_eq!(PcapHeader::FIXED_SIZE, Some(12));
+}
+
+#[test]
+fn magic_endian_byte_strings() {
+    #[binrw]
+    #[brw(magic_endian(b"II", b"MM"))]
+    #[derive(Debug, PartialEq)]
+    struct TiffHeader {
+        #[br(assert(magic == 42))]
+        magic: u16,
+        first_ifd: u32,
+    }
+
+    #[binrw]
+    #[brw(magic_endian(b"II", b"MM"), repr = u16)]
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        A = 1,
+        B = 2,
+    }
+
+    let value = TiffHeader {
+        magic: 42,
+        first_ifd: 8,
+    };
+    let little = b"II\x2a\0\x08\0\0\0";
+    let big = b"MM\0\x2a\0\0\0\x08";
+    assert_eq!(TiffHeader::read(&mut Cursor::new(little)).unwrap(), value);
+    assert_eq!(TiffHeader::read(&mut Cursor::new(big)).unwrap(), value);
+    assert!(TiffHeader::read(&mut Cursor::new(b"IM\x2a\0\x08\0\0\0")).is_err());
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&value).unwrap();
+    out.write_le(&value).unwrap();
+    assert_eq!(out.into_inner(), [&big[..], &little[..]].concat());
+
+    let mut data = Cursor::new(b"II\x02\0MM\0\x01");
+    assert_eq!(data.read_ne::<Kind>().unwrap(), Kind::B);
+    assert_eq!(data.read_ne::<Kind>().unwrap(), Kind::A);
+}
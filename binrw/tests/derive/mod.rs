@@ -1,10 +1,19 @@
 #![allow(dead_code)]
 mod binwrite_temp;
+mod bits;
+mod borrowed;
+mod bounded;
+mod checksum;
+mod context;
 mod r#enum;
+mod magic_endian;
 mod map_args;
+mod stream;
 mod r#struct;
 mod struct_generic;
 mod struct_map;
+mod transform;
 mod unit_enum;
 mod unit_struct;
+mod version;
 mod write;
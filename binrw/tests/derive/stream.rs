@@ -0,0 +1,162 @@
+use binrw::{
+    io::{self, Read},
+    BinRead, Endian, NullString, PosValue, StreamReaderExt,
+};
+
+/// A reader which cannot seek.
+struct Stream<'a>(&'a [u8]);
+
+impl Read for Stream<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.0.len());
+        buf[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn stream_struct() {
+    #[derive(BinRead, Debug)]
+    #[br(stream, big, magic = b"PK")]
+    struct Test {
+        len: u8,
+        #[br(count = len, pad_after = 1)]
+        data: Vec<u16>,
+        #[br(pad_size_to = 4)]
+        name: NullString,
+        #[br(if(len > 1))]
+        tail: Option<PosValue<u8>>,
+        #[br(calc = len + 1)]
+        next: u8,
+    }
+
+    let mut stream = Stream(b"PK\x02\0\x01\0\x02\xffhi\0\0\x07rest");
+    let value = stream.read_stream::<Test>(Endian::Big).unwrap();
+    assert_eq!(value.data, [1, 2]);
+    assert_eq!(value.name.to_string(), "hi");
+    assert_eq!(
+        value.tail.as_ref().map(|tail| (tail.pos, tail.val)),
+        Some((12, 7))
+    );
+    assert_eq!(value.next, 3);
+    assert_eq!(stream.0, b"rest");
+}
+
+#[test]
+fn stream_unit_enum() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(stream, repr = u16)]
+    enum Kind {
+        A = 1,
+        B = 2,
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(stream)]
+    enum Magic {
+        #[br(magic = b"A")]
+        A,
+        #[br(magic = b"B")]
+        B,
+    }
+
+    let mut stream = Stream(b"\0\x02BC");
+    assert_eq!(stream.read_stream::<Kind>(Endian::Big).unwrap(), Kind::B);
+    assert_eq!(stream.read_stream::<Magic>(Endian::Big).unwrap(), Magic::B);
+    assert!(matches!(
+        stream.read_stream::<Magic>(Endian::Big),
+        Err(binrw::Error::NoVariantMatch { pos: 0 })
+    ));
+}
+
+#[test]
+fn stream_errors() {
+    #[derive(BinRead, Debug)]
+    #[br(stream, little)]
+    #[br(assert(a != 0, "bad a"))]
+    struct Test {
+        a: u16,
+        b: u32,
+    }
+
+    let error = Stream(b"\0\0\0\0\0\0")
+        .read_stream::<Test>(Endian::Little)
+        .unwrap_err();
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0, .. }));
+
+    // The original read error is reported, not the failure to rewind
+    let error = Stream(b"\x01\0\x02")
+        .read_stream::<Test>(Endian::Little)
+        .unwrap_err();
+    assert!(error.is_eof());
+}
+
+#[test]
+fn seekable_rewind_errors() {
+    use binrw::{
+        io::{Cursor, Seek, SeekFrom},
+        BinReaderExt,
+    };
+
+    /// A seekable reader which fails to rewind.
+    struct NoRewind(Cursor<&'static [u8]>);
+
+    impl Read for NoRewind {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.0.read(buf)
+        }
+    }
+
+    impl Seek for NoRewind {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            match pos {
+                SeekFrom::Start(_) => Err(io::Error::other("no rewind")),
+                _ => self.0.seek(pos),
+            }
+        }
+    }
+
+    // Readers which can seek still report a failure to rewind
+    let error = NoRewind(Cursor::new(b"\x01")).read_le::<u16>().unwrap_err();
+    assert!(matches!(error, binrw::Error::Io(ref e) if e.kind() == io::ErrorKind::Other));
+}
+
+#[test]
+fn stream_generic() {
+    #[derive(BinRead, Debug)]
+    #[br(stream)]
+    struct Test<T: BinRead<Args = ()>> {
+        a: T,
+        b: [T; 2],
+    }
+
+    let value = Stream(b"\x01\x02\x03")
+        .read_stream::<Test<u8>>(Endian::Big)
+        .unwrap();
+    assert_eq!((value.a, value.b), (1, [2, 3]));
+}
+
+#[test]
+fn stream_map() {
+    use core::convert::TryFrom;
+
+    #[derive(BinRead, Debug)]
+    #[br(stream, little)]
+    struct Test {
+        #[br(map = |name: NullString| name.to_string())]
+        name: String,
+        #[br(try_map = |len: u16| u8::try_from(len))]
+        len: u8,
+    }
+
+    #[derive(BinRead, Debug)]
+    #[br(stream, map = |value: u8| Self(value.into()))]
+    struct Mapped(u32);
+
+    let mut stream = Stream(b"hi\0\x07\0\x09");
+    let value = stream.read_stream::<Test>(Endian::Little).unwrap();
+    assert_eq!((value.name.as_str(), value.len), ("hi", 7));
+    let mapped = stream.read_stream::<Mapped>(Endian::Little).unwrap();
+    assert_eq!(mapped.0, 9);
+}
@@ -144,6 +144,39 @@ fn calc_temp_field() {
     );
 }
 
+#[test]
+fn count_casts() {
+    #[derive(Clone, Copy)]
+    enum Len {
+        Two = 2,
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    struct Test {
+        flag: u8,
+        #[br(count = flag != 0)]
+        a: Vec<u8>,
+        #[br(count = 1.5_f32)]
+        b: Vec<u8>,
+        #[br(count = '\u{1}')]
+        c: Vec<u8>,
+        #[br(count = Len::Two)]
+        d: Vec<u8>,
+    }
+
+    let result = Test::read(&mut Cursor::new(b"\x01ABCDE")).unwrap();
+    assert_eq!(
+        result,
+        Test {
+            flag: 1,
+            a: b"A".to_vec(),
+            b: b"B".to_vec(),
+            c: b"C".to_vec(),
+            d: b"DE".to_vec(),
+        }
+    );
+}
+
 #[test]
 fn deref_now() {
     #[derive(BinRead, Debug, PartialEq)]
@@ -264,6 +297,13 @@ fn magic_char() {
     Test::read(&mut Cursor::new(b"a")).unwrap();
     Test::read(&mut Cursor::new(b"")).expect_err("accepted bad data");
     Test::read(&mut Cursor::new(b"x")).expect_err("accepted bad data");
+
+    #[derive(BinRead, Debug)]
+    #[br(magic = '\u{e9}')]
+    struct Latin1;
+
+    Latin1::read(&mut Cursor::new(b"\xe9")).unwrap();
+    Latin1::read(&mut Cursor::new("\u{e9}".as_bytes())).expect_err("accepted bad data");
 }
 
 #[test]
@@ -0,0 +1,162 @@
+use binrw::{
+    binrw,
+    io::{
+        transform::{Rle, Transform, Xor},
+        Cursor, ErrorKind, Seek, Write,
+    },
+    until_eof, BinRead, BinReaderExt, BinResult, BinWriterExt, FilePtr8, WriteOptions,
+};
+
+#[test]
+fn transform_algorithms() {
+    assert_eq!(Xor(b"").encode(b"abc").unwrap(), b"abc");
+    assert_eq!(Xor([1, 2]).encode(b"\0\0\0").unwrap(), b"\x01\x02\x01");
+
+    let data = [&[7; 300][..], &(0..=255).collect::<Vec<u8>>(), b"xxy"].concat();
+    let encoded = Rle.encode(&data).unwrap();
+    assert_eq!(encoded[..6], [0x81, 7, 0x81, 7, 0xd5, 7]);
+    assert_eq!(encoded[6], 127);
+    assert_eq!(Rle.decode(&encoded).unwrap(), data);
+    assert_eq!(Rle.encode(b"").unwrap(), b"");
+
+    assert_eq!(Rle.decode(b"\x80\xfea\x80\x01bc").unwrap(), b"aaabc");
+    for truncated in [&b"\x02ab"[..], b"\xff"] {
+        assert_eq!(
+            Rle.decode(truncated).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+struct Inner {
+    a: u16,
+    b: u8,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+struct Record {
+    #[br(temp)]
+    #[bw(calc = 0, backpatch = (inner_range.end - inner_range.start) as u8)]
+    len: u8,
+    #[brw(transform(Xor(b"key"), size = len))]
+    #[bw(byte_range = inner_range)]
+    inner: Inner,
+    trailer: u8,
+}
+
+#[test]
+fn transform_read_write() {
+    let value = Record {
+        inner: Inner { a: 0x0201, b: 3 },
+        trailer: 9,
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_ne(&value).unwrap();
+    let out = out.into_inner();
+    assert_eq!(out, b"\x03\x6a\x67\x7a\x09");
+
+    assert_eq!(Cursor::new(&out).read_ne::<Record>().unwrap(), value);
+
+    // Reads cannot go past the end of the decoded data
+    let mut short = out.clone();
+    short[0] = 2;
+    assert!(Cursor::new(short).read_ne::<Record>().is_err());
+
+    // The region must be complete
+    assert!(matches!(
+        Cursor::new(&out[..3]).read_ne::<Record>().unwrap_err(),
+        binrw::Error::Io(error) if error.kind() == ErrorKind::UnexpectedEof
+    ));
+}
+
+#[test]
+fn transform_to_end() {
+    fn write_bytes<T: AsRef<[u8]>, W: Write + Seek>(
+        value: &T,
+        writer: &mut W,
+        _: &WriteOptions,
+        _: (),
+    ) -> BinResult<()> {
+        writer.write_all(value.as_ref())?;
+        Ok(())
+    }
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Image {
+        width: u8,
+        #[br(parse_with = until_eof)]
+        #[bw(write_with = write_bytes)]
+        #[brw(transform = Rle)]
+        pixels: Vec<u8>,
+    }
+
+    let value = Image {
+        width: 4,
+        pixels: b"\0\0\0\0\x01\x02\x02\x02".to_vec(),
+    };
+    let mut out = Cursor::new(Vec::new());
+    out.write_ne(&value).unwrap();
+    let out = out.into_inner();
+    assert_eq!(out, b"\x04\xfd\0\0\x01\xfe\x02");
+    assert_eq!(Cursor::new(out).read_ne::<Image>().unwrap(), value);
+}
+
+#[test]
+fn transform_file_ptr() {
+    #[derive(BinRead, Debug)]
+    struct Table {
+        first: FilePtr8<u8>,
+        second: FilePtr8<u8>,
+    }
+
+    #[derive(BinRead, Debug)]
+    struct File {
+        #[br(transform(Xor([0xff]), size = 4))]
+        table: Table,
+        tail: u8,
+    }
+
+    // Offsets are relative to the start of the decoded data
+    let file = Cursor::new(b"\xfd\xfc\xaa\xbb\x01")
+        .read_ne::<File>()
+        .unwrap();
+    assert_eq!(*file.table.first, 0x55);
+    assert_eq!(*file.table.second, 0x44);
+    assert_eq!(file.tail, 1);
+}
+
+#[cfg(feature = "deflate")]
+#[test]
+fn transform_deflate() {
+    use binrw::io::transform::{Deflate, Zlib};
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Compressed {
+        #[brw(transform = Zlib::default())]
+        data: [u8; 64],
+    }
+
+    let value = Compressed { data: [0x42; 64] };
+    let mut out = Cursor::new(Vec::new());
+    out.write_ne(&value).unwrap();
+    let out = out.into_inner();
+    assert_eq!(out[0], 0x78);
+    assert!(out.len() < 64);
+    assert_eq!(Cursor::new(out).read_ne::<Compressed>().unwrap(), value);
+
+    let deflate = Deflate { level: 9 };
+    let encoded = deflate.encode(b"hello hello hello").unwrap();
+    assert_eq!(deflate.decode(&encoded).unwrap(), b"hello hello hello");
+    assert_eq!(
+        Zlib::default().decode(b"garbage").unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+}
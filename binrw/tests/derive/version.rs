@@ -0,0 +1,127 @@
+use binrw::{binrw, io::Cursor, BinRead, BinReaderExt, BinSize, BinWrite, BinWriterExt};
+
+#[binrw]
+#[brw(big, import(version: u16), version = version)]
+#[derive(Debug, PartialEq)]
+struct Record {
+    id: u8,
+    #[brw(since = 2)]
+    flags: Option<u8>,
+    #[brw(since = 2, until = 4)]
+    legacy: u16,
+    #[brw(until = 3)]
+    old_name: Option<u8>,
+    #[brw(since = 3)]
+    #[bw(calc = name.len() as u8)]
+    name_len: u8,
+    #[brw(since = 3)]
+    #[br(count = name_len)]
+    name: Vec<u8>,
+}
+
+#[test]
+fn version_read() {
+    let read = |bytes: &[u8], version| Record::read_args(&mut Cursor::new(bytes), (version,));
+
+    assert_eq!(
+        read(b"\x01\x02", 1).unwrap(),
+        Record {
+            id: 1,
+            flags: None,
+            legacy: 0,
+            old_name: Some(2),
+            name: vec![],
+        }
+    );
+    assert_eq!(
+        read(b"\x01\x02\x00\x03\x04", 2).unwrap(),
+        Record {
+            id: 1,
+            flags: Some(2),
+            legacy: 3,
+            old_name: Some(4),
+            name: vec![],
+        }
+    );
+    assert_eq!(
+        read(b"\x01\x02\x00\x03\x02ab", 3).unwrap(),
+        Record {
+            id: 1,
+            flags: Some(2),
+            legacy: 3,
+            old_name: None,
+            name: b"ab".to_vec(),
+        }
+    );
+    assert_eq!(
+        read(b"\x01\x02\x01a", 4).unwrap(),
+        Record {
+            id: 1,
+            flags: Some(2),
+            legacy: 0,
+            old_name: None,
+            name: b"a".to_vec(),
+        }
+    );
+}
+
+#[test]
+fn version_write() {
+    let value = Record {
+        id: 1,
+        flags: Some(2),
+        legacy: 3,
+        old_name: Some(4),
+        name: b"ab".to_vec(),
+    };
+
+    let write = |version| {
+        let mut out = Cursor::new(Vec::new());
+        value.write_with_args(&mut out, (version,)).unwrap();
+        out.into_inner()
+    };
+
+    assert_eq!(write(1), b"\x01\x04");
+    assert_eq!(write(2), b"\x01\x02\x00\x03\x04");
+    assert_eq!(write(3), b"\x01\x02\x00\x03\x02ab");
+    assert_eq!(write(4), b"\x01\x02\x02ab");
+}
+
+#[test]
+fn version_from_field() {
+    #[binrw]
+    #[br(version = version)]
+    #[bw(version = *version)]
+    #[derive(BinSize, Debug, PartialEq)]
+    struct Header {
+        version: u8,
+        #[brw(since = 2)]
+        size: u8,
+        #[brw(until = 2)]
+        padding: [u8; 2],
+    }
+
+    let v1 = Header {
+        version: 1,
+        size: 0,
+        padding: [5, 6],
+    };
+    let v2 = Header {
+        version: 2,
+        size: 9,
+        padding: [0; 2],
+    };
+
+    let mut data = Cursor::new(b"\x01\x05\x06\x02\x09");
+    assert_eq!(data.read_ne::<Header>().unwrap(), v1);
+    assert_eq!(data.read_ne::<Header>().unwrap(), v2);
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_ne(&v1).unwrap();
+    out.write_ne(&v2).unwrap();
+    assert_eq!(out.into_inner(), b"\x01\x05\x06\x02\x09");
+
+    assert_eq!(Header::FIXED_SIZE, None);
+    assert_eq!(v1.bin_size().unwrap(), 3);
+    assert_eq!(v2.bin_size().unwrap(), 2);
+}
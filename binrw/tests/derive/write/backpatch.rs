@@ -0,0 +1,108 @@
+use binrw::{binrw, io::Cursor, BinReaderExt, BinSize, BinWrite, BinWriterExt};
+
+#[test]
+fn backpatch_size_and_offset() {
+    #[binrw]
+    #[brw(big)]
+    #[derive(BinSize, Debug, PartialEq)]
+    struct File {
+        #[br(temp)]
+        #[bw(calc = 0, backpatch = (body_range.end - body_range.start) as u16)]
+        body_len: u16,
+        #[br(temp)]
+        #[bw(calc = 0, backpatch = table_range.start as u8)]
+        table_offset: u8,
+        #[br(count = body_len)]
+        #[bw(byte_range = body_range)]
+        body: Vec<u8>,
+        #[br(seek_before = binrw::io::SeekFrom::Start(table_offset.into()))]
+        #[bw(align_before = 4, byte_range = table_range)]
+        table: [u16; 2],
+    }
+
+    let value = File {
+        body: b"hello".to_vec(),
+        table: [1, 2],
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_ne(&value).unwrap();
+    assert_eq!(out.position(), 12);
+    let bytes = out.into_inner();
+    assert_eq!(bytes, b"\0\x05\x08hello\0\x01\0\x02");
+
+    assert_eq!(Cursor::new(bytes).read_ne::<File>().unwrap(), value);
+    assert_eq!(value.bin_size().unwrap(), 12);
+}
+
+#[test]
+fn backpatch_struct_field() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Header {
+        #[bw(backpatch = data_range.end as u32)]
+        end: u32,
+        #[br(count = end - 4)]
+        #[bw(byte_range = data_range)]
+        data: Vec<u8>,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&Header {
+        end: 0,
+        data: vec![1, 2, 3],
+    })
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\x07\0\0\0\x01\x02\x03");
+}
+
+#[test]
+fn backpatch_size_mismatch() {
+    #[derive(BinWrite)]
+    struct Test {
+        #[bw(backpatch = data.clone())]
+        len: Vec<u8>,
+        data: Vec<u8>,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    let error = Test {
+        len: vec![0],
+        data: vec![1, 2],
+    }
+    .write_to(&mut out)
+    .unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::BackpatchSize {
+            pos: 0,
+            reserved: 1,
+            written: 2
+        }
+    ));
+}
+
+#[test]
+fn byte_range_versioned() {
+    #[binrw::binwrite]
+    #[bw(big, import(version: u8), version = version)]
+    struct Test {
+        #[bw(calc = 0, backpatch = extra_range.start as u8)]
+        extra_pos: u8,
+        #[bw(since = 2, byte_range = extra_range)]
+        extra: u16,
+        #[bw(calc = (extra_range.end - extra_range.start) as u8)]
+        extra_len: u8,
+    }
+
+    let write = |version| {
+        let mut out = Cursor::new(Vec::new());
+        Test { extra: 0x0102 }
+            .write_with_args(&mut out, (version,))
+            .unwrap();
+        out.into_inner()
+    };
+
+    assert_eq!(write(1), b"\x01\0");
+    assert_eq!(write(2), b"\x01\x01\x02\x02");
+}
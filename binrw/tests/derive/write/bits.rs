@@ -0,0 +1,100 @@
+use binrw::{binrw, io::Cursor, BinReaderExt, BinResult, BinWrite, BinWriterExt};
+
+#[test]
+fn bits_round_trip() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[brw(bits = 4)]
+        version: u8,
+        #[brw(bits = 3)]
+        kind: u8,
+        #[brw(bits = 1)]
+        last: bool,
+        #[brw(bits = 5)]
+        delta: i8,
+        len: u16,
+        #[brw(bits = 12)]
+        offset: u16,
+    }
+
+    let value = Test {
+        version: 4,
+        kind: 0b101,
+        last: true,
+        delta: -3,
+        len: 0x0102,
+        offset: 0xabc,
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&value).unwrap();
+    let bytes = out.into_inner();
+    assert_eq!(bytes, b"\x4b\xe8\x01\x02\xab\xc0");
+    assert_eq!(Cursor::new(bytes).read_be::<Test>().unwrap(), value);
+}
+
+#[test]
+fn bits_lsb_first() {
+    #[binrw]
+    #[brw(bit_order = binrw::io::BitOrder::LsbFirst)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[brw(bits = 3)]
+        kind: u8,
+        #[brw(bits = 1)]
+        last: bool,
+        #[brw(bits = 12)]
+        len: u16,
+    }
+
+    let value = Test {
+        kind: 0b101,
+        last: true,
+        len: 0x12,
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&value).unwrap();
+    let bytes = out.into_inner();
+    assert_eq!(bytes, b"\x2d\x01");
+    assert_eq!(Cursor::new(bytes).read_le::<Test>().unwrap(), value);
+}
+
+#[test]
+fn bits_map() {
+    #[derive(BinWrite)]
+    struct Test {
+        #[bw(bits = 2, map = |x: &u8| x >> 4)]
+        high: u8,
+        #[bw(bits = 3, try_map = |&x: &bool| -> BinResult<u8> { Ok(if x { 0b111 } else { 0 }) })]
+        ones: bool,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&Test {
+        high: 0x30,
+        ones: true,
+    })
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\xf8");
+}
+
+#[test]
+fn bits_value_too_large() {
+    #[derive(BinWrite)]
+    struct Test {
+        #[bw(bits = 3)]
+        a: u8,
+        #[bw(bits = 3)]
+        b: i8,
+    }
+
+    let write = |value: &Test| {
+        let mut out = Cursor::new(Vec::new());
+        out.write_be(value).map(|_| out.into_inner())
+    };
+    assert_eq!(write(&Test { a: 7, b: -4 }).unwrap(), b"\xf0");
+    assert!(write(&Test { a: 8, b: 0 }).is_err());
+    assert!(write(&Test { a: 0, b: 4 }).is_err());
+}
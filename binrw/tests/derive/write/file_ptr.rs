@@ -0,0 +1,130 @@
+use binrw::{
+    binrw, file_ptr::DataSection, io::Cursor, BinReaderExt, BinSize, BinWrite, BinWriterExt,
+    Endian, FilePtr16, FilePtr8, WriteOptions,
+};
+
+#[binrw]
+#[brw(big)]
+#[derive(Debug, PartialEq)]
+struct Node {
+    value: u8,
+    next: FilePtr8<Option<Leaf>>,
+}
+
+#[binrw]
+#[derive(Debug, PartialEq)]
+struct Leaf(u16);
+
+fn ptr<P: binrw::file_ptr::IntoSeekFrom + Default, T>(value: T) -> binrw::FilePtr<P, T> {
+    binrw::FilePtr {
+        ptr: P::default(),
+        value: Some(value),
+    }
+}
+
+#[test]
+fn file_ptr_data_section() {
+    #[binrw]
+    #[brw(big)]
+    #[bw(data_section)]
+    #[derive(BinSize, Debug, PartialEq)]
+    struct Tree {
+        count: u8,
+        root: FilePtr16<Node>,
+        leaf: FilePtr8<Leaf>,
+        trailer: u8,
+    }
+
+    let tree = Tree {
+        count: 2,
+        root: ptr(Node {
+            value: 7,
+            next: ptr(Some(Leaf(0x1234))),
+        }),
+        leaf: ptr(Leaf(0x5678)),
+        trailer: 0xff,
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&tree).unwrap();
+    // The writer is left after the data section
+    assert_eq!(out.position(), 11);
+    let bytes = out.into_inner();
+    assert_eq!(bytes, b"\x02\0\x05\x09\xff\x07\x07\x12\x34\x56\x78");
+
+    let read = Cursor::new(&bytes).read_be::<Tree>().unwrap();
+    assert_eq!(read, tree);
+    assert_eq!(Tree::FIXED_SIZE, None);
+    assert_eq!(tree.bin_size().unwrap(), 11);
+}
+
+#[test]
+fn file_ptr_offset() {
+    #[binrw]
+    #[brw(big)]
+    #[bw(data_section)]
+    #[derive(Debug, PartialEq)]
+    struct Table {
+        base: u8,
+        #[br(offset = u64::from(base))]
+        #[bw(offset = u64::from(*base))]
+        entries: [FilePtr8<u16>; 2],
+    }
+
+    let table = Table {
+        base: 3,
+        entries: [ptr(1), ptr(2)],
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&table).unwrap();
+    let bytes = out.into_inner();
+    assert_eq!(bytes, b"\x03\0\x02\0\x01\0\x02");
+    assert_eq!(Cursor::new(bytes).read_be::<Table>().unwrap(), table);
+}
+
+#[test]
+fn file_ptr_explicit_data_section() {
+    let data_section = DataSection::new(0x20);
+    let options = WriteOptions::new(Endian::Little).with_data_section(&data_section);
+
+    let mut out = Cursor::new(Vec::new());
+    let first: FilePtr8<u32> = ptr(1);
+    let second: FilePtr8<u16> = ptr(2);
+    first.write_options(&mut out, &options, ()).unwrap();
+    second.write_options(&mut out, &options, ()).unwrap();
+    assert_eq!(out.position(), 2);
+    assert_eq!(data_section.end(), 0x26);
+
+    let bytes = out.into_inner();
+    assert_eq!(bytes[..2], [0x20, 0x24]);
+    assert_eq!(bytes[0x20..], [1, 0, 0, 0, 2, 0]);
+}
+
+#[test]
+fn file_ptr_errors() {
+    let mut out = Cursor::new(Vec::new());
+    let error = out.write_le(&ptr::<u8, u8>(1)).unwrap_err();
+    assert!(matches!(error, binrw::Error::MissingDataSection { pos: 0 }));
+
+    // Unread pointers are written as-is
+    let unread = FilePtr8::<u8> {
+        ptr: 5,
+        value: None,
+    };
+    out.write_le(&unread).unwrap();
+    assert_eq!(out.into_inner(), b"\x05");
+
+    let data_section = DataSection::new(0x100);
+    let options = WriteOptions::new(Endian::Little).with_data_section(&data_section);
+    let error = ptr::<u8, u8>(1)
+        .write_options(&mut Cursor::new(Vec::new()), &options, ())
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        binrw::Error::PointerOverflow {
+            pos: 0,
+            value_pos: 0x100
+        }
+    ));
+}
@@ -0,0 +1,99 @@
+use binrw::{binrw, binwrite, io::Cursor, BinReaderExt, BinSize, BinWrite, BinWriterExt, Endian};
+
+#[test]
+fn if_cond() {
+    #[derive(BinWrite)]
+    #[bw(big)]
+    struct Test {
+        flags: u8,
+        #[bw(if(*flags & 1 != 0))]
+        a: Option<u16>,
+        #[bw(if(*flags & 2 != 0), pad_before = 1, magic = b"B")]
+        b: u8,
+    }
+
+    let write = |flags| {
+        let mut out = Cursor::new(Vec::new());
+        out.write_ne(&Test {
+            flags,
+            a: Some(0x0102),
+            b: 3,
+        })
+        .unwrap();
+        out.into_inner()
+    };
+
+    assert_eq!(write(0), b"\x00B");
+    assert_eq!(write(1), b"\x01\x01\x02B");
+    assert_eq!(write(2), b"\x02B\x00\x03");
+    assert_eq!(write(3), b"\x03\x01\x02B\x00\x03");
+}
+
+#[test]
+fn if_cond_round_trip() {
+    #[binrw]
+    #[brw(big, import(version: u8), version = version)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[bw(calc = extra.is_some() as u8)]
+        has_extra: u8,
+        #[brw(if(has_extra != 0, None))]
+        extra: Option<u16>,
+        #[brw(since = 2, if(has_extra != 0))]
+        more: u8,
+    }
+
+    let round_trip = |value: &Test, version, expected: &[u8]| {
+        let mut out = Cursor::new(Vec::new());
+        value.write_with_args(&mut out, (version,)).unwrap();
+        assert_eq!(out.get_ref(), expected);
+        out.set_position(0);
+        assert_eq!(
+            &out.read_type_args::<Test>(Endian::Big, (version,)).unwrap(),
+            value
+        );
+    };
+
+    let value = Test {
+        extra: Some(5),
+        more: 6,
+    };
+    round_trip(&value, 2, b"\x01\x00\x05\x06");
+    round_trip(&Test { more: 0, ..value }, 1, b"\x01\x00\x05");
+    round_trip(
+        &Test {
+            extra: None,
+            more: 0,
+        },
+        2,
+        b"\x00",
+    );
+}
+
+#[test]
+fn if_cond_byte_range() {
+    #[binwrite]
+    #[bw(little)]
+    #[derive(BinSize)]
+    struct Test {
+        #[bw(calc = 0, backpatch = (data_range.end - data_range.start) as u8)]
+        len: u8,
+        write_data: u8,
+        #[bw(if(*write_data != 0), byte_range = data_range)]
+        data: [u8; 2],
+    }
+
+    let write = |write_data| {
+        let mut out = Cursor::new(Vec::new());
+        out.write_ne(&Test {
+            write_data,
+            data: [1, 2],
+        })
+        .unwrap();
+        out.into_inner()
+    };
+
+    assert_eq!(write(1), b"\x02\x01\x01\x02");
+    assert_eq!(write(0), b"\x00\x00");
+    assert_eq!(Test::FIXED_SIZE, None);
+}
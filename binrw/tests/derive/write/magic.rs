@@ -1,4 +1,4 @@
-use binrw::{io::Cursor, BinRead, BinReaderExt, BinWrite};
+use binrw::{io::Cursor, BinRead, BinReaderExt, BinWrite, BinWriterExt};
 
 #[test]
 fn magic_round_trip() {
@@ -39,3 +39,39 @@ fn magic_one_way() {
 
     assert_eq!(&out.into_inner()[..], data);
 }
+
+#[test]
+fn magic_char() {
+    use binrw::{BinSize, HasMagic};
+
+    #[derive(BinRead, BinWrite, BinSize)]
+    #[brw(magic = 'A')]
+    struct Test {
+        #[brw(magic = 'B')]
+        x: u8,
+    }
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    enum Kind {
+        #[brw(magic = 'x')]
+        X,
+        #[brw(magic = 'y')]
+        Y,
+    }
+
+    assert_eq!(Test::MAGIC, 'A');
+    assert_eq!(Test::FIXED_SIZE, Some(3));
+
+    let data = b"ABC";
+    let test: Test = Cursor::new(data).read_le().unwrap();
+    assert_eq!(test.x, b'C');
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&test).unwrap();
+    assert_eq!(&out.into_inner()[..], data);
+
+    assert_eq!(Cursor::new(b"y").read_le::<Kind>().unwrap(), Kind::Y);
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&Kind::X).unwrap();
+    assert_eq!(out.into_inner(), b"x");
+}
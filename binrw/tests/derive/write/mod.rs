@@ -1,11 +1,15 @@
 mod args;
 mod assert;
+mod backpatch;
 mod binread_temp;
+mod bits;
 mod c_enum;
 mod calc;
 mod custom_writer;
 mod endian;
 mod r#enum;
+mod file_ptr;
+mod if_cond;
 mod ignore;
 mod import;
 mod magic;
@@ -13,4 +17,5 @@ mod map;
 mod padding;
 mod restore_position;
 mod simple;
+mod size;
 mod top_level_map;
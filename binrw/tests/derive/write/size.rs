@@ -0,0 +1,165 @@
+use binrw::{binrw, binwrite, io::Cursor, BinSize, BinWrite, BinWriterExt, NullString};
+
+fn written_len<T: BinWrite<Args = ()>>(value: &T) -> u64 {
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(value).unwrap();
+    out.into_inner().len() as u64
+}
+
+#[test]
+fn fixed_size_struct() {
+    #[derive(BinWrite, BinSize)]
+    #[bw(magic = b"HEAD")]
+    struct Test {
+        #[bw(magic = 1u8)]
+        a: u16,
+        b: [u32; 2],
+        c: (u8, i64),
+        #[bw(ignore)]
+        _d: u128,
+    }
+
+    const SIZE: Option<u64> = Test::FIXED_SIZE;
+    assert_eq!(SIZE, Some(4 + 1 + 2 + 8 + 9));
+
+    let value = Test {
+        a: 1,
+        b: [2, 3],
+        c: (4, 5),
+        _d: 6,
+    };
+    assert_eq!(value.bin_size().unwrap(), written_len(&value));
+}
+
+#[test]
+fn fixed_size_attribute() {
+    #[binrw]
+    #[derive(BinSize)]
+    #[brw(little)]
+    struct Test {
+        #[bw(calc = data.len() as u8)]
+        len: u8,
+        #[br(count = len)]
+        data: Vec<u8>,
+    }
+
+    #[binwrite]
+    #[derive(BinSize, Debug)]
+    struct Inner {
+        #[bw(calc = 1u32)]
+        a: u32,
+        b: u8,
+    }
+
+    assert_eq!(Test::FIXED_SIZE, None);
+    assert_eq!(Inner::FIXED_SIZE, Some(5));
+
+    let value = Test {
+        data: vec![1, 2, 3],
+    };
+    assert_eq!(value.bin_size().unwrap(), 4);
+}
+
+#[test]
+fn variable_size_directives() {
+    #[binwrite]
+    #[derive(BinSize)]
+    #[bw(big)]
+    struct Test {
+        #[bw(pad_before = 1, align_after = 4)]
+        a: u8,
+        #[bw(pad_size_to = 6)]
+        name: NullString,
+        #[bw(map = |x| x.to_string().into_bytes())]
+        mapped: u32,
+        #[bw(restore_position)]
+        peek: u16,
+        tail: u16,
+    }
+
+    let value = Test {
+        a: 1,
+        name: NullString(b"hi".to_vec()),
+        mapped: 1234,
+        peek: 5,
+        tail: 6,
+    };
+
+    assert_eq!(Test::FIXED_SIZE, None);
+    assert_eq!(value.bin_size().unwrap(), 4 + 6 + 4 + 2);
+    assert_eq!(value.bin_size().unwrap(), written_len(&value));
+}
+
+#[test]
+fn enum_size() {
+    #[derive(BinWrite, BinSize)]
+    #[bw(magic = 0xffu8)]
+    enum Same {
+        #[bw(magic = 0u8)]
+        A(u16),
+        #[bw(magic = 1u8)]
+        B { a: u8, b: u8 },
+        #[bw(magic = b"CCC")]
+        C,
+    }
+
+    #[derive(BinWrite, BinSize)]
+    enum Different {
+        A(u16),
+        B(u32),
+    }
+
+    #[derive(BinWrite, BinSize)]
+    #[bw(repr = u16)]
+    enum Repr {
+        A = 1,
+        B = 2,
+    }
+
+    #[derive(BinWrite, BinSize)]
+    #[bw(magic = 9u8)]
+    enum Magic {
+        #[bw(magic = b"AA")]
+        A,
+        #[bw(magic = b"BB")]
+        B,
+    }
+
+    assert_eq!(Same::FIXED_SIZE, Some(4));
+    assert_eq!(Different::FIXED_SIZE, None);
+    assert_eq!(Different::B(0).bin_size().unwrap(), 4);
+    assert_eq!(Repr::FIXED_SIZE, Some(2));
+    assert_eq!(Magic::FIXED_SIZE, Some(3));
+}
+
+#[test]
+fn generic_size() {
+    #[derive(BinWrite, BinSize)]
+    struct Test<T: BinWrite<Args = ()> + 'static> {
+        a: T,
+        b: [T; 3],
+    }
+
+    assert_eq!(<Test<u16>>::FIXED_SIZE, Some(8));
+    assert_eq!(<Test<Vec<u8>>>::FIXED_SIZE, None);
+    assert_eq!(
+        Test {
+            a: vec![1u8],
+            b: [vec![], vec![2, 3], vec![4]]
+        }
+        .bin_size()
+        .unwrap(),
+        4
+    );
+}
+
+#[test]
+fn std_sizes() {
+    assert_eq!(<(u8, [u16; 4], ())>::FIXED_SIZE, Some(9));
+    assert_eq!(<Box<i32>>::FIXED_SIZE, Some(4));
+    assert_eq!(<Option<u8>>::FIXED_SIZE, None);
+    assert_eq!(None::<u8>.bin_size().unwrap(), 0);
+    assert_eq!(vec![1u32, 2, 3].bin_size().unwrap(), 12);
+    assert_eq!(NullString(b"abc".to_vec()).bin_size().unwrap(), 4);
+    assert_eq!(binrw::endian::U32Le::FIXED_SIZE, Some(4));
+}
@@ -105,3 +105,67 @@ fn show_backtrace_2() {
     let err = x.read_be::<OutermostStruct>().map(|_| ()).unwrap_err();
     println!("{}", err);
 }
+
+#[test]
+fn write_backtrace() {
+    use binrw::{error::BacktraceFrame, io::Cursor, BinResult, BinWrite, BinWriterExt, Error};
+    use core::convert::TryFrom;
+
+    #[derive(BinWrite)]
+    struct InnerMostStruct {
+        len: u32,
+
+        #[bw(try_map = |&x: &u32| -> BinResult<u8> {
+            u8::try_from(x).map_err(|_| Error::AssertFail { pos: 0, message: "too big".into() })
+        })]
+        item: u32,
+    }
+
+    #[derive(BinWrite)]
+    enum MiddleEnum {
+        OnlyOption {
+            #[bw(err_context("While writing inner with {} items", inner.len))]
+            inner: InnerMostStruct,
+        },
+    }
+
+    #[derive(BinWrite)]
+    struct OutermostStruct {
+        #[bw(little)]
+        middle: MiddleEnum,
+    }
+
+    let value = OutermostStruct {
+        middle: MiddleEnum::OnlyOption {
+            inner: InnerMostStruct {
+                len: 1,
+                item: 0x100,
+            },
+        },
+    };
+    let mut x = Cursor::new(Vec::new());
+    let err = x.write_be(&value).unwrap_err();
+    println!("{}", err);
+
+    let backtrace = match &err {
+        Error::Backtrace(backtrace) => backtrace,
+        _ => panic!("expected a backtrace, got {:?}", err),
+    };
+    assert!(matches!(*backtrace.error, Error::AssertFail { pos: 0, .. }));
+    let messages = backtrace
+        .frames
+        .iter()
+        .map(|frame| match frame {
+            BacktraceFrame::OwnedFull { message, .. } => message.clone(),
+            frame => panic!("unexpected frame {:?}", frame),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "While writing field 'item' in InnerMostStruct at 0x4",
+            "While writing inner with 1 items",
+            "While writing field 'middle' in OutermostStruct at 0x4",
+        ]
+    );
+}
@@ -0,0 +1,120 @@
+use binrw::io::{BitOrder, BitReader, BitWriter, Cursor, ErrorKind};
+
+#[test]
+fn read_msb_first() {
+    let mut reader = BitReader::new(
+        Cursor::new(&[0b1010_0110, 0b0111_1111, 0x80][..]),
+        BitOrder::MsbFirst,
+    );
+    assert_eq!(reader.read_bits(1).unwrap(), 1);
+    assert_eq!(reader.read_bits(0).unwrap(), 0);
+    assert_eq!(reader.read_bits(5).unwrap(), 0b01001);
+    assert!(!reader.is_aligned());
+    assert_eq!(reader.read_bits(10).unwrap(), 0b10_0111_1111);
+    assert!(reader.is_aligned());
+    assert_eq!(reader.read::<i8>(2).unwrap(), -2);
+    reader.align();
+    assert_eq!(reader.get_ref().position(), 3);
+    assert_eq!(
+        reader.read_bits(1).unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn read_lsb_first() {
+    let mut reader = BitReader::new(
+        Cursor::new(&[0b1010_0110, 0b0111_1111][..]),
+        BitOrder::LsbFirst,
+    );
+    assert_eq!(reader.read_bits(1).unwrap(), 0);
+    assert_eq!(reader.read_bits(5).unwrap(), 0b10011);
+    assert_eq!(reader.read_bits(10).unwrap(), 0b01_1111_1110);
+    assert!(reader.is_aligned());
+}
+
+#[test]
+fn read_wide() {
+    let bytes = [0x80, 0, 0, 0, 0, 0, 0, 0x01, 0xff];
+    let mut reader = BitReader::new(Cursor::new(&bytes[..]), BitOrder::MsbFirst);
+    assert_eq!(reader.read_bits(4).unwrap(), 0x8);
+    assert_eq!(reader.read_bits(64).unwrap(), 0x0000_0000_0000_001f);
+    assert_eq!(
+        reader.read_bits(65).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        reader.read::<u8>(9).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    assert_eq!(reader.read::<u8>(4).unwrap(), 0xf);
+}
+
+#[test]
+fn write_round_trip() {
+    for &order in &[BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        let mut writer = BitWriter::new(Cursor::new(Vec::new()), order);
+        writer.write_bits(0b1, 1).unwrap();
+        writer.write_bits(0b01001, 5).unwrap();
+        writer.write(&-300i16, 11).unwrap();
+        writer.write(&true, 1).unwrap();
+        writer.write_bits(u64::MAX, 64).unwrap();
+        assert!(!writer.is_aligned());
+        let bytes = writer.into_inner().unwrap().into_inner();
+        assert_eq!(bytes.len(), 11);
+
+        let mut reader = BitReader::new(Cursor::new(&bytes[..]), order);
+        assert_eq!(reader.read_bits(1).unwrap(), 0b1);
+        assert_eq!(reader.read_bits(5).unwrap(), 0b01001);
+        assert_eq!(reader.read::<i16>(11).unwrap(), -300);
+        assert!(reader.read::<bool>(1).unwrap());
+        assert_eq!(reader.read_bits(64).unwrap(), u64::MAX);
+        assert_eq!(reader.read_bits(6).unwrap(), 0);
+    }
+}
+
+#[test]
+fn write_bytes() {
+    let mut writer = BitWriter::new(Cursor::new(Vec::new()), BitOrder::MsbFirst);
+    writer.write_bits(0b101, 3).unwrap();
+    writer.write_bits(0b1_1001, 5).unwrap();
+    assert!(writer.is_aligned());
+    assert_eq!(writer.get_ref().get_ref(), &[0b1011_1001]);
+    writer.write_bits(0b11, 2).unwrap();
+    writer.align().unwrap();
+    writer.align().unwrap();
+    assert_eq!(writer.get_ref().get_ref(), &[0b1011_1001, 0b1100_0000]);
+
+    let mut writer = BitWriter::new(Cursor::new(Vec::new()), BitOrder::LsbFirst);
+    writer.write_bits(0b101, 3).unwrap();
+    writer.write_bits(0b1_1001, 5).unwrap();
+    writer.write_bits(0b11, 2).unwrap();
+    assert_eq!(
+        writer.into_inner().unwrap().into_inner(),
+        [0b1100_1101, 0b11]
+    );
+}
+
+#[test]
+fn write_out_of_range() {
+    let mut writer = BitWriter::new(Cursor::new(Vec::new()), BitOrder::MsbFirst);
+    assert_eq!(
+        writer.write_bits(0b100, 2).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        writer.write(&-3i8, 2).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        writer.write(&1u8, 9).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        writer.write(&true, 0).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    writer.write(&-2i8, 2).unwrap();
+    writer.write(&false, 0).unwrap();
+    assert_eq!(writer.into_inner().unwrap().into_inner(), [0b1000_0000]);
+}
@@ -0,0 +1,37 @@
+use binrw::io::{BoundedReader, Cursor, Read, Seek, SeekFrom};
+
+#[test]
+fn bounded_read() {
+    let mut stream = Cursor::new(b"\0\x01\x02\x03\x04\x05");
+    stream.seek(SeekFrom::Start(1)).unwrap();
+    let mut reader = BoundedReader::new(&mut stream, 3).unwrap();
+    assert_eq!((reader.start(), reader.end()), (1, 4));
+
+    let mut buf = [0; 2];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [1, 2]);
+    assert!(!reader.is_overread());
+
+    assert_eq!(reader.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], 3);
+    assert!(reader.is_overread());
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    assert_eq!(reader.stream_position().unwrap(), 4);
+}
+
+#[test]
+fn bounded_seek() {
+    let mut reader = BoundedReader::new(Cursor::new(b"\0\x01\x02\x03"), 2).unwrap();
+
+    // Positions are the same as the inner stream
+    assert_eq!(reader.seek(SeekFrom::Start(1)).unwrap(), 1);
+    let mut buf = [0; 1];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [1]);
+
+    // Reads past the end stop even after seeking there
+    reader.seek(SeekFrom::Start(3)).unwrap();
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    assert!(reader.is_overread());
+    assert_eq!(reader.into_inner().position(), 3);
+}
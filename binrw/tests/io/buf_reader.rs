@@ -0,0 +1,112 @@
+use binrw::io::{BufReader, Cursor, Read, Result, Seek, SeekFrom};
+use binrw::{BinRead, BinReaderExt};
+
+#[derive(Debug)]
+struct Counter<T> {
+    inner: T,
+    reads: usize,
+    seeks: usize,
+}
+
+impl<T> Counter<T> {
+    fn new(inner: T) -> Self {
+        Self {
+            inner,
+            reads: 0,
+            seeks: 0,
+        }
+    }
+}
+
+impl<T: Read> Read for Counter<T> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.reads += 1;
+        self.inner.read(buf)
+    }
+}
+
+impl<T: Seek> Seek for Counter<T> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        self.seeks += 1;
+        self.inner.seek(pos)
+    }
+}
+
+#[test]
+fn buf_reader_keeps_buffer() {
+    let data = (0..=255).collect::<Vec<u8>>();
+    let mut reader = BufReader::with_capacity(16, Counter::new(Cursor::new(&data)));
+    assert_eq!(reader.capacity(), 16);
+
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [0, 1, 2, 3]);
+    assert_eq!(reader.buffer().len(), 12);
+
+    // Getting the position asks the inner stream once
+    assert_eq!(reader.stream_position().unwrap(), 4);
+    assert_eq!(reader.stream_position().unwrap(), 4);
+    assert_eq!(reader.get_ref().seeks, 1);
+
+    // Seeking inside the buffer does not touch the inner stream
+    assert_eq!(reader.seek(SeekFrom::Current(-3)).unwrap(), 1);
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [1, 2, 3, 4]);
+    assert_eq!(reader.seek(SeekFrom::Start(16)).unwrap(), 16);
+    assert_eq!(reader.stream_position().unwrap(), 16);
+    assert_eq!(reader.get_ref().seeks, 1);
+    assert_eq!(reader.get_ref().reads, 1);
+
+    // Seeking outside of it discards the buffer
+    assert_eq!(reader.seek(SeekFrom::Start(100)).unwrap(), 100);
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [100, 101, 102, 103]);
+    assert_eq!(reader.get_ref().seeks, 2);
+
+    assert_eq!(reader.seek(SeekFrom::Start(255)).unwrap(), 255);
+    assert_eq!(reader.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], 255);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    assert_eq!(reader.stream_position().unwrap(), 256);
+}
+
+#[test]
+fn buf_reader_large_reads() {
+    let data = (0..64).collect::<Vec<u8>>();
+    let mut reader = BufReader::with_capacity(8, Counter::new(Cursor::new(&data)));
+
+    // Reads at least as large as the buffer go straight to the inner stream
+    let mut buf = [0; 32];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf[31], 31);
+    assert!(reader.buffer().is_empty());
+    assert_eq!(reader.stream_position().unwrap(), 32);
+
+    let mut small = [0; 2];
+    reader.read_exact(&mut small).unwrap();
+    assert_eq!(small, [32, 33]);
+    assert_eq!(reader.stream_position().unwrap(), 34);
+    assert!(reader.seek(SeekFrom::Current(-35)).is_err());
+}
+
+#[test]
+fn buf_reader_binread() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Header {
+        magic: u32,
+        #[br(count = 3)]
+        values: Vec<u16>,
+        #[br(restore_position)]
+        peek: u8,
+        last: u8,
+    }
+
+    let mut reader = BufReader::new(Counter::new(Cursor::new(
+        b"\0\0\0\x01\0\x02\0\x03\0\x04\x05",
+    )));
+    let header = reader.read_ne::<Header>().unwrap();
+    assert_eq!(header.values, [2, 3, 4]);
+    assert_eq!((header.peek, header.last), (5, 5));
+    assert!(reader.get_ref().seeks <= 1);
+}
@@ -1,2 +1,6 @@
+mod bits;
+mod bounded;
+mod buf_reader;
 #[cfg(not(feature = "std"))]
 mod no_std;
+mod take_seek;
@@ -1,4 +1,4 @@
-use binrw::io::{Cursor, Read, Seek, SeekFrom};
+use binrw::io::{BufRead, Cursor, Read, Seek, SeekFrom};
 use std::io::{Read as StdReadExt, Seek as StdSeekExt};
 
 #[test]
@@ -28,3 +28,40 @@ fn cursor_test() {
     assert_eq!(test.read(&mut buf).unwrap(), test2.read(&mut buf2).unwrap());
     assert_eq!(buf, buf2);
 }
+
+#[test]
+fn cursor_seek() {
+    let data = &[1, 2, 3, 4, 5];
+    let mut test = Cursor::new(data);
+    let mut test2 = std::io::Cursor::new(data);
+
+    assert_eq!(
+        test.seek(SeekFrom::End(-2)).unwrap(),
+        test2.seek(std::io::SeekFrom::End(-2)).unwrap(),
+    );
+    assert_eq!(test.position(), test2.position());
+    assert_eq!(
+        test.seek(SeekFrom::Current(-1)).unwrap(),
+        test2.seek(std::io::SeekFrom::Current(-1)).unwrap(),
+    );
+    assert_eq!(
+        test.seek(SeekFrom::End(2)).unwrap(),
+        test2.seek(std::io::SeekFrom::End(2)).unwrap(),
+    );
+    assert!(test.seek(SeekFrom::End(-6)).is_err());
+    assert!(test.seek(SeekFrom::Current(-8)).is_err());
+    assert_eq!(test.position(), 7);
+}
+
+#[test]
+fn cursor_buf_read() {
+    let mut test = Cursor::new(b"ab\ncd");
+    assert_eq!(test.fill_buf().unwrap(), b"ab\ncd");
+    test.consume(1);
+    let mut line = String::new();
+    assert_eq!(test.read_line(&mut line).unwrap(), 2);
+    assert_eq!(line, "b\n");
+    assert_eq!(test.position(), 3);
+    test.set_position(10);
+    assert_eq!(test.fill_buf().unwrap(), b"");
+}
@@ -1,6 +1,6 @@
 mod cursor;
 
-use binrw::io::{Cursor, Error, ErrorKind, Read, Result};
+use binrw::io::{copy, BufRead, Cursor, Error, ErrorKind, Read, Result, Write};
 
 #[derive(Debug)]
 struct MalfunctioningEddie<'data> {
@@ -50,6 +50,8 @@ fn sanity_check() {
     assert!(TypeId::of::<Error>() != TypeId::of::<std::io::Error>());
     assert!(TypeId::of::<ErrorKind>() != TypeId::of::<std::io::ErrorKind>());
     assert!(TypeId::of::<dyn Read>() != TypeId::of::<dyn std::io::Read>());
+    assert!(TypeId::of::<dyn BufRead>() != TypeId::of::<dyn std::io::BufRead>());
+    assert!(TypeId::of::<dyn Write>() != TypeId::of::<dyn std::io::Write>());
     assert!(TypeId::of::<Result<()>>() != TypeId::of::<std::io::Result<()>>());
 }
 
@@ -259,3 +261,114 @@ fn take() {
         ErrorKind::ConnectionRefused
     );
 }
+
+#[test]
+fn chain() {
+    let mut x = (&b"AB"[..]).chain(&b"CD"[..]);
+    let mut out = [0; 3];
+    assert_eq!(x.read(&mut out).unwrap(), 2);
+    assert_eq!(&out[..2], b"AB");
+    x.read_exact(&mut out[..2]).unwrap();
+    assert_eq!(&out[..2], b"CD");
+    assert_eq!(x.read(&mut out).unwrap(), 0);
+
+    let mut out = Vec::new();
+    let x = Cursor::new(b"AB").chain(Cursor::new(b"CD")).take(3);
+    assert_eq!(x.into_inner().read_to_end(&mut out).unwrap(), 4);
+    assert_eq!(out, b"ABCD");
+
+    // Errors should be returned from either reader
+    let mut first = MalfunctioningEddie::new(b"AB");
+    first.trigger_fatal_error();
+    let mut x = first.chain(&b"CD"[..]);
+    assert_eq!(x.read(&mut out).unwrap_err().kind(), ErrorKind::BrokenPipe);
+    let (first, second) = x.get_ref();
+    assert_eq!(first.data.position(), 0);
+    assert_eq!(*second, b"CD");
+}
+
+#[test]
+fn buf_read() {
+    let mut x = &b"one\ntwo\0three"[..];
+    let mut out = Vec::new();
+    assert_eq!(x.read_until(b'\n', &mut out).unwrap(), 4);
+    assert_eq!(out, b"one\n");
+    assert_eq!(x.fill_buf().unwrap(), b"two\0three");
+    x.consume(4);
+
+    let mut line = String::new();
+    assert_eq!(x.read_line(&mut line).unwrap(), 5);
+    assert_eq!(line, "three");
+    assert_eq!(x.read_line(&mut line).unwrap(), 0);
+
+    // Invalid UTF-8 should not be appended
+    let mut x = &b"\xff\n"[..];
+    assert_eq!(
+        x.read_line(&mut line).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert_eq!(line, "three");
+
+    // Take should not allow reading past its limit, and chain should
+    // continue into the second reader
+    let mut x = (&b"abc"[..]).take(2).chain(&b"d\ne"[..]);
+    let mut out = Vec::new();
+    assert_eq!(x.read_until(b'\n', &mut out).unwrap(), 4);
+    assert_eq!(out, b"abd\n");
+}
+
+#[test]
+fn slice_read() {
+    let mut x = &b"ABCD"[..];
+    let mut out = [0; 3];
+    assert_eq!(x.read(&mut out).unwrap(), 3);
+    assert_eq!(out, *b"ABC");
+    assert_eq!(x, b"D");
+    assert_eq!(
+        x.read_exact(&mut out).unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+    assert!(x.is_empty());
+}
+
+#[test]
+fn vec_write() {
+    let mut out = Vec::new();
+    out.write_all(b"AB").unwrap();
+    assert_eq!(out.write(b"CD").unwrap(), 2);
+    (&mut out).write_all(b"EF").unwrap();
+    out.flush().unwrap();
+    assert_eq!(out, b"ABCDEF");
+}
+
+#[test]
+fn copy_reader() {
+    let data = (0..=255).cycle().take(0x2345).collect::<Vec<u8>>();
+    let mut reader = MalfunctioningEddie::new(&data);
+    reader.trigger_non_fatal_error();
+    let mut out = Vec::new();
+    assert_eq!(copy(&mut reader, &mut out).unwrap(), data.len() as u64);
+    assert_eq!(out, data);
+
+    reader.trigger_fatal_error();
+    assert_eq!(
+        copy(&mut reader, &mut out).unwrap_err().kind(),
+        ErrorKind::BrokenPipe
+    );
+}
+
+#[test]
+fn error_payload() {
+    let mut error = Error::new(ErrorKind::InvalidData, "bad magic");
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert_eq!(error.get_ref().unwrap().to_string(), "bad magic");
+    assert!(error.get_mut().is_some());
+    assert_eq!(error.to_string(), "bad magic");
+    assert!(format!("{:?}", error).contains("bad magic"));
+    assert_eq!(error.into_inner().unwrap().to_string(), "bad magic");
+
+    let error = Error::from(ErrorKind::NotFound);
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+    assert!(error.get_ref().is_none());
+    assert!(error.into_inner().is_none());
+}
@@ -0,0 +1,70 @@
+use binrw::io::{Cursor, ErrorKind, Read, Seek, SeekFrom, TakeSeek, TakeSeekExt};
+use binrw::{BinRead, BinReaderExt, FilePtr8};
+
+#[test]
+fn take_seek_read() {
+    let mut stream = Cursor::new(b"\0\x01\x02\x03\x04\x05");
+    stream.seek(SeekFrom::Start(1)).unwrap();
+    let mut window = (&mut stream).take_seek(3).unwrap();
+    assert_eq!(window.limit(), 3);
+
+    let mut buf = [0; 2];
+    window.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [1, 2]);
+    assert_eq!(window.limit(), 1);
+    assert_eq!(window.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], 3);
+    assert_eq!(window.read(&mut buf).unwrap(), 0);
+    assert_eq!(window.stream_position().unwrap(), 3);
+
+    window.set_limit(1);
+    assert_eq!(window.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], 4);
+    assert_eq!(stream.position(), 5);
+}
+
+#[test]
+fn take_seek_seek() {
+    let mut stream = Cursor::new(b"\0\x01\x02\x03\x04\x05");
+    stream.seek(SeekFrom::Start(2)).unwrap();
+    let mut window = TakeSeek::new(stream, 3).unwrap();
+    let mut buf = [0; 1];
+
+    assert_eq!(window.seek(SeekFrom::End(-1)).unwrap(), 2);
+    window.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [4]);
+
+    assert_eq!(window.seek(SeekFrom::Current(-3)).unwrap(), 0);
+    window.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [2]);
+
+    assert_eq!(window.seek(SeekFrom::Start(1)).unwrap(), 1);
+    assert_eq!(window.get_ref().position(), 3);
+
+    // Seeking past the end is allowed, but there is nothing to read
+    assert_eq!(window.seek(SeekFrom::End(2)).unwrap(), 5);
+    assert_eq!(window.read(&mut buf).unwrap(), 0);
+
+    // The window cannot be escaped by seeking backwards
+    assert_eq!(
+        window.seek(SeekFrom::Current(-6)).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    assert_eq!(window.stream_position().unwrap(), 5);
+}
+
+#[test]
+fn take_seek_binread() {
+    #[derive(BinRead, Debug)]
+    struct Table {
+        first: FilePtr8<u8>,
+        second: FilePtr8<u8>,
+    }
+
+    // Offsets are relative to the start of the window
+    let mut stream = Cursor::new(b"\xff\x02\x03\x07\x08");
+    stream.seek(SeekFrom::Start(1)).unwrap();
+    let table = stream.take_seek(4).unwrap().read_ne::<Table>().unwrap();
+    assert_eq!(*table.first, 7);
+    assert_eq!(*table.second, 8);
+}
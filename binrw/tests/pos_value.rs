@@ -2,7 +2,7 @@
 extern crate alloc;
 #[cfg(not(feature = "std"))]
 use alloc::format;
-use binrw::{io::Cursor, BinRead, BinReaderExt, PosValue};
+use binrw::{binrw, io::Cursor, BinRead, BinReaderExt, BinWriterExt, PosValue};
 
 #[test]
 fn pos_value() {
@@ -25,3 +25,69 @@ fn pos_value() {
     assert_eq!(*clone, *val.b);
     assert_eq!(clone.pos, val.b.pos);
 }
+
+#[test]
+fn pos_value_write() {
+    #[binrw]
+    #[derive(Debug)]
+    struct MyType {
+        a: u16,
+        b: PosValue<u8>,
+        #[bw(write_with = PosValue::write_checked)]
+        c: PosValue<u8>,
+    }
+
+    let mut val = Cursor::new(b"\xFF\xFE\xFD\xFC")
+        .read_be::<MyType>()
+        .unwrap();
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&val).unwrap();
+    assert_eq!(out.into_inner(), b"\xFF\xFE\xFD\xFC");
+
+    // Only the position of the checked value matters
+    val.b.pos = 0;
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&val).unwrap();
+
+    val.c.pos = 0;
+    let mut out = Cursor::new(Vec::new());
+    let error = out.write_be(&val).unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::AssertFail { pos: 3, .. }
+    ));
+}
+
+#[test]
+fn pos_value_write_recorded() {
+    use binrw::{binwrite, BinWrite, Endian, WriteOptions};
+    use core::cell::Cell;
+
+    #[binwrite]
+    #[bw(context(c_pos: Cell<u64>))]
+    struct MyType {
+        a: u16,
+        b: PosValue<u8>,
+        #[bw(write_with = PosValue::write_recorded, args(c_pos, ()))]
+        c: PosValue<u8>,
+    }
+
+    let val = MyType {
+        a: 1,
+        b: PosValue { val: 2, pos: 0 },
+        c: PosValue { val: 3, pos: 0 },
+    };
+
+    let c_pos = Cell::new(0u64);
+    let mut out = Cursor::new(vec![0; 4]);
+    out.set_position(4);
+    val.write_options(
+        &mut out,
+        &WriteOptions::new(Endian::Big).with_context(&c_pos),
+        (),
+    )
+    .unwrap();
+    assert_eq!(c_pos.get(), 7);
+    assert_eq!(out.into_inner(), b"\0\0\0\0\0\x01\x02\x03");
+}
@@ -0,0 +1,135 @@
+use binrw::{
+    args, binrw, io::Cursor, BinRead, BinReaderExt, BinSize, BinWrite, BinWriterExt, Endian, Error,
+    PrefixedString, PrefixedVec, ReadOptions, WriteOptions,
+};
+
+#[test]
+fn prefixed_string_round_trip() {
+    #[binrw]
+    #[derive(BinSize, Debug, PartialEq)]
+    #[brw(little)]
+    struct Strings {
+        short: PrefixedString<u8>,
+        long: PrefixedString<u32>,
+        #[brw(args { includes_prefix: true })]
+        inclusive: PrefixedString<u16>,
+    }
+
+    const DATA: &[u8] = b"\x03abc\x02\0\0\0de\x05\0fgh";
+    let strings: Strings = Cursor::new(DATA).read_le().unwrap();
+    assert_eq!(strings.short.clone().into_string(), "abc");
+    assert_eq!(strings.long.clone().into_string(), "de");
+    assert_eq!(strings.inclusive.clone().into_string(), "fgh");
+    assert_eq!(strings.bin_size().unwrap(), DATA.len() as u64);
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&strings).unwrap();
+    assert_eq!(out.into_inner(), DATA);
+}
+
+#[test]
+fn prefixed_vec_elements() {
+    let mut data = Cursor::new(b"\0\x02\0\x01\0\x02\0\x03".as_ref());
+    let list: PrefixedVec<u16, u16> = data.read_be().unwrap();
+    assert_eq!(*list, [1, 2]);
+
+    // Not enough elements
+    let mut data = Cursor::new(b"\x03\0\x01\0\x02".as_ref());
+    assert!(data.read_be::<PrefixedVec<u8, u16>>().is_err());
+
+    let list = PrefixedVec::<u8, (u8, u16)>::new(vec![(1, 2), (3, 4)]);
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&list).unwrap();
+    assert_eq!(out.into_inner(), b"\x02\x01\0\x02\x03\0\x04");
+}
+
+#[test]
+fn prefixed_vec_bytes() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Entry {
+        len: u8,
+        #[br(count = len)]
+        data: Vec<u8>,
+    }
+
+    let read_options = ReadOptions::new(Endian::Big);
+    let write_options = WriteOptions::new(Endian::Big);
+
+    // Variable-sized elements are read until the length is used up
+    let data = b"\x07\x02ab\x00\x01c\xff";
+    let list = PrefixedVec::<u8, Entry>::read_options(
+        &mut Cursor::new(data),
+        &read_options,
+        args! { count_bytes: true, includes_prefix: true },
+    )
+    .unwrap();
+    assert_eq!(list.len(), 3);
+    assert_eq!(list[2].data, b"c");
+
+    let mut out = Cursor::new(Vec::new());
+    list.write_options(
+        &mut out,
+        &write_options,
+        args! { count_bytes: true, includes_prefix: true },
+    )
+    .unwrap();
+    assert_eq!(out.into_inner(), &data[..7]);
+
+    // Primitive elements which do not fill the length are an error
+    let error = PrefixedVec::<u8, u16>::read_options(
+        &mut Cursor::new(b"\x03\0\x01\0\x02"),
+        &read_options,
+        args! { count_bytes: true },
+    )
+    .unwrap_err();
+    assert!(matches!(error, Error::SizeExceeded { pos: 1, size: 3 }));
+
+    // So are variable-sized elements which go past the end of the list
+    let error = PrefixedVec::<u8, Entry>::read_options(
+        &mut Cursor::new(b"\x02\x02ab"),
+        &read_options,
+        args! { count_bytes: true },
+    )
+    .unwrap_err();
+    assert!(matches!(error, Error::SizeExceeded { pos: 1, size: 2 }));
+}
+
+#[test]
+fn prefixed_errors() {
+    let read_options = ReadOptions::new(Endian::Little);
+    let write_options = WriteOptions::new(Endian::Little);
+
+    // The length prefix cannot include itself when counting elements
+    let error = PrefixedVec::<u8, u16>::read_options(
+        &mut Cursor::new(b"\x01\0\0"),
+        &read_options,
+        args! { includes_prefix: true },
+    )
+    .unwrap_err();
+    assert!(matches!(error, Error::AssertFail { pos: 0, .. }));
+
+    // A length which is smaller than its own prefix
+    let error = PrefixedString::<u16>::read_options(
+        &mut Cursor::new(b"\x01\0"),
+        &read_options,
+        args! { includes_prefix: true },
+    )
+    .unwrap_err();
+    assert!(matches!(error, Error::AssertFail { pos: 0, .. }));
+
+    // A length which does not fit in the prefix
+    let long = PrefixedString::<u8>::from(vec![0; 0x100]);
+    let mut out = Cursor::new(Vec::new());
+    assert!(matches!(
+        long.write_options(&mut out, &write_options, <_>::default()),
+        Err(Error::AssertFail { pos: 0, .. })
+    ));
+    let long = PrefixedString::<u8>::from(vec![0; 0xff]);
+    assert!(long
+        .write_options(&mut out, &write_options, args! { includes_prefix: true })
+        .is_err());
+    assert!(long
+        .write_options(&mut out, &write_options, <_>::default())
+        .is_ok());
+}
@@ -2,7 +2,7 @@
 extern crate alloc;
 #[cfg(not(feature = "std"))]
 use alloc::format;
-use binrw::{io::Cursor, punctuated::Punctuated, BinRead, BinReaderExt};
+use binrw::{binrw, io::Cursor, punctuated::Punctuated, BinRead, BinReaderExt, BinWriterExt};
 
 #[derive(BinRead, Clone, Copy, Debug)]
 #[br(magic = 1u8)]
@@ -72,6 +72,38 @@ fn punctuated_trailing() {
     y[0] = y[1];
 }
 
+#[test]
+fn punctuated_write() {
+    #[binrw]
+    #[derive(Debug)]
+    struct Lists {
+        #[br(count = 3, parse_with = Punctuated::separated)]
+        separated: Punctuated<u16, u8>,
+        #[br(count = 2, parse_with = Punctuated::separated_trailing)]
+        trailing: Punctuated<u16, u8>,
+    }
+
+    let data = b"\0\x03\0\0\x02\x01\0\x01\0\x05\x02\0\x06\x03";
+    let value: Lists = Cursor::new(data).read_be().unwrap();
+    assert_eq!(value.separated.separators, [0, 1]);
+    assert_eq!(value.trailing.separators, [2, 3]);
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&value).unwrap();
+    assert_eq!(out.into_inner(), data);
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&Punctuated::<u8, u8>::new(vec![], vec![]))
+        .unwrap();
+    assert!(out.into_inner().is_empty());
+
+    let mut out = Cursor::new(Vec::new());
+    let error = out
+        .write_be(&Punctuated::<u8, u8>::new(vec![1, 2, 3], vec![0]))
+        .unwrap_err();
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0, .. }));
+}
+
 // TODO: move to UI tests?
 // #[test]
 // #[should_panic]
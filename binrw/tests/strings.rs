@@ -25,6 +25,26 @@ fn null_wide_strings() {
     );
 }
 
+#[test]
+fn null_terminated_non_zero_vecs() {
+    use binrw::{io::Cursor, BinRead};
+    use core::num::{NonZeroU16, NonZeroU8};
+
+    #[derive(BinRead)]
+    #[br(little)]
+    struct Test {
+        narrow: Vec<NonZeroU8>,
+        wide: Vec<NonZeroU16>,
+    }
+
+    let test = Test::read(&mut Cursor::new(b"ab\0c\0\0\0")).unwrap();
+    assert_eq!(
+        test.narrow,
+        [NonZeroU8::new(b'a').unwrap(), NonZeroU8::new(b'b').unwrap()]
+    );
+    assert_eq!(test.wide, [NonZeroU16::new(u16::from(b'c')).unwrap()]);
+}
+
 #[test]
 fn null_strings() {
     use binrw::{io::Cursor, BinReaderExt, NullString};
@@ -78,3 +98,280 @@ fn null_wide_string_round_trip() {
 
     assert_eq!(&s2.into_string(), data);
 }
+
+#[test]
+fn fixed_strings() {
+    use binrw::{io::Cursor, BinReaderExt, FixedString, FixedWideString};
+
+    let mut data = Cursor::new(b"name\0abcfull".as_ref());
+    let name: FixedString<8> = data.read_be().unwrap();
+    assert_eq!(name.clone().into_string(), "name");
+    assert_eq!(name.trailing, b"\0abc");
+    let full: FixedString<4> = data.read_be().unwrap();
+    assert_eq!(full.clone().into_string(), "full");
+    assert!(full.trailing.is_empty());
+
+    let mut data = Cursor::new(b"\0h\0i\0\0\0j".as_ref());
+    let wide: FixedWideString<4> = data.read_be().unwrap();
+    assert_eq!(wide.clone().into_string(), "hi");
+    assert_eq!(wide.trailing, [0, 0x6a]);
+
+    // Not enough data
+    let mut data = Cursor::new(b"abc".as_ref());
+    assert!(data.read_be::<FixedString<4>>().is_err());
+}
+
+#[test]
+fn fixed_string_round_trip() {
+    use binrw::{
+        binrw, io::Cursor, BinReaderExt, BinSize, BinWriterExt, FixedString, FixedWideString,
+    };
+
+    #[binrw]
+    #[derive(BinSize, Debug, PartialEq)]
+    #[brw(little)]
+    struct Header {
+        name: FixedString<8>,
+        #[brw(args { terminator: None, pad: b' ' })]
+        label: FixedString<6>,
+        wide: FixedWideString<3>,
+    }
+
+    assert_eq!(Header::FIXED_SIZE, Some(20));
+
+    const DATA: &[u8] = b"abc\0\xff\xffxyFILE  h\0\0\0\x01\x02";
+    let mut header: Header = Cursor::new(DATA).read_le().unwrap();
+    assert_eq!(header.name.value, b"abc");
+    assert_eq!(header.label.value, b"FILE");
+    assert_eq!(header.label.trailing, b"  ");
+    assert_eq!(header.wide.value, [0x68]);
+
+    // Trailing bytes are preserved as-is
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&header).unwrap();
+    assert_eq!(out.into_inner(), DATA);
+
+    // Changing the length of a string writes new padding instead
+    header.name = FixedString::from_string("ab".into());
+    header.label = FixedString::from_string("B".into());
+    header.wide = FixedWideString::from_string("xyz".into());
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&header).unwrap();
+    assert_eq!(out.into_inner(), b"ab\0\0\0\0\0\0B     x\0y\0z\0".as_ref());
+}
+
+#[test]
+fn fixed_string_overflow() {
+    use binrw::{
+        args, io::Cursor, BinWrite, BinWriterExt, Endian, Error, FixedString, FixedWideString,
+        WriteOptions,
+    };
+
+    let long = FixedString::<4>::from_string("too long".into());
+    let mut out = Cursor::new(Vec::new());
+    out.set_position(2);
+    match out.write_be(&long).unwrap_err().root_cause() {
+        Error::SizeExceeded { pos, size } => assert_eq!((*pos, *size), (2, 4)),
+        error => panic!("unexpected error: {:?}", error),
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    long.write_options(
+        &mut out,
+        &WriteOptions::new(Endian::Big),
+        args! { truncate: true },
+    )
+    .unwrap();
+    assert_eq!(out.into_inner(), b"too ");
+
+    let wide = FixedWideString::<2>::from_string("abc".into());
+    let mut out = Cursor::new(Vec::new());
+    match out.write_le(&wide).unwrap_err().root_cause() {
+        Error::SizeExceeded { size, .. } => assert_eq!(*size, 4),
+        error => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[test]
+fn encoded_strings() {
+    use binrw::{
+        encoding::{Latin1, Utf16, Utf16Be, Utf16Le, Utf8, Windows1252},
+        io::Cursor,
+        BinReaderExt, EncodedString, FixedString, NullString, NullWideString, PrefixedString,
+    };
+
+    let mut data = Cursor::new(b"\xe9\x80\0\x03\xe2\x82\xac".as_ref());
+    let latin1: EncodedString<FixedString<2>, Latin1> = data.read_be().unwrap();
+    assert_eq!(*latin1, "é\u{80}");
+    data.set_position(0);
+    let windows: EncodedString<NullString, Windows1252> = data.read_be().unwrap();
+    assert_eq!(*windows, "é€");
+    let utf8: EncodedString<PrefixedString<u8>, Utf8> = data.read_be().unwrap();
+    assert_eq!(*utf8, "€");
+
+    // Wide strings are decoded in the byte order of the encoding, or of the
+    // field if the encoding does not have one
+    let data = b"\0h\0i\0\0";
+    let mut data = Cursor::new(data.as_ref());
+    let be: EncodedString<NullWideString, Utf16Be> = data.read_le().unwrap();
+    assert_eq!(*be, "hi");
+    data.set_position(0);
+    let le: EncodedString<NullWideString, Utf16Le> = data.read_be().unwrap();
+    assert_eq!(*le, "\u{6800}\u{6900}");
+    data.set_position(0);
+    let field: EncodedString<NullWideString, Utf16> = data.read_be().unwrap();
+    assert_eq!(*field, "hi");
+}
+
+#[test]
+fn encoded_string_boms() {
+    use binrw::{
+        args,
+        encoding::{Utf16Le, Utf8},
+        io::Cursor,
+        BinRead, BinWrite, EncodedString, Endian, NullString, NullWideString, ReadOptions,
+        WriteOptions,
+    };
+
+    let options = ReadOptions::new(Endian::Little);
+    let read_utf16 = |data: &[u8], bom| {
+        <EncodedString<NullWideString, Utf16Le>>::read_options(
+            &mut Cursor::new(data),
+            &options,
+            args! { bom },
+        )
+        .unwrap()
+        .into_string()
+    };
+
+    // A byte order mark overrides the encoding
+    assert_eq!(read_utf16(b"\xfe\xff\0h\0i\0\0", true), "hi");
+    assert_eq!(read_utf16(b"\xff\xfeh\0i\0\0\0", true), "hi");
+    assert_eq!(read_utf16(b"h\0i\0\0\0", true), "hi");
+    assert_eq!(read_utf16(b"\xff\xfeh\0\0\0", false), "\u{feff}h");
+
+    let s = EncodedString::<NullWideString, Utf16Le>::from("hi");
+    let mut out = Cursor::new(Vec::new());
+    s.write_options(
+        &mut out,
+        &WriteOptions::new(Endian::Big),
+        args! { bom: true },
+    )
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\xff\xfeh\0i\0\0\0");
+
+    let s = EncodedString::<NullString, Utf8>::from("hi");
+    let mut out = Cursor::new(Vec::new());
+    s.write_options(
+        &mut out,
+        &WriteOptions::new(Endian::Big),
+        args! { bom: true },
+    )
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\xef\xbb\xbfhi\0");
+    let s = <EncodedString<NullString, Utf8>>::read_options(
+        &mut Cursor::new(b"\xef\xbb\xbfhi\0"),
+        &options,
+        args! { bom: true },
+    )
+    .unwrap();
+    assert_eq!(*s, "hi");
+}
+
+#[test]
+fn encoded_string_errors() {
+    use binrw::{
+        args,
+        encoding::{ErrorMode, Latin1, Utf16, Utf8, Windows1252},
+        io::Cursor,
+        BinRead, BinReaderExt, BinWrite, BinWriterExt, EncodedString, Endian, Error, FixedString,
+        NullString, NullWideString, ReadOptions, WriteOptions,
+    };
+
+    let mut data = Cursor::new(b"\0a\xff\0".as_ref());
+    data.set_position(1);
+    let error = data
+        .read_be::<EncodedString<NullString, Utf8>>()
+        .unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        Error::BadEncoding {
+            pos: 1,
+            encoding: "UTF-8"
+        }
+    ));
+
+    let s = <EncodedString<NullString, Utf8>>::read_options(
+        &mut Cursor::new(b"a\xff\0"),
+        &ReadOptions::new(Endian::Big),
+        args! { errors: ErrorMode::Replace },
+    )
+    .unwrap();
+    assert_eq!(*s, "a\u{fffd}");
+
+    // Unpaired surrogates
+    let mut data = Cursor::new(b"\xd8\0\0\0".as_ref());
+    assert!(data
+        .read_be::<EncodedString<NullWideString, Utf16>>()
+        .is_err());
+
+    let mut out = Cursor::new(Vec::new());
+    let s = EncodedString::<NullString, Latin1>::from("a€");
+    assert!(matches!(
+        out.write_be(&s).unwrap_err().root_cause(),
+        Error::BadEncoding {
+            pos: 0,
+            encoding: "Latin-1"
+        }
+    ));
+    s.write_options(
+        &mut out,
+        &WriteOptions::new(Endian::Big),
+        args! { errors: ErrorMode::Replace },
+    )
+    .unwrap();
+    assert_eq!(out.into_inner(), b"a?\0");
+
+    // Inner arguments are passed to the raw string type
+    let s = EncodedString::<FixedString<4>, Windows1252>::from("€ab");
+    let mut out = Cursor::new(Vec::new());
+    s.write_options(
+        &mut out,
+        &WriteOptions::new(Endian::Big),
+        args! { inner: args! { pad: b' ', terminator: None } },
+    )
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\x80ab ");
+}
+
+#[cfg(feature = "shift_jis")]
+#[test]
+fn shift_jis() {
+    use binrw::{
+        args, encoding::ErrorMode, encoding::ShiftJis, io::Cursor, BinReaderExt, BinWrite,
+        BinWriterExt, EncodedString, Endian, NullString, WriteOptions,
+    };
+
+    let data = b"\x83n\x83\x8d\x81[\0";
+    let s: EncodedString<NullString, ShiftJis> = Cursor::new(data).read_be().unwrap();
+    assert_eq!(*s, "ハロー");
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_be(&s).unwrap();
+    assert_eq!(out.into_inner(), data);
+
+    assert!(Cursor::new(b"\x83\0")
+        .read_be::<EncodedString<NullString, ShiftJis>>()
+        .is_err());
+
+    let s = EncodedString::<NullString, ShiftJis>::from("ハ😀");
+    let mut out = Cursor::new(Vec::new());
+    assert!(out.write_be(&s).is_err());
+    s.write_options(
+        &mut out,
+        &WriteOptions::new(Endian::Big),
+        args! { errors: ErrorMode::Replace },
+    )
+    .unwrap();
+    assert_eq!(out.into_inner(), b"\x83n?\0");
+}
@@ -0,0 +1,13 @@
+use binrw::BinWrite;
+
+#[derive(BinWrite)]
+struct Foo {
+    #[bw(map = |a: &u8| *a as u16, backpatch = 0u16)]
+    a: u8,
+    #[bw(ignore, backpatch = 0)]
+    b: u8,
+    #[bw(bits = 4, byte_range = c_range)]
+    c: u8,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use binrw::{BinRead, BinWrite};
+
+#[derive(BinRead)]
+struct Foo {
+    #[br(bits = 4, pad_before = 1)]
+    a: u8,
+    #[br(bits = 4, count = 1)]
+    b: u8,
+    #[br(bits = 4, calc = 1)]
+    c: u8,
+}
+
+#[derive(BinWrite)]
+struct Bar {
+    #[bw(bits = 4, magic = 1u8)]
+    a: u8,
+    #[bw(bits = 4, align_after = 2)]
+    b: u8,
+}
+
+fn main() {}
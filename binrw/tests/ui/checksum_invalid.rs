@@ -0,0 +1,55 @@
+use binrw::{binrw, checksum::Crc32, BinRead, BinWrite};
+
+#[derive(BinRead)]
+struct MissingField {
+    a: u8,
+    #[br(checksum(Crc32, from = b))]
+    crc: u32,
+}
+
+#[derive(BinWrite)]
+struct Reversed {
+    a: u8,
+    b: u8,
+    #[bw(checksum(Crc32, from = b, to = a))]
+    crc: u32,
+}
+
+#[binrw]
+struct CoversItself {
+    a: u8,
+    #[brw(checksum(Crc32, from = a, to = b))]
+    crc: u32,
+    b: u8,
+}
+
+#[derive(BinRead)]
+struct Calculated {
+    a: u8,
+    #[br(calc = 0, checksum(Crc32, from = a))]
+    crc: u32,
+}
+
+#[derive(BinWrite)]
+struct Mapped {
+    a: u8,
+    #[bw(map = |crc: &u32| *crc as u64, checksum(Crc32, from = a))]
+    crc: u32,
+}
+
+#[derive(BinRead)]
+#[br(stream)]
+struct Streamed {
+    a: u8,
+    #[br(checksum(Crc32, from = a))]
+    crc: u32,
+}
+
+#[derive(BinRead)]
+struct MissingFrom {
+    a: u8,
+    #[br(checksum(Crc32, to = a))]
+    crc: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use binrw::{BinWrite, FilePtr8};
+
+#[derive(BinWrite)]
+enum Foo {
+    #[bw(magic = 0u8, data_section)]
+    A { a: FilePtr8<u8> },
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-use binrw::BinRead;
+use binrw::{BinRead, BinWrite};
 
 #[derive(BinRead)]
 struct Foo {
@@ -6,4 +6,12 @@ struct Foo {
     a: u8,
 }
 
+#[derive(BinWrite)]
+struct Bar {
+    #[bw(if(false, 0, 1, 2, 3))]
+    a: u8,
+    #[bw(if())]
+    b: u8,
+}
+
 fn main() {}
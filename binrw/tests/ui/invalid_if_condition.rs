@@ -1,4 +1,4 @@
-use binrw::BinRead;
+use binrw::{BinRead, BinWrite};
 
 #[derive(BinRead)]
 struct Foo {
@@ -6,4 +6,10 @@ struct Foo {
     a: i32,
 }
 
+#[derive(BinWrite)]
+struct Bar {
+    #[bw(if("wrong type"))]
+    a: i32,
+}
+
 fn main() {}
@@ -0,0 +1,7 @@
+use binrw::BinRead;
+
+#[derive(BinRead)]
+#[br(magic = '€')]
+struct Foo;
+
+fn main() {}
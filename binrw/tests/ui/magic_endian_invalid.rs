@@ -0,0 +1,23 @@
+use binrw::{BinRead, BinWrite};
+
+#[derive(BinRead)]
+#[br(big, magic_endian = 0xa1b2c3d4u32)]
+struct Conflict;
+
+#[derive(BinRead)]
+#[br(magic_endian = 0xa1b2c3d4)]
+struct NoSuffix;
+
+#[derive(BinWrite)]
+#[bw(magic_endian(b"II", b"MMM"))]
+struct Length;
+
+#[derive(BinWrite)]
+#[bw(magic_endian(b"II", 1u16))]
+struct Mixed;
+
+#[derive(BinRead)]
+#[br(magic_endian = b"II")]
+struct Single;
+
+fn main() {}
@@ -0,0 +1,30 @@
+use binrw::{BinRead, BinWrite};
+
+#[derive(BinRead)]
+struct Calculated {
+    #[br(calc = 0, size = 1)]
+    a: u8,
+}
+
+#[derive(BinRead)]
+struct Bits {
+    #[br(bits = 4, size = 1)]
+    a: u8,
+    #[br(bits = 4)]
+    b: u8,
+}
+
+#[derive(BinWrite)]
+struct Transformed {
+    #[bw(size = 4, transform = binrw::io::transform::Rle)]
+    a: u8,
+}
+
+#[derive(BinRead)]
+#[br(borrow)]
+struct Borrowed<'a> {
+    #[br(size = 4)]
+    a: &'a [u8],
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use binrw::BinRead;
+
+#[derive(BinRead)]
+#[br(stream)]
+enum Foo {
+    A(u8),
+    B(u16),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use binrw::{BinRead, FilePtr32};
+
+#[derive(BinRead)]
+#[br(stream)]
+struct Foo {
+    a: u8,
+    b: FilePtr32<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use binrw::{BinRead, FilePtr32};
+
+#[derive(BinRead)]
+#[br(stream)]
+struct Foo {
+    #[br(map = |ptr: FilePtr32<u8>| *ptr)]
+    a: u8,
+}
+
+fn main() {}
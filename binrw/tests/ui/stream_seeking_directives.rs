@@ -0,0 +1,16 @@
+use binrw::BinRead;
+
+#[derive(BinRead)]
+#[br(stream)]
+struct Foo {
+    #[br(seek_before = binrw::io::SeekFrom::Start(0))]
+    a: u8,
+    #[br(restore_position)]
+    b: u8,
+    #[br(align_after = 4)]
+    c: u8,
+    #[br(try)]
+    d: Option<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use binrw::{BinRead, BinWrite};
+
+#[derive(BinRead)]
+struct Calculated {
+    #[br(calc = 0, transform = binrw::io::transform::Rle)]
+    a: u8,
+}
+
+#[derive(BinRead)]
+struct Bits {
+    #[br(bits = 4, transform = binrw::io::transform::Rle)]
+    a: u8,
+    #[br(bits = 4)]
+    b: u8,
+}
+
+#[derive(BinWrite)]
+struct Backpatched {
+    #[bw(backpatch = 1, transform = binrw::io::transform::Rle)]
+    a: u8,
+}
+
+#[derive(BinRead)]
+#[br(borrow)]
+struct Borrowed<'a> {
+    #[br(transform = binrw::io::transform::Rle)]
+    a: &'a [u8],
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use binrw::{BinRead, BinWrite};
+
+#[derive(BinRead)]
+struct Foo {
+    #[br(since = 2)]
+    a: u8,
+}
+
+#[derive(BinWrite)]
+enum Bar {
+    #[bw(magic = 0u8)]
+    A {
+        #[bw(until = 3)]
+        a: u8,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,151 @@
+use binrw::{
+    binrw, io::Cursor, BinReaderExt, BinSize, BinWriterExt, Error, PrefixedString, PrefixedVec,
+    SLeb128, ULeb128, VarInt, ZigZag,
+};
+
+fn write<T: binrw::BinWrite<Args = ()>>(value: &T) -> Vec<u8> {
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(value).unwrap();
+    out.into_inner()
+}
+
+#[test]
+fn uleb128_round_trip() {
+    const CASES: &[(u64, &[u8])] = &[
+        (0, b"\x00"),
+        (127, b"\x7f"),
+        (128, b"\x80\x01"),
+        (624_485, b"\xe5\x8e\x26"),
+        (u64::MAX, b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01"),
+    ];
+
+    for &(value, data) in CASES {
+        let read: ULeb128 = Cursor::new(data).read_le().unwrap();
+        assert_eq!(read, ULeb128(value));
+        assert_eq!(write(&read), data);
+        assert_eq!(read.bin_size().unwrap(), data.len() as u64);
+    }
+}
+
+#[test]
+fn sleb128_round_trip() {
+    const CASES: &[(i64, &[u8])] = &[
+        (0, b"\x00"),
+        (63, b"\x3f"),
+        (64, b"\xc0\x00"),
+        (-1, b"\x7f"),
+        (-64, b"\x40"),
+        (-65, b"\xbf\x7f"),
+        (-123_456, b"\xc0\xbb\x78"),
+        (i64::MIN, b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x7f"),
+        (i64::MAX, b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x00"),
+    ];
+
+    for &(value, data) in CASES {
+        let read: SLeb128 = Cursor::new(data).read_le().unwrap();
+        assert_eq!(read, SLeb128(value));
+        assert_eq!(write(&read), data);
+    }
+}
+
+#[test]
+fn zigzag_round_trip() {
+    assert_eq!(write(&ZigZag(0i32)), b"\x00");
+    assert_eq!(write(&ZigZag(-1i32)), b"\x01");
+    assert_eq!(write(&ZigZag(1i32)), b"\x02");
+    assert_eq!(write(&ZigZag(i8::MIN)), b"\xff\x01");
+    assert_eq!(
+        write(&ZigZag(i64::MIN)),
+        b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01"
+    );
+
+    for &value in &[0, 1, -1, 1000, -1000, i64::MAX, i64::MIN] {
+        let read: ZigZag<i64> = Cursor::new(write(&ZigZag(value))).read_le().unwrap();
+        assert_eq!(read.0, value);
+    }
+}
+
+fn assert_error<T: binrw::BinRead<Args = ()>>(data: &[u8], expected: &str) {
+    let mut reader = Cursor::new([b"\xaa".as_ref(), data].concat());
+    reader.set_position(1);
+    match reader.read_le::<T>() {
+        Err(Error::AssertFail { pos, message }) => {
+            assert_eq!(pos, 1);
+            assert!(message.contains(expected), "unexpected message {}", message);
+        }
+        _ => panic!("expected an error reading {:02x?}", data),
+    }
+}
+
+#[test]
+fn overflow() {
+    assert_error::<ULeb128>(b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x02", "too large");
+    assert_error::<ULeb128>(b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x01", "too large");
+    assert_error::<SLeb128>(b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01", "too large");
+    assert_error::<SLeb128>(b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x7e", "too large");
+    assert_error::<ZigZag<i8>>(b"\x80\x02", "too large");
+}
+
+#[test]
+fn overlong() {
+    assert_error::<ULeb128>(b"\x80\x00", "overlong");
+    assert_error::<ULeb128>(b"\xff\x80\x00", "overlong");
+    assert_error::<ULeb128>(b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x00", "overlong");
+    assert_error::<SLeb128>(b"\x80\x00", "overlong");
+    assert_error::<SLeb128>(b"\xff\x7f", "overlong");
+    assert_error::<ZigZag<i32>>(b"\x81\x00", "overlong");
+
+    // Padding which changes the sign bit is needed
+    let value: SLeb128 = Cursor::new(b"\xc0\x00").read_le().unwrap();
+    assert_eq!(value.0, 64);
+}
+
+#[test]
+fn count() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        len: VarInt,
+        #[br(count = len)]
+        data: Vec<u8>,
+        signed_len: ZigZag<i16>,
+        #[br(count = signed_len)]
+        signed_data: Vec<u8>,
+    }
+
+    let data = [&[0x81, 0x01][..], &[7; 129], b"\x04\x01\x02"].concat();
+    let test: Test = Cursor::new(&data).read_le().unwrap();
+    assert_eq!(test.data, [7; 129]);
+    assert_eq!(test.signed_data, [1, 2]);
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&test).unwrap();
+    assert_eq!(out.into_inner(), data);
+}
+
+#[test]
+fn length_prefix() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        name: PrefixedString<VarInt>,
+        #[brw(args { includes_prefix: true })]
+        data: PrefixedVec<ULeb128, u8>,
+    }
+
+    let test = Test {
+        name: PrefixedString::from(b"binrw".to_vec()),
+        data: PrefixedVec::new(vec![1; 127]),
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    out.write_le(&test).unwrap();
+    let data = out.into_inner();
+    assert_eq!(&data[..6], b"\x05binrw");
+    // 127 bytes of data plus a 2 byte prefix
+    assert_eq!(&data[6..8], b"\x81\x01");
+    assert_eq!(data.len(), 6 + 129);
+
+    let read: Test = Cursor::new(data).read_le().unwrap();
+    assert_eq!(read, test);
+}
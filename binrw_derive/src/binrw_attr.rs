@@ -3,7 +3,8 @@ use std::collections::HashSet;
 use quote::quote;
 use syn::DeriveInput;
 
-use crate::codegen::{generate_binread_impl, generate_binwrite_impl};
+use crate::binwrite::take_bin_size_derive;
+use crate::codegen::{generate_bin_size_impl, generate_binread_impl, generate_binwrite_impl};
 use crate::parser::{
     read, read::is_binread_attr, write, write::is_binwrite_attr, ParseResult, TempableField,
 };
@@ -22,6 +23,8 @@ pub(crate) fn derive_from_attribute(mut derive_input: DeriveInput) -> proc_macro
 
     let generated_impl_rd = generate_binread_impl(&derive_input, &binread_input);
     let generated_impl_wr = generate_binwrite_impl(&derive_input, &binwrite_input);
+    let generated_impl_size = take_bin_size_derive(&mut derive_input)
+        .then(|| generate_bin_size_impl(&derive_input, &binwrite_input));
 
     let binread_input = binread_input.ok();
     let binwrite_input = binwrite_input.ok();
@@ -49,6 +52,7 @@ pub(crate) fn derive_from_attribute(mut derive_input: DeriveInput) -> proc_macro
         #derive_input
         #generated_impl_rd
         #generated_impl_wr
+        #generated_impl_size
     )
 }
 
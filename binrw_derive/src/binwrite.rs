@@ -1,10 +1,10 @@
 use crate::{
-    codegen::generate_binwrite_impl,
+    codegen::{generate_bin_size_impl, generate_binwrite_impl},
     parser::{read::is_binread_attr, write, write::is_binwrite_attr, ParseResult},
 };
 
 use quote::quote;
-use syn::DeriveInput;
+use syn::{punctuated::Punctuated, DeriveInput, Token};
 
 #[cfg(not(tarpaulin_include))]
 fn clean_struct_attrs(attrs: &mut Vec<syn::Attribute>) {
@@ -21,9 +21,48 @@ fn has_attr(input: &DeriveInput, attr_name: &str) -> bool {
     })
 }
 
+/// Removes `BinSize` from the derives of the input, returning true if it was
+/// there.
+///
+/// The attribute macros remove the attributes that `#[derive(BinSize)]` reads,
+/// so they have to generate the impl themselves instead.
+pub(crate) fn take_bin_size_derive(input: &mut DeriveInput) -> bool {
+    let mut found = false;
+    input.attrs.retain_mut(|attr| {
+        if !attr.path.is_ident("derive") {
+            return true;
+        }
+
+        let Ok(paths) = attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+        else {
+            return true;
+        };
+
+        let is_bin_size = |path: &syn::Path| {
+            path.segments
+                .last()
+                .is_some_and(|seg| seg.ident == "BinSize")
+        };
+        if !paths.iter().any(is_bin_size) {
+            return true;
+        }
+
+        found = true;
+        let paths = paths
+            .into_iter()
+            .filter(|path| !is_bin_size(path))
+            .collect::<Vec<_>>();
+        attr.tokens = quote! { (#(#paths),*) };
+        !paths.is_empty()
+    });
+    found
+}
+
 #[cfg(not(tarpaulin_include))]
 pub(crate) fn derive_from_attribute(mut derive_input: DeriveInput) -> proc_macro2::TokenStream {
     let (binwrite_input, generated_impl) = derive_from_input(&derive_input, false);
+    let bin_size_impl = take_bin_size_derive(&mut derive_input)
+        .then(|| generate_bin_size_impl(&derive_input, &binwrite_input));
     let binwrite_input = binwrite_input.ok();
 
     // only clean fields if binread isn't going to be applied after
@@ -33,6 +72,7 @@ pub(crate) fn derive_from_attribute(mut derive_input: DeriveInput) -> proc_macro
 
             #derive_input
             #generated_impl
+            #bin_size_impl
         };
     }
 
@@ -58,6 +98,7 @@ pub(crate) fn derive_from_attribute(mut derive_input: DeriveInput) -> proc_macro
     quote!(
         #derive_input
         #generated_impl
+        #bin_size_impl
     )
 }
 
@@ -71,6 +112,11 @@ pub(crate) fn derive_from_input(
     (binwrite_input, generated_impl)
 }
 
+pub(crate) fn derive_bin_size_from_input(derive_input: &DeriveInput) -> proc_macro2::TokenStream {
+    let binwrite_input = write::Input::from_input(derive_input, true);
+    generate_bin_size_impl(derive_input, &binwrite_input)
+}
+
 #[cfg(not(tarpaulin_include))]
 fn clean_field_attrs(
     binwrite_input: &Option<write::Input>,
@@ -0,0 +1,151 @@
+use super::sanitization::{BIN_SIZE_TRAIT, SAME_FIXED_SIZE, SUM_FIXED_SIZES};
+use crate::parser::{
+    write::{EnumVariant, Input, Struct, StructField},
+    CondEndian, Magic, MagicEndian, WriteMode,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote_spanned, spanned::Spanned};
+
+pub(crate) fn generate(input: Option<&Input>, derive_input: &syn::DeriveInput) -> TokenStream {
+    // Every type which contributes to the fixed size has to implement
+    // `BinSize`, which is checked by requiring it in the where clause
+    let mut types = Vec::new();
+    let fixed_size = input.map(|input| {
+        let fixed_size = fixed_size(input, &mut types)
+            .unwrap_or_else(|| quote! { ::core::option::Option::None });
+        quote! {
+            const FIXED_SIZE: ::core::option::Option<u64> = #fixed_size;
+        }
+    });
+
+    let mut generics = derive_input.generics.clone();
+    let predicates = &mut generics.make_where_clause().predicates;
+    for ty in types {
+        predicates.push(parse_quote_spanned! {ty.span()=> #ty: #BIN_SIZE_TRAIT });
+    }
+
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #BIN_SIZE_TRAIT for #name #ty_generics #where_clause {
+            #fixed_size
+        }
+    }
+}
+
+/// Returns an expression for the fixed size of the input, or `None` if the
+/// size can change.
+fn fixed_size<'a>(input: &'a Input, types: &mut Vec<&'a syn::Type>) -> Option<TokenStream> {
+    if input.map().is_some() || input.has_data_section() {
+        return None;
+    }
+
+    match input {
+        Input::Struct(st) | Input::UnitStruct(st) => {
+            struct_fixed_size(&CondEndian::Inherited, &None, st, types)
+        }
+        Input::Enum(en) => {
+            let variants = en
+                .variants
+                .iter()
+                .map(|variant| match variant {
+                    EnumVariant::Variant { options, .. } => {
+                        struct_fixed_size(&en.endian, &en.magic, options, types)
+                    }
+                    EnumVariant::Unit(variant) => Some(sum(&[
+                        magic_endian_size(&en.endian),
+                        magic_size(&en.magic),
+                        magic_size(&variant.magic),
+                    ])),
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(quote! { #SAME_FIXED_SIZE(&[#(#variants),*]) })
+        }
+        Input::UnitOnlyEnum(en) => {
+            let value_size = if let Some(repr) = &en.repr {
+                let repr = repr.as_ref();
+                quote! { <#repr as #BIN_SIZE_TRAIT>::FIXED_SIZE }
+            } else {
+                let variants = en.fields.iter().map(|field| magic_size(&field.magic));
+                quote! { #SAME_FIXED_SIZE(&[#(#variants),*]) }
+            };
+            Some(sum(&[
+                magic_endian_size(&en.endian),
+                magic_size(&en.magic),
+                value_size,
+            ]))
+        }
+    }
+}
+
+fn struct_fixed_size<'a>(
+    outer_endian: &CondEndian,
+    outer_magic: &Magic,
+    st: &'a Struct,
+    types: &mut Vec<&'a syn::Type>,
+) -> Option<TokenStream> {
+    let mut sizes = vec![
+        magic_endian_size(outer_endian),
+        magic_size(outer_magic),
+        magic_endian_size(&st.endian),
+        magic_size(&st.magic),
+    ];
+    for field in &st.fields {
+        if !field.is_written() {
+            continue;
+        }
+
+        if has_variable_size(field) {
+            return None;
+        }
+
+        let ty = &field.ty;
+        types.push(ty);
+        sizes.push(magic_size(&field.magic));
+        sizes.push(quote! { <#ty as #BIN_SIZE_TRAIT>::FIXED_SIZE });
+    }
+
+    Some(sum(&sizes))
+}
+
+/// Returns true if the number of bytes written for the field depends on
+/// something other than its type.
+fn has_variable_size(field: &StructField) -> bool {
+    matches!(
+        field.write_mode,
+        WriteMode::WriteWith(_) | WriteMode::Bits(_)
+    ) || field.map.is_some()
+        || field.count.is_some()
+        || field.restore_position.is_some()
+        || field.seek_before.is_some()
+        || field.pad_before.is_some()
+        || field.pad_after.is_some()
+        || field.align_before.is_some()
+        || field.align_after.is_some()
+        || field.pad_size_to.is_some()
+        || field.size.is_some()
+        || field.transform.is_some()
+        || field.is_conditional()
+}
+
+fn magic_size(magic: &Magic) -> TokenStream {
+    if let Some(magic) = magic {
+        let ty: TokenStream = magic.kind().into();
+        quote! { ::core::option::Option::Some(::core::mem::size_of::<#ty>() as u64) }
+    } else {
+        quote! { ::core::option::Option::Some(0) }
+    }
+}
+
+fn magic_endian_size(endian: &CondEndian) -> TokenStream {
+    if let CondEndian::Magic(MagicEndian { little, .. }) = endian {
+        quote! { ::core::option::Option::Some((#little).len() as u64) }
+    } else {
+        quote! { ::core::option::Option::Some(0) }
+    }
+}
+
+fn sum(sizes: &[TokenStream]) -> TokenStream {
+    quote! { #SUM_FIXED_SIZES(&[#(#sizes),*]) }
+}
@@ -8,8 +8,12 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> Option
     let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
     let magic = input.magic().as_ref();
     magic.map(|magic| {
-        let ty: TokenStream = magic.kind().into();
-        let val = magic.deref_value();
+        let (ty, val) = if magic.is_char() {
+            let val = magic.deref_value();
+            (quote! { char }, quote! { #val as char })
+        } else {
+            (magic.kind().into(), magic.deref_value())
+        };
         quote! {
             impl #impl_generics #HAS_MAGIC for #name #ty_generics #where_clause {
                 type MagicType = #ty;
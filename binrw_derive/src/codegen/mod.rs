@@ -1,8 +1,10 @@
 #[macro_use]
 pub(crate) mod sanitization;
+mod bin_size;
 mod has_magic;
 mod imports;
 mod read_options;
+mod stream_read;
 pub(crate) mod typed_builder;
 mod types;
 mod write_options;
@@ -38,25 +40,118 @@ pub(crate) fn generate_binread_impl(
         ParseResult::Partial(_, error) | ParseResult::Err(error) => Some(error.to_compile_error()),
     };
 
+    let stream_read_impl = match binread_input {
+        ParseResult::Ok(binread_input) => stream_read::generate(binread_input, derive_input),
+        _ => None,
+    };
+
+    let async_impl = match binread_input {
+        ParseResult::Ok(binread_input) if binread_input.is_async() => {
+            Some(generate_async_binread_impl(derive_input, binread_input))
+        }
+        _ => None,
+    };
+
     let name = &derive_input.ident;
     let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
 
+    let binread_impl = match binread_input {
+        ParseResult::Ok(binread_input) if binread_input.is_borrowed() => {
+            generate_borrowed_binread_impl(derive_input, binread_input, &arg_type)
+        }
+        _ => quote! {
+            #[allow(non_snake_case)]
+            impl #impl_generics #BINREAD_TRAIT for #name #ty_generics #where_clause {
+                type Args = #arg_type;
+
+                fn read_options<R: #READ_TRAIT + #SEEK_TRAIT>
+                    (#READER: &mut R, #OPT: &#READ_OPTIONS<'_>, #ARGS: Self::Args)
+                    -> #BIN_RESULT<Self>
+                {
+                    #read_opt_impl
+                }
+            }
+        },
+    };
+
     quote! {
-        #[allow(non_snake_case)]
-        impl #impl_generics #BINREAD_TRAIT for #name #ty_generics #where_clause {
-            type Args = #arg_type;
+        #binread_impl
 
-            fn read_options<R: #READ_TRAIT + #SEEK_TRAIT>
-                (#READER: &mut R, #OPT: &#READ_OPTIONS, #ARGS: Self::Args)
+        #async_impl
+
+        #stream_read_impl
+
+        #has_magic_impl
+
+        #arg_type_declaration
+    }
+}
+
+fn generate_async_binread_impl(
+    derive_input: &syn::DeriveInput,
+    binread_input: &read::Input,
+) -> TokenStream {
+    if cfg!(not(feature = "async")) {
+        return missing_async_feature();
+    }
+
+    let read_opt_impl = with_io_mode(IoMode::Async, || {
+        read_options::generate(binread_input, derive_input)
+    });
+
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+
+    quote! {
+        #[allow(non_snake_case)]
+        impl #impl_generics #ASYNC_BINREAD_TRAIT for #name #ty_generics #where_clause {
+            async fn read_options_async<R>
+                (#READER: &mut R, #OPT: &#READ_OPTIONS<'_>, #ARGS: Self::Args)
                 -> #BIN_RESULT<Self>
+            where
+                R: #ASYNC_READ_TRAIT + #ASYNC_SEEK_TRAIT + ::core::marker::Unpin,
             {
                 #read_opt_impl
             }
         }
+    }
+}
 
-        #has_magic_impl
+fn generate_borrowed_binread_impl(
+    derive_input: &syn::DeriveInput,
+    binread_input: &read::Input,
+    arg_type: &TokenStream,
+) -> TokenStream {
+    let lifetime = match derive_input.generics.lifetimes().next() {
+        Some(def) => &def.lifetime,
+        None => {
+            return syn::Error::new(
+                derive_input.ident.span(),
+                "`borrow` requires a lifetime parameter for the borrowed input",
+            )
+            .to_compile_error()
+        }
+    };
 
-        #arg_type_declaration
+    let read_opt_impl = with_io_mode(IoMode::Borrowed, || {
+        read_options::generate(binread_input, derive_input)
+    });
+
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+
+    quote! {
+        #[allow(non_snake_case)]
+        impl #impl_generics #BORROWED_BINREAD_TRAIT<#lifetime> for #name #ty_generics #where_clause {
+            type Args = #arg_type;
+
+            fn read_options_borrowed
+                (#READER: &mut #CURSOR<&#lifetime [u8]>, #OPT: &#READ_OPTIONS<'_>, #ARGS: Self::Args)
+                -> #BIN_RESULT<Self>
+            {
+                #read_opt_impl
+            }
+        }
     }
 }
 
@@ -80,6 +175,13 @@ pub(crate) fn generate_binwrite_impl(
         ParseResult::Partial(_, error) | ParseResult::Err(error) => error.to_compile_error(),
     };
 
+    let async_impl = match binwrite_input {
+        ParseResult::Ok(binwrite_input) if binwrite_input.is_async() => {
+            Some(generate_async_binwrite_impl(derive_input, binwrite_input))
+        }
+        _ => None,
+    };
+
     let name = &derive_input.ident;
     let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
 
@@ -90,13 +192,87 @@ pub(crate) fn generate_binwrite_impl(
             fn write_options<W: #WRITE_TRAIT + #SEEK_TRAIT>(
                 &self,
                 #WRITER: &mut W,
-                #OPT: &#WRITE_OPTIONS,
+                #OPT: &#WRITE_OPTIONS<'_>,
                 #ARGS: Self::Args
             ) -> #BIN_RESULT<()> {
                 #write_opt_impl
             }
         }
 
+        #async_impl
+
         #arg_type_declaration
     }
 }
+
+pub(crate) fn generate_bin_size_impl(
+    derive_input: &syn::DeriveInput,
+    binwrite_input: &ParseResult<write::Input>,
+) -> TokenStream {
+    // Errors are reported by the BinWrite impl, so the fixed size is just left
+    // out when the input can not be parsed
+    let binwrite_input = match binwrite_input {
+        ParseResult::Ok(binwrite_input) | ParseResult::Partial(binwrite_input, _) => {
+            Some(binwrite_input)
+        }
+        ParseResult::Err(_) => None,
+    };
+
+    bin_size::generate(binwrite_input, derive_input)
+}
+
+fn generate_async_binwrite_impl(
+    derive_input: &syn::DeriveInput,
+    binwrite_input: &write::Input,
+) -> TokenStream {
+    if cfg!(not(feature = "async")) {
+        return missing_async_feature();
+    }
+
+    let write_opt_impl = with_io_mode(IoMode::Async, || {
+        write_options::generate(binwrite_input, derive_input)
+    });
+
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #ASYNC_BINWRITE_TRAIT for #name #ty_generics #where_clause {
+            async fn write_options_async<W>(
+                &self,
+                #WRITER: &mut W,
+                #OPT: &#WRITE_OPTIONS<'_>,
+                #ARGS: Self::Args
+            ) -> #BIN_RESULT<()>
+            where
+                W: #ASYNC_WRITE_TRAIT + #ASYNC_SEEK_TRAIT + ::core::marker::Unpin,
+            {
+                #write_opt_impl
+            }
+        }
+    }
+}
+
+/// Returns an expression which checks whether a field with `since` and
+/// `until` directives exists in the current version of the format.
+fn get_version_condition(
+    version: &TokenStream,
+    since: Option<&TokenStream>,
+    until: Option<&TokenStream>,
+) -> TokenStream {
+    let since = since.map_or_else(
+        || quote! { ::core::option::Option::None },
+        |since| quote! { ::core::option::Option::Some(#since) },
+    );
+    let until = until.map_or_else(
+        || quote! { ::core::option::Option::None },
+        |until| quote! { ::core::option::Option::Some(#until) },
+    );
+    quote! { #VERSION_IN_RANGE(#version, #since, #until) }
+}
+
+fn missing_async_feature() -> TokenStream {
+    quote! {
+        compile_error!("the `async` directive requires the `async` feature of binrw to be enabled");
+    }
+}
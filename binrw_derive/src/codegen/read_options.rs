@@ -4,7 +4,9 @@ mod r#struct;
 
 #[allow(clippy::wildcard_imports)]
 use crate::codegen::sanitization::*;
-use crate::parser::{read::Input, Assert, AssertionError, CondEndian, Endian, Magic, Map};
+use crate::parser::{
+    read::Input, Assert, AssertionError, CondEndian, Endian, Magic, MagicEndian, Map,
+};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::Ident;
@@ -25,14 +27,27 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
         Map::Map(map) => map::generate_map(input, name, map),
     };
 
-    quote! {
-        let #POS = #SEEK_TRAIT::stream_position(#READER)?;
-        (|| {
+    let stream_position = stream_position(READER);
+
+    // Streams cannot be rewound, so there is nothing to do on failure
+    if input.is_stream() {
+        return quote! {
+            let #POS = #stream_position?;
             #inner
-        })().or_else(|error| {
-            #SEEK_TRAIT::seek(#READER, #SEEK_FROM::Start(#POS))?;
-            Err(error)
-        })
+        };
+    }
+
+    let body = try_block(inner);
+    let rewind = seek(READER, quote! { #SEEK_FROM::Start(#POS) });
+    quote! {
+        let #POS = #stream_position?;
+        match #body {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                #rewind?;
+                Err(error)
+            }
+        }
     }
 }
 
@@ -65,6 +80,19 @@ impl<'input> PreludeGenerator<'input> {
         self
     }
 
+    fn add_context(mut self) -> Self {
+        if let Some(context) = self.input.context() {
+            let (ident, ty) = (&context.ident, &context.ty);
+            let head = self.out;
+            self.out = quote! {
+                #head
+                let #ident: &#ty = #REQUIRE_CONTEXT(#OPT.context::<#ty>(), #POS)?;
+            };
+        }
+
+        self
+    }
+
     fn add_options(mut self) -> Self {
         let options = ReadOptionsGenerator::new(OPT)
             .endian(self.input.endian())
@@ -97,9 +125,10 @@ impl<'input> PreludeGenerator<'input> {
     fn reset_position_after_magic(mut self) -> Self {
         if self.input.magic().is_some() {
             let head = self.out;
+            let stream_position = stream_position(READER);
             self.out = quote! {
                 #head
-                let #POS = #SEEK_TRAIT::stream_position(#READER)?;
+                let #POS = #stream_position?;
             };
         };
 
@@ -150,8 +179,10 @@ fn get_assertions(assertions: &[Assert]) -> impl Iterator<Item = TokenStream> +
 fn get_magic(magic: &Magic, options_var: &impl ToTokens) -> Option<TokenStream> {
     magic.as_ref().map(|magic| {
         let magic = magic.deref_value();
+        let assert_magic = assert_magic();
+        let dot_await = dot_await();
         quote! {
-            #ASSERT_MAGIC(#READER, #magic, #options_var)?;
+            #assert_magic(#READER, #magic, #options_var)#dot_await?;
         }
     })
 }
@@ -205,6 +236,13 @@ impl ReadOptionsGenerator {
                     }
                 }
             }
+            CondEndian::Magic(MagicEndian { little, big }) => {
+                let magic_endian = magic_endian();
+                let dot_await = dot_await();
+                quote! {
+                    #magic_endian(#READER, #little, #big, #OPT)#dot_await?
+                }
+            }
         };
 
         let head = self.out;
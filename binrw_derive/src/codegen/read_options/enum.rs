@@ -18,6 +18,7 @@ pub(super) fn generate_unit_enum(
 ) -> TokenStream {
     let prelude = PreludeGenerator::new(input)
         .add_imports(name)
+        .add_context()
         .add_options()
         .add_magic_pre_assertion()
         .finish();
@@ -43,8 +44,10 @@ fn generate_unit_enum_repr(repr: &TokenStream, variants: &[UnitEnumField]) -> To
         }
     });
 
+    let read_method = read_method();
+    let dot_await = dot_await();
     quote! {
-        let #TEMP: #repr = #READ_METHOD(#READER, #OPT, ())?;
+        let #TEMP: #repr = #read_method(#READER, #OPT, ())#dot_await?;
         #(#clauses else)* {
             Err(#BIN_ERROR::NoVariantMatch {
                 pos: #POS,
@@ -75,8 +78,10 @@ fn generate_unit_enum_magic(en: &UnitOnlyEnum, variants: &[UnitEnumField]) -> To
         .as_ref()
         .map(|magic| magic.add_ref());
 
+    let read_method = read_method();
+    let dot_await = dot_await();
     quote! {
-        match #amp#READ_METHOD(#READER, #OPT, ())? {
+        match #amp#read_method(#READER, #OPT, ())#dot_await? {
             #(#matches,)*
             _ => Err(#BIN_ERROR::NoVariantMatch { pos: #POS })
         }
@@ -112,6 +117,7 @@ pub(super) fn generate_data_enum(input: &Input, name: Option<&Ident>, en: &Enum)
 
     let prelude = PreludeGenerator::new(input)
         .add_imports(name)
+        .add_context()
         .add_options()
         .add_magic_pre_assertion()
         .reset_position_after_magic()
@@ -129,16 +135,16 @@ pub(super) fn generate_data_enum(input: &Input, name: Option<&Ident>, en: &Enum)
             TokenStream::new()
         };
 
+        let body = try_block(body);
+        let rewind = seek(READER, quote! { #SEEK_FROM::Start(#POS) });
         quote! {
-            let #TEMP = (|| {
-                #body
-            })();
+            let #TEMP = #body;
 
             if #TEMP.is_ok() {
                 return #TEMP;
             } else {
                 #handle_error
-                #SEEK_TRAIT::seek(#READER, #SEEK_FROM::Start(#POS))?;
+                #rewind?;
             }
         }
     });
@@ -155,8 +161,9 @@ fn generate_variant_impl(en: &Enum, variant: &EnumVariant) -> TokenStream {
     // TODO: Kind of expensive since the enum is containing all the fields
     // and this is a clone.
     let mut new_enum = en.with_variant(variant);
-    // Drop imports, we already have them in scope
+    // Drop imports and context, we already have them in scope
     new_enum.imports = Imports::None;
+    new_enum.context = None;
     let input = Input::Enum(new_enum);
 
     match variant {
@@ -10,8 +10,10 @@ use super::{get_assertions, get_map_err, PreludeGenerator};
 
 pub(crate) fn generate_map(input: &Input, name: Option<&Ident>, map: &TokenStream) -> TokenStream {
     let verify_map = verify_map(input, map.span());
+    let map = stream_map(input, map);
     let prelude = PreludeGenerator::new(input)
         .add_imports(name)
+        .add_context()
         .add_options()
         .add_magic_pre_assertion()
         .finish();
@@ -21,13 +23,16 @@ pub(crate) fn generate_map(input: &Input, name: Option<&Ident>, map: &TokenStrea
         .field_asserts()
         .chain(get_assertions(input.assertions()));
 
+    let read_method = read_method();
+    let dot_await = dot_await();
+
     // TODO: replace args with top-level arguments and only
     // use `()` as a default
     quote! {
         #verify_map
         #prelude
 
-        #READ_METHOD(#READER, #OPT, ())
+        #read_method(#READER, #OPT, ())#dot_await
             .map(#map)
                 .and_then(|this| {
                     #destructure_ref
@@ -50,8 +55,10 @@ pub(crate) fn generate_try_map(
 ) -> TokenStream {
     let map_err = get_map_err(POS);
     let verify_map = verify_map(input, map.span());
+    let map = stream_map(input, map);
     let prelude = PreludeGenerator::new(input)
         .add_imports(name)
+        .add_context()
         .add_options()
         .add_magic_pre_assertion()
         .finish();
@@ -61,14 +68,17 @@ pub(crate) fn generate_try_map(
         .field_asserts()
         .chain(get_assertions(input.assertions()));
 
+    let read_method = read_method();
+    let dot_await = dot_await();
+
     // TODO: replace args with top-level arguments and only
     // use `()` as a default
     quote! {
         #verify_map
         #prelude
 
-        #READ_METHOD(#READER, #OPT, #ARGS).and_then(|value| {
-            #map(value)#map_err
+        #read_method(#READER, #OPT, #ARGS)#dot_await.and_then(|value| {
+            (#map)(value)#map_err
         })
         .and_then(|this| {
             #destructure_ref
@@ -100,6 +110,16 @@ fn verify_map(input: &Input, span: Span) -> Option<TokenStream> {
     }
 }
 
+/// Requires the type read before mapping to implement `StreamRead` when
+/// reading from a stream.
+fn stream_map(input: &Input, map: &TokenStream) -> TokenStream {
+    if input.is_stream() {
+        quote! { #STREAM_MAP_FN_TYPE_HINT(#map) }
+    } else {
+        map.clone()
+    }
+}
+
 fn destructure_ref(input: &Input) -> Option<TokenStream> {
     match input {
         Input::Struct(input) => {
@@ -1,8 +1,9 @@
 use super::{get_assertions, get_magic, PreludeGenerator, ReadOptionsGenerator};
+use crate::codegen::get_version_condition;
 #[allow(clippy::wildcard_imports)]
 use crate::codegen::sanitization::*;
 use crate::parser::read::{Input, Struct, StructField};
-use crate::parser::{ErrContext, Map, PassedArgs, ReadMode, TempableField};
+use crate::parser::{Checksum, ErrContext, Map, PassedArgs, ReadMode, TempableField};
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
@@ -67,18 +68,35 @@ impl<'input> StructGenerator<'input> {
 
     pub(super) fn read_fields(mut self, name: Option<&Ident>, variant_name: Option<&str>) -> Self {
         let prelude = get_prelude(self.input, name);
-        let read_fields = self
-            .st
-            .fields
-            .iter()
-            .map(|field| generate_field(field, name, variant_name));
+        let bit_state = get_bit_state(self.st);
+        let read_fields = self.st.fields.iter().enumerate().map(|(index, field)| {
+            let read_field = generate_field(
+                field,
+                name,
+                variant_name,
+                self.st.version.as_ref(),
+                self.input.is_stream(),
+            );
+            let read_field = if ends_bit_run(self.st, index) {
+                quote! {
+                    #read_field
+                    #BIT_STATE.clear();
+                }
+            } else {
+                read_field
+            };
+            wrap_checksum_positions(self.st, field, &read_field)
+        });
+        let verify_checksums = self.st.fields.iter().filter_map(generate_verify_checksum);
         let after_parse = {
             let after_parse = self.st.fields.iter().map(generate_after_parse);
             wrap_save_restore(quote!(#(#after_parse)*))
         };
         self.out = quote! {
             #prelude
+            #bit_state
             #(#read_fields)*
+            #(#verify_checksums)*
             #after_parse
         };
 
@@ -131,10 +149,68 @@ fn generate_after_parse(field: &StructField) -> Option<TokenStream> {
     })
 }
 
+/// Records the positions before and after `field` if they are needed to
+/// verify a checksum.
+fn wrap_checksum_positions(
+    st: &Struct,
+    field: &StructField,
+    read_field: &TokenStream,
+) -> TokenStream {
+    let is_checksum_edge = |edge: fn(&Checksum) -> &Ident| {
+        field.checksum.is_some()
+            || st
+                .fields
+                .iter()
+                .filter_map(|field| field.checksum.as_ref())
+                .any(|checksum| *edge(checksum) == field.ident)
+    };
+
+    let stream_position = stream_position(READER);
+    let start = is_checksum_edge(|checksum| &checksum.from).then(|| {
+        let start = make_ident(&field.ident, "checksum_start");
+        quote! { let #start = #stream_position?; }
+    });
+    let end = is_checksum_edge(|checksum| &checksum.to).then(|| {
+        let end = make_ident(&field.ident, "checksum_end");
+        quote! { let #end = #stream_position?; }
+    });
+
+    quote! {
+        #start
+        #read_field
+        #end
+    }
+}
+
+fn generate_verify_checksum(field: &StructField) -> Option<TokenStream> {
+    let checksum = field.checksum.as_ref()?;
+    let algorithm = &checksum.algorithm;
+    if io_mode() == IoMode::Async {
+        return Some(quote_spanned! {algorithm.span()=>
+            compile_error!("`checksum` cannot be used with `async` because it calls a blocking function");
+        });
+    }
+
+    let ident = &field.ident;
+    let pos = make_ident(ident, "checksum_start");
+    let end = make_ident(ident, "checksum_end");
+    let from = make_ident(&checksum.from, "checksum_start");
+    let to = make_ident(&checksum.to, "checksum_end");
+
+    // Checksum fields which were not read have nothing to verify
+    Some(quote! {
+        if #pos != #end {
+            #VERIFY_CHECKSUM::<#algorithm, _>(#READER, #from..#to, #pos, &#ident)?;
+        }
+    })
+}
+
 fn generate_field(
     field: &StructField,
     name: Option<&Ident>,
     variant_name: Option<&str>,
+    version: Option<&TokenStream>,
+    is_stream: bool,
 ) -> TokenStream {
     // temp + ignore == just don't bother
     if field.is_temp() && matches!(field.read_mode, ReadMode::Default) {
@@ -145,13 +221,16 @@ fn generate_field(
     //let back = BacktraceFrame::from_field(field);
     //eprintln!("{}", back);
 
-    FieldGenerator::new(field)
+    FieldGenerator::new(field, is_stream)
         .read_value()
+        .wrap_size()
         .try_conversion(name, variant_name)
         .map_value()
         .deref_now()
+        .wrap_transform()
         .wrap_seek()
         .wrap_condition()
+        .wrap_version(version)
         .assign_to_var()
         .append_assertions()
         .wrap_restore_position()
@@ -186,8 +265,9 @@ impl<'field> AfterParseCallGenerator<'field> {
             "called `AfterParseCallGenerator::call_after_parse` but no `options_var` was generated",
         );
         let args_arg = get_args_argument(args_var);
+        let dot_await = dot_await();
         self.out = quote! {
-            #after_parse_fn(#value, #READER, #options_var, #args_arg)?;
+            #after_parse_fn(#value, #READER, #options_var, #args_arg)#dot_await?;
         };
 
         self
@@ -233,10 +313,11 @@ struct FieldGenerator<'field> {
     out: TokenStream,
     args_var: Option<Ident>,
     options_var: Option<Ident>,
+    is_stream: bool,
 }
 
 impl<'field> FieldGenerator<'field> {
-    fn new(field: &'field StructField) -> Self {
+    fn new(field: &'field StructField, is_stream: bool) -> Self {
         let (args_var, options_var) = make_field_vars(field);
 
         Self {
@@ -244,6 +325,7 @@ impl<'field> FieldGenerator<'field> {
             out: TokenStream::new(),
             args_var,
             options_var,
+            is_stream,
         }
     }
 
@@ -306,8 +388,9 @@ impl<'field> FieldGenerator<'field> {
                 // TODO: Position should always just be saved once for a field if used
                 let value = self.out;
                 let map_err = super::get_map_err(SAVED_POSITION);
+                let stream_position = stream_position(READER);
                 quote! {{
-                    let #SAVED_POSITION = #SEEK_TRAIT::stream_position(#READER)?;
+                    let #SAVED_POSITION = #stream_position?;
 
                     #map_func(#value)#map_err?
                 }}
@@ -321,14 +404,26 @@ impl<'field> FieldGenerator<'field> {
         let map_func = make_ident(&self.field.ident, "map_func");
         let ty = &self.field.ty;
 
+        // The type read before mapping is only known once the map function
+        // is, so stream readers check it here instead of in the where clause
+        let stream_map = |map: &TokenStream| {
+            if self.is_stream {
+                quote! { #STREAM_MAP_FN_TYPE_HINT(#map) }
+            } else {
+                map.clone()
+            }
+        };
+
         let set_map_function = match &self.field.map {
             Map::None => return self,
             Map::Map(map) => {
+                let map = stream_map(map);
                 quote! {
                     let #map_func = (#COERCE_FN::<#ty, _, _>(#map));
                 }
             }
             Map::Try(try_map) => {
+                let try_map = stream_map(try_map);
                 // TODO: Position should always just be saved once for a field if used
                 quote! {
                     let #map_func = (#COERCE_FN::<::core::result::Result<#ty, _>, _, _>(#try_map));
@@ -347,8 +442,13 @@ impl<'field> FieldGenerator<'field> {
 
     fn prefix_read_function(mut self) -> Self {
         let read_function = match &self.field.read_mode {
+            ReadMode::ParseWith(parser) if io_mode() == IoMode::Async => {
+                Some(quote_spanned! {parser.span()=>
+                    compile_error!("`parse_with` cannot be used with `async` because it calls a blocking function")
+                })
+            }
             ReadMode::ParseWith(parser) => Some(parser.clone()),
-            ReadMode::Normal => Some(READ_METHOD.to_token_stream()),
+            ReadMode::Normal => Some(read_method().to_token_stream()),
             _ => None,
         };
 
@@ -368,19 +468,30 @@ impl<'field> FieldGenerator<'field> {
             let ty = &self.field.ty;
 
             if let ReadMode::ParseWith(_) = &self.field.read_mode {
+                // Transformed and sized fields are parsed from a wrapper
+                // instead
+                let reader_type = if self.field.transform.is_some() {
+                    TRANSFORM_STREAM_TYPE.to_token_stream()
+                } else if self.field.size.is_some() {
+                    quote! { #BOUNDED_READER<&mut R> }
+                } else {
+                    reader_type()
+                };
                 quote! {
-                    let #args_var = #ARGS_TYPE_HINT::<R, #ty, _, _>(#READ_FUNCTION, #args);
+                    let #args_var = #ARGS_TYPE_HINT::<#reader_type, #ty, _, _>(#READ_FUNCTION, #args);
                 }
             } else {
                 match &self.field.map {
                     Map::Map(_) | Map::Try(_) => {
+                        let map_args_type_hint = map_args_type_hint();
                         quote! {
-                            let #args_var = #MAP_ARGS_TYPE_HINT(&#map_func, #args);
+                            let #args_var = #map_args_type_hint(&#map_func, #args);
                         }
                     }
                     Map::None => {
+                        let binread_trait = binread_trait();
                         quote! {
-                            let #args_var: <#ty as #BINREAD_TRAIT>::Args = #args;
+                            let #args_var: <#ty as #binread_trait>::Args = #args;
                         }
                     }
                 }
@@ -423,12 +534,21 @@ impl<'field> FieldGenerator<'field> {
         self.out = match &self.field.read_mode {
             ReadMode::Default => quote! { <_>::default() },
             ReadMode::Calc(calc) => quote! { #calc },
+            ReadMode::Bits(bits) => {
+                let read_bits = read_bits();
+                let dot_await = dot_await();
+
+                quote! {
+                    #read_bits(#READER, &mut #BIT_STATE, (#bits) as u32)#dot_await
+                }
+            }
             ReadMode::Normal | ReadMode::ParseWith(_) => {
                 let args_arg = get_args_argument(&self.args_var);
                 let options_var = &self.options_var;
+                let dot_await = dot_await();
 
                 quote! {
-                    #READ_FUNCTION(#READER, #options_var, #args_arg)
+                    #READ_FUNCTION(#READER, #options_var, #args_arg)#dot_await
                 }
             }
         };
@@ -527,6 +647,26 @@ impl<'field> FieldGenerator<'field> {
         self
     }
 
+    fn wrap_version(mut self, version: Option<&TokenStream>) -> Self {
+        if let (Some(version), true) = (version, self.field.is_versioned()) {
+            let condition = get_version_condition(
+                version,
+                self.field.since.as_ref(),
+                self.field.until.as_ref(),
+            );
+            let consequent = self.out;
+            self.out = quote! {
+                if #condition {
+                    #consequent
+                } else {
+                    <_>::default()
+                }
+            };
+        }
+
+        self
+    }
+
     fn wrap_restore_position(mut self) -> Self {
         if self.field.restore_position.is_some() {
             self.out = wrap_save_restore(self.out);
@@ -535,6 +675,58 @@ impl<'field> FieldGenerator<'field> {
         self
     }
 
+    fn wrap_transform(mut self) -> Self {
+        if let Some(transform) = &self.field.transform {
+            let value = self.out;
+            let stream = make_ident(&self.field.ident, "transform_stream");
+            let size = transform.size.as_ref().map_or_else(
+                || quote! { ::core::option::Option::None },
+                |size| quote! { ::core::option::Option::Some((#size) as u64) },
+            );
+            let transform = &transform.transform;
+            self.out = match io_mode() {
+                IoMode::Async => quote_spanned! {transform.span()=>
+                    compile_error!("`transform` cannot be used with `async` because it calls a blocking function")
+                },
+                IoMode::Borrowed => quote_spanned! {transform.span()=>
+                    compile_error!("`transform` cannot be used with `borrow` because decoded data cannot be borrowed")
+                },
+                IoMode::Blocking => quote! {{
+                    let mut #stream = #DECODE_TRANSFORM(#READER, &(#transform), #size)?;
+                    let #READER = &mut #stream;
+                    #value
+                }},
+            };
+        }
+
+        self
+    }
+
+    fn wrap_size(mut self) -> Self {
+        if let (Some(size), false) = (&self.field.size, self.field.generated_value()) {
+            let value = self.out;
+            let bounded = make_ident(&self.field.ident, "bounded_reader");
+            self.out = match io_mode() {
+                IoMode::Async => quote_spanned! {size.span()=>
+                    compile_error!("`size` cannot be used with `async` because it calls a blocking function")
+                },
+                IoMode::Borrowed => quote_spanned! {size.span()=>
+                    compile_error!("`size` cannot be used with `borrow` because the bounded reader cannot be borrowed from")
+                },
+                IoMode::Blocking => quote! {{
+                    let mut #bounded = #BOUNDED_READER::new(&mut *#READER, (#size) as u64)?;
+                    let #TEMP = {
+                        let #READER = &mut #bounded;
+                        #value
+                    };
+                    #FINISH_BOUNDED(#bounded, #TEMP)
+                }},
+            };
+        }
+
+        self
+    }
+
     fn wrap_seek(mut self) -> Self {
         let seek_before = generate_seek_before(self.field);
         let seek_after = generate_seek_after(self.field);
@@ -561,10 +753,17 @@ fn get_args_argument(args_var: &Option<Ident>) -> TokenStream {
 
 fn get_passed_args(field: &StructField) -> Option<TokenStream> {
     let args = &field.args;
+    let count = field.count.as_ref().map(|count| {
+        if field.count_is_varint {
+            quote! { #INTO_COUNT(#count) }
+        } else {
+            quote! { ((#count) as usize) }
+        }
+    });
     match args {
-        PassedArgs::Named(fields) => Some(if let Some(count) = &field.count {
+        PassedArgs::Named(fields) => Some(if let Some(count) = count {
             quote! {
-                #ARGS_MACRO! { count: ((#count) as usize) #(, #fields)* }
+                #ARGS_MACRO! { count: #count #(, #fields)* }
             }
         } else {
             quote! {
@@ -573,16 +772,32 @@ fn get_passed_args(field: &StructField) -> Option<TokenStream> {
         }),
         PassedArgs::List(list) => Some(quote! { (#(#list,)*) }),
         PassedArgs::Tuple(tuple) => Some(tuple.clone()),
-        PassedArgs::None => field
-            .count
-            .as_ref()
-            .map(|count| quote! { #ARGS_MACRO! { count: ((#count) as usize) }}),
+        PassedArgs::None => count.map(|count| quote! { #ARGS_MACRO! { count: #count }}),
     }
 }
 
+fn get_bit_state(st: &Struct) -> Option<TokenStream> {
+    st.fields.iter().any(StructField::is_bits).then(|| {
+        let bit_order = st
+            .bit_order
+            .clone()
+            .unwrap_or_else(|| quote! { #BIT_ORDER::MsbFirst });
+        quote! {
+            let mut #BIT_STATE = #BIT_STATE_TYPE::new(#bit_order);
+        }
+    })
+}
+
+/// Returns true if the field at `index` is the last field of a run of `bits`
+/// fields, so the reader must be realigned to a byte boundary after it.
+fn ends_bit_run(st: &Struct, index: usize) -> bool {
+    st.fields[index].is_bits() && !st.fields.get(index + 1).is_some_and(StructField::is_bits)
+}
+
 fn get_prelude(input: &Input, name: Option<&Ident>) -> TokenStream {
     PreludeGenerator::new(input)
         .add_imports(name)
+        .add_context()
         .add_options()
         .add_magic_pre_assertion()
         .finish()
@@ -590,11 +805,13 @@ fn get_prelude(input: &Input, name: Option<&Ident>) -> TokenStream {
 
 fn generate_seek_after(field: &StructField) -> TokenStream {
     let pad_size_to = field.pad_size_to.as_ref().map(|pad| {
+        let stream_position = stream_position(READER);
+        let seek = seek(READER, quote! { #SEEK_FROM::Current(pad - size) });
         quote! {{
             let pad = (#pad) as i64;
-            let size = (#SEEK_TRAIT::stream_position(#READER)? - #POS) as i64;
+            let size = (#stream_position? - #POS) as i64;
             if size < pad {
-                #SEEK_TRAIT::seek(#READER, #SEEK_FROM::Current(pad - size))?;
+                #seek?;
             }
         }}
     });
@@ -609,16 +826,18 @@ fn generate_seek_after(field: &StructField) -> TokenStream {
 }
 
 fn generate_seek_before(field: &StructField) -> TokenStream {
-    let seek_before = field.seek_before.as_ref().map(|seek| {
+    let seek_before = field.seek_before.as_ref().map(|pos| {
+        let seek = seek(READER, pos);
         quote! {
-            #SEEK_TRAIT::seek(#READER, #seek)?;
+            #seek?;
         }
     });
     let pad_before = field.pad_before.as_ref().map(map_pad);
     let align_before = field.align_before.as_ref().map(map_align);
     let pad_size_to_before = field.pad_size_to.as_ref().map(|_| {
+        let stream_position = stream_position(READER);
         quote! {
-            let #POS = #SEEK_TRAIT::stream_position(#READER)?;
+            let #POS = #stream_position?;
         }
     });
 
@@ -631,7 +850,7 @@ fn generate_seek_before(field: &StructField) -> TokenStream {
 }
 
 fn get_after_parse_handler(field: &StructField) -> Option<IdentStr> {
-    field.can_call_after_parse().then(|| AFTER_PARSE)
+    field.can_call_after_parse().then(after_parse_method)
 }
 
 fn get_return_type(variant_ident: Option<&Ident>) -> TokenStream {
@@ -655,16 +874,22 @@ fn make_field_vars(field: &StructField) -> (Option<Ident>, Option<Ident>) {
 }
 
 fn map_align(align: &TokenStream) -> TokenStream {
+    let stream_position = stream_position(READER);
+    let seek = seek(
+        READER,
+        quote! { #SEEK_FROM::Current((align - (pos % align)) % align) },
+    );
     quote! {{
         let align = (#align) as i64;
-        let pos = #SEEK_TRAIT::stream_position(#READER)? as i64;
-        #SEEK_TRAIT::seek(#READER, #SEEK_FROM::Current((align - (pos % align)) % align))?;
+        let pos = #stream_position? as i64;
+        #seek?;
     }}
 }
 
 fn map_pad(pad: &TokenStream) -> TokenStream {
+    let seek = seek(READER, quote! { #SEEK_FROM::Current((#pad) as i64) });
     quote! {
-        #SEEK_TRAIT::seek(#READER, #SEEK_FROM::Current((#pad) as i64))?;
+        #seek?;
     }
 }
 
@@ -672,10 +897,12 @@ fn wrap_save_restore(value: TokenStream) -> TokenStream {
     if value.is_empty() {
         value
     } else {
+        let stream_position = stream_position(READER);
+        let restore = seek(READER, quote! { #SEEK_FROM::Start(#SAVED_POSITION) });
         quote! {
-            let #SAVED_POSITION = #SEEK_TRAIT::stream_position(#READER)?;
+            let #SAVED_POSITION = #stream_position?;
             #value
-            #SEEK_TRAIT::seek(#READER, #SEEK_FROM::Start(#SAVED_POSITION))?;
+            #restore?;
         }
     }
 }
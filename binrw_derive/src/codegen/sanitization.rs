@@ -1,6 +1,7 @@
 ///! Utilities for helping sanitize macro
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use std::cell::Cell;
 
 macro_rules! ident_str {
     () => {};
@@ -41,6 +42,7 @@ macro_rules! from_write_trait {
 
 ident_str! {
     pub(crate) BINREAD_TRAIT = from_read_trait!();
+    pub(crate) STREAM_READ_TRAIT = from_crate!(StreamRead);
     pub(crate) BINWRITE_TRAIT = from_write_trait!();
     pub(crate) BIN_ERROR = from_crate!(Error);
     pub(crate) READ_OPTIONS = from_crate!(ReadOptions);
@@ -63,6 +65,7 @@ ident_str! {
     pub(crate) ASSERT = from_crate!(__private::assert);
     pub(crate) ASSERT_ERROR_FN = from_crate!(__private::AssertErrorFn);
     pub(crate) COERCE_FN = from_crate!(__private::coerce_fn);
+    pub(crate) STREAM_MAP_FN_TYPE_HINT = from_crate!(__private::stream_map_fn_type_hint);
     pub(crate) ARGS_TYPE_HINT = from_crate!(__private::parse_function_args_type_hint);
     pub(crate) MAP_ARGS_TYPE_HINT = from_crate!(__private::map_args_type_hint);
     pub(crate) WRITE_FN_TYPE_HINT = from_crate!(__private::write_fn_type_hint);
@@ -88,6 +91,199 @@ ident_str! {
     pub(crate) READ_FUNCTION = "__binrw_generated_read_function";
     pub(crate) WRITE_FUNCTION = "__binrw_generated_write_function";
     pub(crate) BEFORE_POS = "__binrw_generated_before_pos";
+    pub(crate) BYTE_RANGE_START = "__binrw_generated_byte_range_start";
+    pub(crate) BACKPATCH_END = "__binrw_generated_backpatch_end";
+    pub(crate) ASYNC_BINREAD_TRAIT = from_crate!(AsyncBinRead);
+    pub(crate) ASYNC_BINWRITE_TRAIT = from_crate!(AsyncBinWrite);
+    pub(crate) ASYNC_READ_TRAIT = from_crate!(asynchronous::AsyncRead);
+    pub(crate) ASYNC_WRITE_TRAIT = from_crate!(asynchronous::AsyncWrite);
+    pub(crate) ASYNC_SEEK_TRAIT = from_crate!(asynchronous::AsyncSeek);
+    pub(crate) ASYNC_READ_METHOD = from_crate!(__private::read_async);
+    pub(crate) ASYNC_AFTER_PARSE = from_crate!(AsyncBinRead::after_parse_async);
+    pub(crate) ASYNC_WRITE_METHOD = from_crate!(AsyncBinWrite::write_options_async);
+    pub(crate) ASYNC_SEEK = from_crate!(__private::async_seek);
+    pub(crate) ASYNC_STREAM_POSITION = from_crate!(__private::async_stream_position);
+    pub(crate) ASYNC_ASSERT_MAGIC = from_crate!(__private::magic_async);
+    pub(crate) MAGIC_ENDIAN = from_crate!(__private::magic_endian);
+    pub(crate) ASYNC_MAGIC_ENDIAN = from_crate!(__private::magic_endian_async);
+    pub(crate) MAGIC_FOR_ENDIAN = from_crate!(__private::magic_for_endian);
+    pub(crate) ASYNC_WRITE_ZEROES = from_crate!(__private::write_zeroes_async);
+    pub(crate) BORROWED_BINREAD_TRAIT = from_crate!(BorrowedBinRead);
+    pub(crate) BORROWED_READ_METHOD = from_crate!(BorrowedBinRead::read_options_borrowed);
+    pub(crate) BORROWED_AFTER_PARSE = from_crate!(BorrowedBinRead::after_parse_borrowed);
+    pub(crate) BORROWED_MAP_ARGS_TYPE_HINT = from_crate!(__private::borrowed_map_args_type_hint);
+    pub(crate) CURSOR = from_crate!(io::Cursor);
+    pub(crate) BIT_STATE = "__binrw_generated_bit_state";
+    pub(crate) BIT_STATE_TYPE = from_crate!(__private::BitState);
+    pub(crate) BIT_ORDER = from_crate!(io::BitOrder);
+    pub(crate) READ_BITS = from_crate!(__private::read_bits);
+    pub(crate) WRITE_BITS = from_crate!(__private::write_bits);
+    pub(crate) FLUSH_BITS = from_crate!(__private::flush_bits);
+    pub(crate) ASYNC_READ_BITS = from_crate!(__private::read_bits_async);
+    pub(crate) ASYNC_WRITE_BITS = from_crate!(__private::write_bits_async);
+    pub(crate) ASYNC_FLUSH_BITS = from_crate!(__private::flush_bits_async);
+    pub(crate) BIN_SIZE_TRAIT = from_crate!(BinSize);
+    pub(crate) REQUIRE_CONTEXT = from_crate!(__private::require_context);
+    pub(crate) VERSION_IN_RANGE = from_crate!(__private::version_in_range);
+    pub(crate) CHECK_BACKPATCH = from_crate!(__private::check_backpatch);
+    pub(crate) VERIFY_CHECKSUM = from_crate!(__private::verify_checksum);
+    pub(crate) CHECKSUM_WRITER = from_crate!(__private::ChecksumWriter);
+    pub(crate) CHECKSUM_WRITER_VAR = "__binrw_generated_checksum_writer";
+    pub(crate) DECODE_TRANSFORM = from_crate!(__private::decode_transform);
+    pub(crate) ENCODE_TRANSFORM = from_crate!(__private::encode_transform);
+    pub(crate) TRANSFORM_STREAM = from_crate!(__private::transform_stream);
+    pub(crate) TRANSFORM_STREAM_TYPE = from_crate!(__private::TransformStream);
+    pub(crate) BOUNDED_READER = from_crate!(io::BoundedReader);
+    pub(crate) FINISH_BOUNDED = from_crate!(__private::finish_bounded);
+    pub(crate) INTO_COUNT = from_crate!(__private::into_count);
+    pub(crate) SIZE_PADDING = from_crate!(__private::size_padding);
+    pub(crate) WRITE_WITH_DATA_SECTION = from_crate!(file_ptr::DataSection::write_after);
+    pub(crate) DATA_SECTION_AFTER = from_crate!(__private::data_section_after);
+    pub(crate) DATA_SECTION = "__binrw_generated_data_section";
+    pub(crate) SUM_FIXED_SIZES = from_crate!(__private::sum_fixed_sizes);
+    pub(crate) SAME_FIXED_SIZE = from_crate!(__private::same_fixed_size);
+}
+
+/// The kind of I/O performed by generated code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum IoMode {
+    /// Generate a `BinRead` or `BinWrite` implementation.
+    Blocking,
+    /// Generate an `AsyncBinRead` or `AsyncBinWrite` implementation.
+    Async,
+    /// Generate a `BorrowedBinRead` implementation, which reads from a
+    /// `Cursor` over a byte slice using blocking I/O.
+    Borrowed,
+}
+
+thread_local! {
+    static IO_MODE: Cell<IoMode> = const { Cell::new(IoMode::Blocking) };
+}
+
+/// Runs `f` with every I/O helper in this module generating code for `mode`.
+///
+/// The same generators are used to build both the blocking and async
+/// implementations, so rather than threading the mode through every one of
+/// them, it is stored for the duration of the call.
+pub(crate) fn with_io_mode<T>(mode: IoMode, f: impl FnOnce() -> T) -> T {
+    let previous = IO_MODE.with(|current| current.replace(mode));
+    let out = f();
+    IO_MODE.with(|current| current.set(previous));
+    out
+}
+
+pub(crate) fn io_mode() -> IoMode {
+    IO_MODE.with(Cell::get)
+}
+
+fn by_io_mode(blocking: IdentStr, non_blocking: IdentStr) -> IdentStr {
+    match io_mode() {
+        IoMode::Blocking | IoMode::Borrowed => blocking,
+        IoMode::Async => non_blocking,
+    }
+}
+
+pub(crate) fn read_method() -> IdentStr {
+    if io_mode() == IoMode::Borrowed {
+        BORROWED_READ_METHOD
+    } else {
+        by_io_mode(READ_METHOD, ASYNC_READ_METHOD)
+    }
+}
+
+pub(crate) fn after_parse_method() -> IdentStr {
+    if io_mode() == IoMode::Borrowed {
+        BORROWED_AFTER_PARSE
+    } else {
+        by_io_mode(AFTER_PARSE, ASYNC_AFTER_PARSE)
+    }
+}
+
+pub(crate) fn map_args_type_hint() -> IdentStr {
+    if io_mode() == IoMode::Borrowed {
+        BORROWED_MAP_ARGS_TYPE_HINT
+    } else {
+        MAP_ARGS_TYPE_HINT
+    }
+}
+
+/// Generates the type of the reader passed to field read functions.
+pub(crate) fn reader_type() -> TokenStream {
+    if io_mode() == IoMode::Borrowed {
+        quote! { #CURSOR<&'_ [u8]> }
+    } else {
+        quote! { R }
+    }
+}
+
+/// Generates the path of the trait whose `Args` are passed to a field.
+pub(crate) fn binread_trait() -> TokenStream {
+    if io_mode() == IoMode::Borrowed {
+        quote! { #BORROWED_BINREAD_TRAIT<'_> }
+    } else {
+        BINREAD_TRAIT.to_token_stream()
+    }
+}
+
+pub(crate) fn write_method() -> IdentStr {
+    by_io_mode(WRITE_METHOD, ASYNC_WRITE_METHOD)
+}
+
+pub(crate) fn assert_magic() -> IdentStr {
+    by_io_mode(ASSERT_MAGIC, ASYNC_ASSERT_MAGIC)
+}
+
+pub(crate) fn magic_endian() -> IdentStr {
+    by_io_mode(MAGIC_ENDIAN, ASYNC_MAGIC_ENDIAN)
+}
+
+pub(crate) fn write_zeroes() -> IdentStr {
+    by_io_mode(WRITE_ZEROES, ASYNC_WRITE_ZEROES)
+}
+
+pub(crate) fn read_bits() -> IdentStr {
+    by_io_mode(READ_BITS, ASYNC_READ_BITS)
+}
+
+pub(crate) fn write_bits() -> IdentStr {
+    by_io_mode(WRITE_BITS, ASYNC_WRITE_BITS)
+}
+
+pub(crate) fn flush_bits() -> IdentStr {
+    by_io_mode(FLUSH_BITS, ASYNC_FLUSH_BITS)
+}
+
+/// Suffix for a call to one of the I/O functions in this module, which awaits
+/// the call when generating async code.
+pub(crate) fn dot_await() -> Option<TokenStream> {
+    (io_mode() == IoMode::Async).then(|| quote! { .await })
+}
+
+/// Generates a call to seek `stream` to `pos`. The result must still be
+/// checked with `?`.
+pub(crate) fn seek(stream: IdentStr, pos: impl ToTokens) -> TokenStream {
+    match io_mode() {
+        IoMode::Blocking | IoMode::Borrowed => quote! { #SEEK_TRAIT::seek(#stream, #pos) },
+        IoMode::Async => quote! { #ASYNC_SEEK(#stream, #pos).await },
+    }
+}
+
+/// Generates a call to get the position of `stream`. The result must still be
+/// checked with `?`.
+pub(crate) fn stream_position(stream: IdentStr) -> TokenStream {
+    match io_mode() {
+        IoMode::Blocking | IoMode::Borrowed => quote! { #SEEK_TRAIT::stream_position(#stream) },
+        IoMode::Async => quote! { #ASYNC_STREAM_POSITION(#stream).await },
+    }
+}
+
+/// Generates an expression which evaluates `body` in its own scope so that
+/// `?` inside of it does not return from the enclosing function.
+pub(crate) fn try_block(body: impl ToTokens) -> TokenStream {
+    match io_mode() {
+        IoMode::Blocking | IoMode::Borrowed => quote! { (|| { #body })() },
+        IoMode::Async => quote! { (async { #body }).await },
+    }
 }
 
 pub(crate) fn make_ident(ident: &Ident, kind: &str) -> Ident {
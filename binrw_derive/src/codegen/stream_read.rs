@@ -0,0 +1,42 @@
+use super::sanitization::STREAM_READ_TRAIT;
+use crate::parser::{read::Input, Map, ReadMode};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote_spanned, spanned::Spanned};
+
+pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> Option<TokenStream> {
+    if !input.is_stream() {
+        return None;
+    }
+
+    // Every type read by the generated parser also has to be safe to read
+    // from a stream, which is checked by requiring it in the where clause
+    let mut generics = derive_input.generics.clone();
+    let predicates = &mut generics.make_where_clause().predicates;
+    match input {
+        // A top-level map reads some other type, which is checked where the
+        // map function is called
+        _ if !matches!(input.map(), Map::None) => {}
+        Input::Struct(st) => {
+            for field in &st.fields {
+                if matches!(field.read_mode, ReadMode::Normal) && matches!(field.map, Map::None) {
+                    let ty = &field.ty;
+                    predicates.push(parse_quote_spanned! {ty.span()=> #ty: #STREAM_READ_TRAIT });
+                }
+            }
+        }
+        Input::UnitOnlyEnum(en) => {
+            if let Some(repr) = &en.repr {
+                let repr = repr.as_ref();
+                predicates.push(parse_quote_spanned! {repr.span()=> #repr: #STREAM_READ_TRAIT });
+            }
+        }
+        Input::UnitStruct(_) | Input::Enum(_) => {}
+    }
+
+    let name = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Some(quote! {
+        impl #impl_generics #STREAM_READ_TRAIT for #name #ty_generics #where_clause {}
+    })
+}
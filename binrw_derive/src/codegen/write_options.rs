@@ -25,13 +25,15 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
         },
         Map::Try(map) | Map::Map(map) => {
             let try_op = matches!(input.map(), Map::Try(_)).then(|| quote! { ? });
+            let write_method = write_method();
+            let dot_await = dot_await();
             let write_data = quote! {
-                #WRITE_METHOD(
+                #write_method(
                     &((#map)(self) #try_op),
                     #WRITER,
                     #OPT,
                     ()
-                )?;
+                )#dot_await?;
             };
 
             let magic = input.magic();
@@ -39,6 +41,7 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
             prelude::PreludeGenerator::new(write_data, Some(input), name)
                 .prefix_magic(magic)
                 .prefix_endian(endian)
+                .prefix_context()
                 .prefix_imports()
                 .finish()
         }
@@ -54,9 +57,50 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
     //    })
     //}
 
+    // Types with a data section start a new one if they are not already
+    // being written into another one
+    let stream_position = stream_position(WRITER);
+    let (data_section, data_section_end) = if !input.has_data_section() {
+        (None, None)
+    } else if io_mode() == IoMode::Async {
+        // A derived async writer cannot call itself through a generic helper
+        // without boxing its own future, so the data section is set up inline
+        let seek_end = seek(WRITER, quote! { #SEEK_FROM::Start(#DATA_SECTION.end()) });
+        (
+            Some(quote! {
+                let #DATA_SECTION = if #OPT.data_section().is_none() {
+                    let #POS = #stream_position?;
+                    Some(#DATA_SECTION_AFTER(self, #POS, #OPT, #ARGS.clone())?)
+                } else {
+                    None
+                };
+                let #OPT = &match &#DATA_SECTION {
+                    Some(#DATA_SECTION) => #OPT.with_data_section(#DATA_SECTION),
+                    None => *#OPT,
+                };
+            }),
+            Some(quote! {
+                if let Some(#DATA_SECTION) = &#DATA_SECTION {
+                    #seek_end?;
+                }
+            }),
+        )
+    } else {
+        (
+            Some(quote! {
+                if #OPT.data_section().is_none() {
+                    return #WRITE_WITH_DATA_SECTION(self, #WRITER, #OPT, #ARGS);
+                }
+            }),
+            None,
+        )
+    };
+
     quote! {
-        let #POS = #SEEK_TRAIT::stream_position(#WRITER)?;
+        #data_section
+        let #POS = #stream_position?;
         #inner
+        #data_section_end
 
         Ok(())
     }
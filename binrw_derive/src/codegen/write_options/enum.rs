@@ -20,6 +20,7 @@ pub(crate) fn generate_unit_enum(
     PreludeGenerator::new(write, Some(input), name)
         .prefix_magic(&en.magic)
         .prefix_endian(&en.endian)
+        .prefix_context()
         .prefix_imports()
         .finish()
 }
@@ -58,7 +59,11 @@ impl<'a> EnumGenerator<'a> {
 
             let writing = match variant {
                 EnumVariant::Variant { options, .. } => StructGenerator::new(None, options, None)
-                    .write_fields()
+                    .write_fields(Some(&format!(
+                        "{}::{}",
+                        self.name.map_or_else(String::new, ToString::to_string),
+                        name
+                    )))
                     .prefix_prelude()
                     .finish(),
                 EnumVariant::Unit(variant) => variant
@@ -66,13 +71,15 @@ impl<'a> EnumGenerator<'a> {
                     .as_ref()
                     .map(|magic| {
                         let magic = magic.match_value();
+                        let write_method = write_method();
+                        let dot_await = dot_await();
                         quote! {
-                            #WRITE_METHOD (
+                            #write_method (
                                 &#magic,
                                 #WRITER,
                                 &#OPT,
                                 ()
-                            )?;
+                            )#dot_await?;
                         }
                     })
                     .unwrap_or_default(),
@@ -100,6 +107,7 @@ impl<'a> EnumGenerator<'a> {
         self.out = PreludeGenerator::new(out, Some(self.input), self.name)
             .prefix_magic(&self.en.magic)
             .prefix_endian(&self.en.endian)
+            .prefix_context()
             .prefix_imports()
             .finish();
 
@@ -119,15 +127,17 @@ fn generate_unit_enum_repr(repr: &TokenStream, variants: &[UnitEnumField]) -> To
         }
     });
 
+    let write_method = write_method();
+    let dot_await = dot_await();
     quote! {
-        #WRITE_METHOD (
+        #write_method (
             &(match self {
                 #(#branches),*
             } as #repr),
             #WRITER,
             &#OPT,
             (),
-        )?;
+        )#dot_await?;
     }
 }
 
@@ -136,14 +146,16 @@ fn generate_unit_enum_magic(variants: &[UnitEnumField]) -> TokenStream {
         let name = &variant.ident;
         let magic = variant.magic.as_ref().map(|magic| {
             let magic = magic.match_value();
+            let write_method = write_method();
+            let dot_await = dot_await();
 
             quote! {
-                #WRITE_METHOD (
+                #write_method (
                     &#magic,
                     #WRITER,
                     &#OPT,
                     (),
-                )?;
+                )#dot_await?;
             }
         });
 
@@ -3,7 +3,7 @@ use quote::quote;
 
 #[allow(clippy::wildcard_imports)]
 use crate::codegen::sanitization::*;
-use crate::parser::{write, CondEndian, Magic};
+use crate::parser::{write, CondEndian, Magic, MagicEndian};
 
 pub(crate) struct PreludeGenerator<'a> {
     out: TokenStream,
@@ -35,17 +35,32 @@ impl<'a> PreludeGenerator<'a> {
         self
     }
 
+    pub(crate) fn prefix_context(mut self) -> Self {
+        if let Some(context) = self.input.and_then(write::Input::context) {
+            let (ident, ty) = (&context.ident, &context.ty);
+            let out = self.out;
+            self.out = quote! {
+                let #ident: &#ty = #REQUIRE_CONTEXT(#OPT.context::<#ty>(), #POS)?;
+                #out
+            };
+        }
+
+        self
+    }
+
     pub(crate) fn prefix_magic(mut self, magic: &Magic) -> Self {
         if let Some(magic) = magic {
             let magic = magic.match_value();
             let out = self.out;
+            let write_method = write_method();
+            let dot_await = dot_await();
             self.out = quote! {
-                #WRITE_METHOD (
+                #write_method (
                     &#magic,
                     #WRITER,
                     &#OPT,
                     ()
-                )?;
+                )#dot_await?;
 
                 #out
             };
@@ -73,6 +88,18 @@ impl<'a> PreludeGenerator<'a> {
                     let #OPT = &#OPT;
                 }
             }),
+            CondEndian::Magic(MagicEndian { little, big }) => Some({
+                let write_method = write_method();
+                let dot_await = dot_await();
+                quote! {
+                    #write_method (
+                        &#MAGIC_FOR_ENDIAN(#OPT.endian(), #little, #big),
+                        #WRITER,
+                        &#OPT,
+                        ()
+                    )#dot_await?;
+                }
+            }),
         };
 
         self.out = quote! {
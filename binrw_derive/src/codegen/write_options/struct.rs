@@ -1,17 +1,22 @@
-use crate::parser::write::{Input, Struct};
+use crate::codegen::sanitization::{
+    dot_await, flush_bits, io_mode, make_ident, seek, stream_position, IoMode, BACKPATCH_END,
+    BIT_ORDER, BIT_STATE, BIT_STATE_TYPE, CHECKSUM_WRITER, CHECKSUM_WRITER_VAR, SEEK_FROM, WRITER,
+};
+use crate::parser::write::{Input, Struct, StructField};
+use crate::parser::Checksum;
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::Ident;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Ident};
 
 //#[allow(clippy::wildcard_imports)]
 //use crate::codegen::sanitization::*;
 
 use super::prelude::PreludeGenerator;
-use super::struct_field::write_field;
+use super::struct_field::{backpatch_field, write_field};
 
 pub(super) fn generate_struct(input: &Input, name: Option<&Ident>, st: &Struct) -> TokenStream {
     StructGenerator::new(Some(input), st, name)
-        .write_fields()
+        .write_fields(None)
         .prefix_assertions()
         .prefix_prelude()
         .prefix_borrow_fields()
@@ -43,6 +48,7 @@ impl<'input> StructGenerator<'input> {
         self.out = PreludeGenerator::new(self.out, self.input, self.name)
             .prefix_magic(&self.st.magic)
             .prefix_endian(&self.st.endian)
+            .prefix_context()
             .prefix_imports()
             .finish();
 
@@ -61,17 +67,143 @@ impl<'input> StructGenerator<'input> {
         self
     }
 
-    pub(crate) fn write_fields(mut self) -> Self {
-        let write_fields = self
+    pub(crate) fn write_fields(mut self, variant_name: Option<&str>) -> Self {
+        let owner = self.name.map_or_else(
+            || {
+                variant_name
+                    .unwrap_or("[please report this error]")
+                    .to_string()
+            },
+            ToString::to_string,
+        );
+        let bit_state = self.st.fields.iter().any(StructField::is_bits).then(|| {
+            let bit_order = self
+                .st
+                .bit_order
+                .clone()
+                .unwrap_or_else(|| quote! { #BIT_ORDER::MsbFirst });
+            quote! {
+                let mut #BIT_STATE = #BIT_STATE_TYPE::new(#bit_order);
+            }
+        });
+        let write_fields = self.st.fields.iter().enumerate().map(|(index, field)| {
+            let write_field =
+                write_field(field, &owner, self.st.temp_legal, self.st.version.as_ref());
+            // The last field of a run of `bits` fields writes out the final
+            // partial byte so the next field starts on a byte boundary
+            let ends_bit_run = field.is_bits()
+                && !self
+                    .st
+                    .fields
+                    .get(index + 1)
+                    .is_some_and(StructField::is_bits);
+            let write_field = if ends_bit_run {
+                let flush_bits = flush_bits();
+                let dot_await = dot_await();
+                quote! {
+                    #write_field
+                    #flush_bits(#WRITER, &mut #BIT_STATE)#dot_await?;
+                }
+            } else {
+                write_field
+            };
+            self.wrap_checksum_positions(field, &write_field)
+        });
+
+        // Backpatched fields are overwritten once everything else is written,
+        // so their values can depend on the positions of any other field.
+        // Checksums go last so they cover the final value of every other field
+        let backpatch_fields = self
             .st
             .fields
             .iter()
-            .map(|f| write_field(f, self.st.temp_legal));
+            .filter(|field| field.checksum.is_none())
+            .chain(
+                self.st
+                    .fields
+                    .iter()
+                    .filter(|field| field.checksum.is_some()),
+            )
+            .filter_map(|field| backpatch_field(field, &owner, self.st.temp_legal))
+            .collect::<Vec<_>>();
+        let backpatch = (!backpatch_fields.is_empty()).then(|| {
+            let current_pos = stream_position(WRITER);
+            let seek_end = seek(WRITER, quote! { #SEEK_FROM::Start(#BACKPATCH_END) });
+            quote! {
+                let #BACKPATCH_END = #current_pos?;
+                #(#backpatch_fields)*
+                #seek_end?;
+            }
+        });
 
         self.out = quote! {
+            #bit_state
             #(#write_fields)*
+            #backpatch
+        };
+
+        self.wrap_checksum_writer()
+    }
+
+    /// Records the positions before and after `field` if they are needed to
+    /// compute a checksum.
+    fn wrap_checksum_positions(
+        &self,
+        field: &StructField,
+        write_field: &TokenStream,
+    ) -> TokenStream {
+        let is_checksum_edge = |edge: fn(&Checksum) -> &Ident| {
+            self.st
+                .fields
+                .iter()
+                .filter_map(|field| field.checksum.as_ref())
+                .any(|checksum| *edge(checksum) == field.ident)
         };
 
+        let current_pos = stream_position(WRITER);
+        let start = is_checksum_edge(|checksum| &checksum.from).then(|| {
+            let start = make_ident(&field.ident, "checksum_start");
+            quote! { let #start = #current_pos?; }
+        });
+        let end = is_checksum_edge(|checksum| &checksum.to).then(|| {
+            let end = make_ident(&field.ident, "checksum_end");
+            quote! { let #end = #current_pos?; }
+        });
+
+        quote! {
+            #start
+            #write_field
+            #end
+        }
+    }
+
+    /// Routes all writes for the fields through a writer which keeps a copy of
+    /// the written data, since checksums are computed after it was written.
+    fn wrap_checksum_writer(mut self) -> Self {
+        let checksum = self
+            .st
+            .fields
+            .iter()
+            .find_map(|field| field.checksum.as_ref());
+        if let Some(checksum) = checksum {
+            let out = self.out;
+            self.out = if io_mode() == IoMode::Async {
+                let error = quote_spanned! {checksum.algorithm.span()=>
+                    compile_error!("`checksum` cannot be used with `async` because it calls a blocking function");
+                };
+                quote! {
+                    #error
+                    #out
+                }
+            } else {
+                quote! {
+                    let mut #CHECKSUM_WRITER_VAR = #CHECKSUM_WRITER::new(#WRITER)?;
+                    let #WRITER = &mut #CHECKSUM_WRITER_VAR;
+                    #out
+                }
+            };
+        }
+
         self
     }
 
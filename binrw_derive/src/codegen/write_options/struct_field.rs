@@ -5,38 +5,166 @@ use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::Ident;
 
+use crate::codegen::get_version_condition;
 #[allow(clippy::wildcard_imports)]
 use crate::codegen::sanitization::*;
 use crate::parser::write::StructField;
-use crate::parser::{CondEndian, Map, PassedArgs, TempableField, WriteMode};
+use crate::parser::{CondEndian, ErrContext, Map, PassedArgs, TempableField, WriteMode};
 
-pub(crate) fn write_field(field: &StructField, temp_legal: bool) -> TokenStream {
-    StructFieldGenerator::new(field, temp_legal)
+pub(crate) fn write_field(
+    field: &StructField,
+    owner: &str,
+    temp_legal: bool,
+    version: Option<&TokenStream>,
+) -> TokenStream {
+    StructFieldGenerator::new(field, owner, temp_legal)
         .write_field()
+        .wrap_transform()
+        .wrap_size()
+        .wrap_byte_range()
         .wrap_padding()
         .prefix_args()
         .prefix_write_fn()
         .prefix_map_fn()
+        .wrap_condition(version)
         .prefix_magic()
         .prefix_assertions()
+        .prefix_calc()
+        .suffix_byte_range()
         .finish()
 }
 
+/// Generates the code which overwrites the value of a `backpatch` or
+/// `checksum` field once the rest of its struct has been written.
+pub(crate) fn backpatch_field(
+    field: &StructField,
+    owner: &str,
+    temp_legal: bool,
+) -> Option<TokenStream> {
+    let generator = StructFieldGenerator::new(field, owner, temp_legal);
+    let expr = match (&field.backpatch, &field.checksum) {
+        (Some(expr), _) => expr.clone(),
+        // Checksums cannot be computed asynchronously, which is reported by
+        // the struct instead
+        (None, Some(_)) if io_mode() == IoMode::Async => return None,
+        (None, Some(checksum)) => {
+            let algorithm = &checksum.algorithm;
+            let from = make_ident(&checksum.from, "checksum_start");
+            let to = make_ident(&checksum.to, "checksum_end");
+            quote! { #WRITER.checksum::<#algorithm>(#from..#to) }
+        }
+        (None, None) => return None,
+    };
+    if generator.is_temp_error() {
+        return None;
+    }
+
+    let ty = &field.ty;
+    let value = make_ident(&field.ident, "backpatch");
+    let args = generator.args_ident();
+    let args_val = get_passed_args(field).unwrap_or_else(|| quote! { <_>::default() });
+    let range = generator.byte_range_ident();
+    let specify_options = generator.specify_options();
+    let write_method = write_method();
+    let dot_await = dot_await();
+    let seek = seek(WRITER, quote! { #SEEK_FROM::Start(#range.start) });
+    let current_pos = stream_position(WRITER);
+    let write = generator.map_err_context(&quote! {
+        #write_method(&#value, #WRITER, &#OPT#specify_options, #args)#dot_await
+    });
+    let check = generator.map_err_context(&quote! {
+        #CHECK_BACKPATCH(#range.start, #range.end, #current_pos?)
+    });
+
+    // Fields which were not written have nothing to patch
+    Some(quote! {
+        if !#range.is_empty() {
+            let #value: #ty = #expr;
+            let #args: <#ty as #BINWRITE_TRAIT>::Args = #args_val;
+            #seek?;
+            #write
+            #check
+        }
+    })
+}
+
 struct StructFieldGenerator<'input> {
     field: &'input StructField,
+    owner: &'input str,
     out: TokenStream,
     temp_legal: bool,
 }
 
 impl<'a> StructFieldGenerator<'a> {
-    fn new(field: &'a StructField, temp_legal: bool) -> Self {
+    fn new(field: &'a StructField, owner: &'a str, temp_legal: bool) -> Self {
         Self {
             field,
+            owner,
             out: TokenStream::new(),
             temp_legal,
         }
     }
 
+    /// Generates a statement which evaluates `result` and returns its error
+    /// with an extra backtrace frame for this field.
+    fn map_err_context(&self, result: &TokenStream) -> TokenStream {
+        let frame = match self.field.err_context.as_ref() {
+            Some(ErrContext::Format(message, exprs)) if exprs.is_empty() => {
+                quote_spanned! {self.field.ident.span()=>
+                    #BACKTRACE_FRAME::Full {
+                        message: #message,
+                        line: ::core::line!(),
+                        file: ::core::file!(),
+                        code: None,
+                    }
+                }
+            }
+            Some(ErrContext::Format(format, exprs)) => {
+                quote_spanned! {self.field.ident.span()=>
+                    #BACKTRACE_FRAME::OwnedFull {
+                        message: ::binrw::alloc::format!(#format, #(#exprs),*),
+                        line: ::core::line!(),
+                        file: ::core::file!(),
+                        code: None,
+                    }
+                }
+            }
+            Some(ErrContext::Context(expr)) => {
+                quote! {
+                    #BACKTRACE_FRAME::Custom(Box::new(#expr) as _)
+                }
+            }
+            None => {
+                let message = format!(
+                    "While writing field '{}' in {}",
+                    self.field.ident, self.owner
+                );
+                let current_pos = stream_position(WRITER);
+                quote_spanned! {self.field.ident.span()=>
+                    #BACKTRACE_FRAME::OwnedFull {
+                        message: match #current_pos {
+                            Ok(pos) => ::binrw::alloc::format!("{} at {:#x}", #message, pos),
+                            Err(_) => ::binrw::alloc::string::ToString::to_string(#message),
+                        },
+                        line: ::core::line!(),
+                        file: ::core::file!(),
+                        code: None,
+                    }
+                }
+            }
+        };
+
+        quote! {
+            if let Err(error) = #result {
+                return Err(#WITH_CONTEXT(error, #frame));
+            }
+        }
+    }
+
+    fn is_temp_error(&self) -> bool {
+        !self.temp_legal && self.field.is_temp_for_crossover()
+    }
+
     fn args_ident(&self) -> Ident {
         make_ident(&self.field.ident, "args")
     }
@@ -45,6 +173,10 @@ impl<'a> StructFieldGenerator<'a> {
         make_ident(&self.field.ident, "map_fn")
     }
 
+    fn byte_range_ident(&self) -> Ident {
+        make_ident(&self.field.ident, "byte_range")
+    }
+
     fn specify_endian(&self) -> Option<TokenStream> {
         match &self.field.endian {
             CondEndian::Inherited => None,
@@ -61,6 +193,20 @@ impl<'a> StructFieldGenerator<'a> {
                     .clone().with_endian(if #cond { #endian } else { #else_endian })
                 }
             }),
+            CondEndian::Magic(_) => unreachable!("Fields cannot use `magic_endian`"),
+        }
+    }
+
+    fn specify_options(&self) -> Option<TokenStream> {
+        let offset = self
+            .field
+            .offset
+            .as_ref()
+            .map(|offset| quote! { .with_offset(#offset) });
+        match (self.specify_endian(), offset) {
+            (None, None) => None,
+            (Some(endian), offset) => Some(quote! { #endian #offset }),
+            (None, Some(offset)) => Some(quote! { .clone() #offset }),
         }
     }
 
@@ -77,17 +223,30 @@ impl<'a> StructFieldGenerator<'a> {
     }
 
     fn prefix_write_fn(mut self) -> Self {
-        if !self.field.is_written() {
+        if !self.field.is_written() || self.field.is_bits() {
             return self;
         }
 
         let write_fn = match &self.field.write_mode {
-            WriteMode::Normal | WriteMode::Calc(_) => quote! { #WRITE_METHOD },
+            WriteMode::WriteWith(write_fn) if io_mode() == IoMode::Async => {
+                quote_spanned! {write_fn.span()=>
+                    compile_error!("`write_with` cannot be used with `async` because it calls a blocking function")
+                }
+            }
+            WriteMode::Normal | WriteMode::Calc(_) => {
+                let write_method = write_method();
+                quote! { #write_method }
+            }
             WriteMode::WriteWith(write_fn) => write_fn.clone(),
             WriteMode::Ignore => unreachable!("Ignored fields are not written"),
+            WriteMode::Bits(_) => unreachable!("Bit fields are written without a write function"),
         };
 
-        let write_fn = if self.field.map.is_some() {
+        // The type hints only accept blocking write functions, but async
+        // writes only ever go through the trait method, which needs no hints
+        let write_fn = if io_mode() == IoMode::Async {
+            write_fn
+        } else if self.field.map.is_some() {
             let map_fn = self.map_fn_ident();
             if self.field.map.is_try() {
                 quote! { #WRITE_FN_TRY_MAP_OUTPUT_TYPE_HINT(&#map_fn, #write_fn) }
@@ -137,9 +296,9 @@ impl<'a> StructFieldGenerator<'a> {
     fn write_field(mut self) -> Self {
         let name = &self.field.ident;
         let args = self.args_ident();
-        let specify_endian = self.specify_endian();
+        let specify_options = self.specify_options();
 
-        if !self.temp_legal && self.field.is_temp_for_crossover() {
+        if self.is_temp_error() {
             // Emit error regarding temp.
             let ty = &self.field.ty;
             self.out = quote_spanned! {self.field.field.span()=>
@@ -151,13 +310,9 @@ impl<'a> StructFieldGenerator<'a> {
             return self;
         }
 
-        let initialize = match &self.field.write_mode {
-            WriteMode::Calc(expr) => Some({
-                let ty = &self.field.ty;
-                quote! {
-                    let #name: #ty = #expr;
-                }
-            }),
+        match &self.field.write_mode {
+            // Calculated values are initialized by `prefix_calc`
+            WriteMode::Calc(_) => {}
             // If ignored, just skip this now
             WriteMode::Ignore => return self,
             // If field is temp, it should also be calc or ignore
@@ -174,58 +329,84 @@ impl<'a> StructFieldGenerator<'a> {
                 };
                 return self;
             }
-            _ => None,
-        };
+            _ => {}
+        }
 
-        let map_fn = self.field.map.is_some().then(|| self.map_fn_ident());
-        let map_try = self.field.map.is_try().then(|| quote! { ? });
+        let map_fn = self.map_fn_ident();
+        let dot_await = dot_await();
+        let write_value = |value: TokenStream| {
+            if let WriteMode::Bits(bits) = &self.field.write_mode {
+                let write_bits = write_bits();
+                quote! {
+                    #write_bits(#value, #WRITER, &mut #BIT_STATE, (#bits) as u32)#dot_await
+                }
+            } else {
+                quote! {
+                    #WRITE_FUNCTION (
+                        #value,
+                        #WRITER,
+                        &#OPT#specify_options,
+                        #args
+                    )#dot_await
+                }
+            }
+        };
 
-        self.out = quote! {
-            #initialize
-
-            #WRITE_FUNCTION (
-                &(#map_fn (#name) #map_try),
-                #WRITER,
-                &#OPT#specify_endian,
-                #args
-            )?;
+        // Errors from `try_map` get the same context as errors from writing
+        let write = match &self.field.map {
+            Map::Try(_) => {
+                let write_value = write_value(quote! { &value });
+                quote! {
+                    match #map_fn(#name) {
+                        Ok(value) => #write_value,
+                        Err(error) => Err(error),
+                    }
+                }
+            }
+            Map::Map(_) => write_value(quote! { &#map_fn(#name) }),
+            // Unmapped fields are already borrowed from `self`
+            Map::None if self.field.is_bits() => write_value(quote! { #name }),
+            Map::None => write_value(quote! { &#name }),
         };
+        self.out = self.map_err_context(&write);
 
         self
     }
 
     fn pad_before(&self) -> TokenStream {
-        let seek_before = self.field.seek_before.as_ref().map(|seek| {
+        let write_zeroes = write_zeroes();
+        let dot_await = dot_await();
+        let current_pos = seek(WRITER, quote! { #SEEK_FROM::Current(0) });
+
+        let seek_before = self.field.seek_before.as_ref().map(|pos| {
+            let seek = seek(WRITER, pos);
             quote! {
-                #SEEK_TRAIT::seek(
-                    #WRITER,
-                    #seek,
-                )?;
+                #seek?;
             }
         });
         let pad_before = self.field.pad_before.as_ref().map(|padding| {
             quote! {
-                #WRITE_ZEROES(#WRITER, (#padding) as u64)?;
+                #write_zeroes(#WRITER, (#padding) as u64)#dot_await?;
             }
         });
         let align_before = self.field.align_before.as_ref().map(|alignment| {
             quote! {{
-                let pos = #SEEK_TRAIT::seek(#WRITER, #SEEK_FROM::Current(0))?;
+                let pos = #current_pos?;
                 let align = ((#alignment) as u64);
                 let rem = pos % align;
                 if rem != 0 {
-                    #WRITE_ZEROES(#WRITER, align - rem)?;
+                    #write_zeroes(#WRITER, align - rem)#dot_await?;
                 }
             }}
         });
         let pad_size_to_before = self.field.pad_size_to.as_ref().map(|_| {
             quote! {
-                let #BEFORE_POS = #SEEK_TRAIT::seek(#WRITER, #SEEK_FROM::Current(0))?;
+                let #BEFORE_POS = #current_pos?;
             }
         });
         let store_position = self.field.restore_position.map(|_| {
             quote! {
-                let #SAVED_POSITION = #SEEK_TRAIT::seek(#WRITER, #SEEK_FROM::Current(0))?;
+                let #SAVED_POSITION = #current_pos?;
             }
         });
 
@@ -239,35 +420,40 @@ impl<'a> StructFieldGenerator<'a> {
     }
 
     fn pad_after(&self) -> TokenStream {
+        let write_zeroes = write_zeroes();
+        let dot_await = dot_await();
+        let current_pos = seek(WRITER, quote! { #SEEK_FROM::Current(0) });
+
         let pad_size_to = self.field.pad_size_to.as_ref().map(|size| {
             quote! {{
                 let pad_to_size = (#size) as u64;
-                let after_pos = #SEEK_TRAIT::seek(#WRITER, #SEEK_FROM::Current(0))?;
+                let after_pos = #current_pos?;
                 if let Some(size) = after_pos.checked_sub(#BEFORE_POS) {
                     if let Some(padding) = pad_to_size.checked_sub(size) {
-                        #WRITE_ZEROES(#WRITER, padding)?;
+                        #write_zeroes(#WRITER, padding)#dot_await?;
                     }
                 }
             }}
         });
         let pad_after = self.field.pad_after.as_ref().map(|padding| {
             quote! {
-                #WRITE_ZEROES(#WRITER, (#padding) as u64)?;
+                #write_zeroes(#WRITER, (#padding) as u64)#dot_await?;
             }
         });
         let align_after = self.field.align_after.as_ref().map(|alignment| {
             quote! {{
-                let pos = #SEEK_TRAIT::seek(#WRITER, #SEEK_FROM::Current(0))?;
+                let pos = #current_pos?;
                 let align = ((#alignment) as u64);
                 let rem = pos % align;
                 if rem != 0 {
-                    #WRITE_ZEROES(#WRITER, align - rem)?;
+                    #write_zeroes(#WRITER, align - rem)#dot_await?;
                 }
             }}
         });
         let restore_position = self.field.restore_position.map(|_| {
+            let restore = seek(WRITER, quote! { #SEEK_FROM::Start(#SAVED_POSITION) });
             quote! {
-                #SEEK_TRAIT::seek(#WRITER, #SEEK_FROM::Start(#SAVED_POSITION))?;
+                #restore?;
             }
         });
 
@@ -279,6 +465,76 @@ impl<'a> StructFieldGenerator<'a> {
         }
     }
 
+    fn wrap_transform(mut self) -> Self {
+        let transform = match &self.field.transform {
+            Some(transform) if self.field.is_written() && !self.is_temp_error() => {
+                &transform.transform
+            }
+            _ => return self,
+        };
+
+        if io_mode() == IoMode::Async {
+            self.out = quote_spanned! {transform.span()=>
+                compile_error!("`transform` cannot be used with `async` because it calls a blocking function");
+            };
+            return self;
+        }
+
+        let stream = make_ident(&self.field.ident, "transform_stream");
+        let encode = self.map_err_context(&quote! {
+            #ENCODE_TRANSFORM(#WRITER, &(#transform), #stream)
+        });
+        let out = self.out;
+        self.out = quote! {
+            let mut #stream = #TRANSFORM_STREAM();
+            {
+                let #WRITER = &mut #stream;
+                #out
+            }
+            #encode
+        };
+
+        self
+    }
+
+    fn wrap_size(mut self) -> Self {
+        let size = match &self.field.size {
+            Some(size) if self.field.is_written() && !self.is_temp_error() => size,
+            _ => return self,
+        };
+
+        let start = make_ident(&self.field.ident, "size_start");
+        let current_pos = stream_position(WRITER);
+        let write_zeroes = write_zeroes();
+        let dot_await = dot_await();
+        let out = self.out;
+        self.out = quote! {
+            let #start = #current_pos?;
+            #out
+            {
+                let padding = #SIZE_PADDING(#start, (#size) as u64, #current_pos?)?;
+                #write_zeroes(#WRITER, padding)#dot_await?;
+            }
+        };
+
+        self
+    }
+
+    fn wrap_byte_range(mut self) -> Self {
+        if self.field.needs_byte_range() && !self.is_temp_error() {
+            let range = self.byte_range_ident();
+            let current_pos = stream_position(WRITER);
+            let out = self.out;
+            self.out = quote! {
+                let #BYTE_RANGE_START = #current_pos?;
+                #out
+                let #range = #BYTE_RANGE_START..#current_pos?;
+            };
+        }
+
+        self
+    }
+
     fn wrap_padding(mut self) -> Self {
         let out = &self.out;
 
@@ -293,8 +549,88 @@ impl<'a> StructFieldGenerator<'a> {
         self
     }
 
+    fn wrap_condition(mut self, version: Option<&TokenStream>) -> Self {
+        let version = version
+            .filter(|_| self.field.is_versioned())
+            .map(|version| {
+                get_version_condition(
+                    version,
+                    self.field.since.as_ref(),
+                    self.field.until.as_ref(),
+                )
+            });
+        let if_cond = self.field.if_cond.as_ref().map(|cond| &cond.condition);
+        let condition = match (version, if_cond) {
+            (None, None) => return self,
+            (Some(version), None) => version,
+            (None, Some(if_cond)) => quote! { #if_cond },
+            (Some(version), Some(if_cond)) => quote! { #version && (#if_cond) },
+        };
+
+        let needs_byte_range = self.field.needs_byte_range() && !self.is_temp_error();
+        let range = self.byte_range_ident();
+        let out = self.out;
+        self.out = if needs_byte_range {
+            // Fields which are not written get an empty range so the
+            // range can still be used after the condition
+            let current_pos = stream_position(WRITER);
+            quote! {
+                let #range = if #condition {
+                    #out
+                    #range
+                } else {
+                    let pos = #current_pos?;
+                    pos..pos
+                };
+            }
+        } else {
+            quote! {
+                if #condition {
+                    #out
+                }
+            }
+        };
+
+        self
+    }
+
+    // Calculated values are initialized outside of any conditions so later
+    // fields can always refer to them
+    fn prefix_calc(mut self) -> Self {
+        if let (WriteMode::Calc(expr), false) = (&self.field.write_mode, self.is_temp_error()) {
+            let name = &self.field.ident;
+            let ty = &self.field.ty;
+            let out = self.out;
+            self.out = quote! {
+                let #name: #ty = #expr;
+                #out
+            };
+        }
+
+        self
+    }
+
+    fn suffix_byte_range(mut self) -> Self {
+        if let (Some(name), false) = (&self.field.byte_range, self.is_temp_error()) {
+            let range = self.byte_range_ident();
+            // The range is still needed to backpatch the field
+            let range = if self.field.is_backpatched() {
+                quote! { #range.clone() }
+            } else {
+                quote! { #range }
+            };
+            let out = self.out;
+            self.out = quote! {
+                #out
+                let #name = #range;
+            };
+        }
+
+        self
+    }
+
     fn prefix_args(mut self) -> Self {
-        if !self.field.is_written() {
+        if !self.field.is_written() || self.field.is_bits() {
             return self;
         }
 
@@ -303,7 +639,7 @@ impl<'a> StructFieldGenerator<'a> {
         let args_val = if let Some(args) = get_passed_args(self.field) {
             args
         } else {
-            quote! { () }
+            quote! { <_>::default() }
         };
 
         let map_fn = self.map_fn_ident();
@@ -346,14 +682,21 @@ impl<'a> StructFieldGenerator<'a> {
             }
             WriteMode::WriteWith(_) => {
                 let ty = &self.field.ty;
+                // Transformed fields are written to a buffer instead
+                let writer = if self.field.transform.is_some() {
+                    quote! { &#TRANSFORM_STREAM() }
+                } else {
+                    quote! { &*#WRITER }
+                };
                 quote! {
-                    let #args = #WRITE_WITH_ARGS_TYPE_HINT::<#ty, W, _, _>(
-                        #WRITE_FUNCTION, #args_val
+                    let #args = #WRITE_WITH_ARGS_TYPE_HINT::<#ty, _, _, _>(
+                        #WRITE_FUNCTION, #writer, #args_val
                     );
                     #out
                 }
             }
             WriteMode::Ignore => unreachable!("Ignored fields are not written"),
+            WriteMode::Bits(_) => unreachable!("Bit fields do not take arguments"),
         };
 
         self
@@ -364,13 +707,15 @@ impl<'a> StructFieldGenerator<'a> {
             let magic = magic.match_value();
             let specify_endian = self.specify_endian();
             let out = self.out;
+            let write_method = write_method();
+            let dot_await = dot_await();
             self.out = quote! {
-                #WRITE_METHOD (
+                #write_method (
                     &#magic,
                     #WRITER,
                     &#OPT #specify_endian,
                     ()
-                )?;
+                )#dot_await?;
 
                 #out
             };
@@ -386,10 +731,17 @@ impl<'a> StructFieldGenerator<'a> {
 
 fn get_passed_args(field: &StructField) -> Option<TokenStream> {
     let args = &field.args;
+    let count = field.count.as_ref().map(|count| {
+        if field.count_is_varint {
+            quote! { #INTO_COUNT(#count) }
+        } else {
+            quote! { ((#count) as usize) }
+        }
+    });
     match args {
-        PassedArgs::Named(fields) => Some(if let Some(count) = &field.count {
+        PassedArgs::Named(fields) => Some(if let Some(count) = count {
             quote! {
-                #ARGS_MACRO! { count: ((#count) as usize) #(, #fields)* }
+                #ARGS_MACRO! { count: #count #(, #fields)* }
             }
         } else {
             quote! {
@@ -398,9 +750,6 @@ fn get_passed_args(field: &StructField) -> Option<TokenStream> {
         }),
         PassedArgs::List(list) => Some(quote! { (#(#list,)*) }),
         PassedArgs::Tuple(tuple) => Some(tuple.clone()),
-        PassedArgs::None => field
-            .count
-            .as_ref()
-            .map(|count| quote! { #ARGS_MACRO! { count: ((#count) as usize) }}),
+        PassedArgs::None => count.map(|count| quote! { #ARGS_MACRO! { count: #count }}),
     }
 }
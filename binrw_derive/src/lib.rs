@@ -41,6 +41,12 @@ pub fn derive_binwrite_trait(input: TokenStream) -> TokenStream {
         .into()
 }
 
+#[proc_macro_derive(BinSize, attributes(bw, brw))]
+#[cfg(not(tarpaulin_include))]
+pub fn derive_binsize_trait(input: TokenStream) -> TokenStream {
+    binwrite::derive_bin_size_from_input(&parse_macro_input!(input as DeriveInput)).into()
+}
+
 #[proc_macro_attribute]
 #[cfg(not(tarpaulin_include))]
 pub fn binwrite(_: TokenStream, input: TokenStream) -> TokenStream {
@@ -12,17 +12,26 @@ define_keywords! {
     args,
     args_raw,
     assert,
+    backpatch,
     big,
     binread,
+    bit_order,
+    bits,
+    borrow,
     br,
     brw,
     binwrite,
     bw,
+    byte_range,
     calc,
+    checksum,
+    context,
     count,
+    data_section,
     default,
     deref_now,
     err_context,
+    from,
     ignore,
     import,
     import_raw,
@@ -30,6 +39,7 @@ define_keywords! {
     is_little,
     little,
     magic,
+    magic_endian,
     map,
     offset,
     offset_after,
@@ -44,7 +54,14 @@ define_keywords! {
     return_all_errors,
     return_unexpected_error,
     seek_before,
+    since,
+    size,
+    stream,
     temp,
+    to,
+    transform,
     try_map,
+    until,
+    version,
     write_with,
 }
@@ -69,6 +69,12 @@ impl<Keyword, Value: ToTokens> From<MetaValue<Keyword, Value>> for TokenStream {
     }
 }
 
+impl<Keyword> From<MetaValue<Keyword, IdentPatType>> for IdentPatType {
+    fn from(value: MetaValue<Keyword, IdentPatType>) -> Self {
+        value.value
+    }
+}
+
 impl<Keyword, Value: ToTokens> ToTokens for MetaValue<Keyword, Value> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.value.to_tokens(tokens);
@@ -20,6 +20,28 @@ fn combine_error(all_errors: &mut Option<syn::Error>, new_error: syn::Error) {
     }
 }
 
+/// Returns true if a `count` expression is only the name of one of the
+/// previous `fields` and that field has a variable-length integer type, which
+/// cannot be cast to `usize` with `as`.
+fn is_varint_count<'a>(
+    count: Option<&proc_macro2::TokenStream>,
+    mut fields: impl Iterator<Item = (&'a syn::Ident, &'a syn::Type)>,
+) -> bool {
+    const VARINT_TYPES: [&str; 4] = ["ULeb128", "SLeb128", "VarInt", "ZigZag"];
+
+    let ident = match count.and_then(|count| syn::parse2::<syn::Ident>(count.clone()).ok()) {
+        Some(ident) => ident,
+        None => return false,
+    };
+
+    fields.any(|(name, ty)| {
+        *name == ident
+            && matches!(ty, syn::Type::Path(ty) if ty.path.segments.last().map_or(false, |segment| {
+                VARINT_TYPES.iter().any(|varint| segment.ident == varint)
+            }))
+    })
+}
+
 pub(crate) trait TempableField {
     // The identifier for this field.
     fn ident(&self) -> &syn::Ident;
@@ -4,7 +4,7 @@ use super::super::{
         IdentPatType, IdentTypeMaybeDefault, MetaEnclosedList, MetaExpr, MetaList, MetaLit,
         MetaType, MetaValue, MetaVoid,
     },
-    KeywordToken,
+    types, KeywordToken,
 };
 use proc_macro2::{Span, TokenStream};
 use syn::{
@@ -34,8 +34,14 @@ pub(crate) type Args = MetaEnclosedList<kw::args, Expr, TokenStream>;
 pub(crate) type ArgsRaw = MetaExpr<kw::args_raw>;
 pub(crate) type AssertLike<K> = MetaList<K, Expr>;
 pub(crate) type Assert = AssertLike<kw::assert>;
+pub(crate) type Async = MetaVoid<Token![async]>;
 pub(crate) type Big = MetaVoid<kw::big>;
+pub(crate) type BitOrder = MetaExpr<kw::bit_order>;
+pub(crate) type Bits = MetaExpr<kw::bits>;
+pub(crate) type Borrow = MetaVoid<kw::borrow>;
 pub(crate) type Calc = MetaExpr<kw::calc>;
+pub(crate) type Checksum = MetaValue<kw::checksum, types::Checksum>;
+pub(crate) type Context = MetaValue<kw::context, IdentPatType>;
 pub(crate) type Count = MetaExpr<kw::count>;
 pub(crate) type Default = MetaVoid<kw::default>;
 pub(crate) type DerefNow = MetaVoid<kw::deref_now>;
@@ -49,6 +55,7 @@ pub(crate) type IsBig = MetaExpr<kw::is_big>;
 pub(crate) type IsLittle = MetaExpr<kw::is_little>;
 pub(crate) type Little = MetaVoid<kw::little>;
 pub(crate) type Magic = MetaLit<kw::magic>;
+pub(crate) type MagicEndian = MetaValue<kw::magic_endian, types::MagicEndian>;
 pub(crate) type Map = MetaExpr<kw::map>;
 pub(crate) type Offset = MetaExpr<kw::offset>;
 pub(crate) type OffsetAfter = MetaExpr<kw::offset_after>;
@@ -63,6 +70,12 @@ pub(crate) type RestorePosition = MetaVoid<kw::restore_position>;
 pub(crate) type ReturnAllErrors = MetaVoid<kw::return_all_errors>;
 pub(crate) type ReturnUnexpectedError = MetaVoid<kw::return_unexpected_error>;
 pub(crate) type SeekBefore = MetaExpr<kw::seek_before>;
+pub(crate) type Since = MetaExpr<kw::since>;
+pub(crate) type Size = MetaExpr<kw::size>;
+pub(crate) type Stream = MetaVoid<kw::stream>;
 pub(crate) type Temp = MetaVoid<kw::temp>;
+pub(crate) type Transform = MetaValue<kw::transform, types::Transform>;
 pub(crate) type Try = MetaVoid<Token![try]>;
 pub(crate) type TryMap = MetaExpr<kw::try_map>;
+pub(crate) type Until = MetaExpr<kw::until>;
+pub(crate) type Version = MetaExpr<kw::version>;
@@ -1,6 +1,9 @@
 use super::super::{
     read::{FromAttrs, FromInput},
-    types::{Assert, CondEndian, Condition, ErrContext, Magic, Map, PassedArgs, ReadMode},
+    types::{
+        Assert, Checksum, CondEndian, Condition, ErrContext, Magic, Map, PassedArgs, ReadMode,
+        Transform,
+    },
     FromField, ParseResult, SpannedValue, TrySet,
 };
 
@@ -28,7 +31,7 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(Args, ArgsRaw)]
         pub(crate) args: PassedArgs,
-        #[from(Calc, Default, Ignore, ParseWith)]
+        #[from(Calc, Default, Ignore, ParseWith, Bits)]
         pub(crate) read_mode: ReadMode,
         #[from(Count)]
         pub(crate) count: Option<TokenStream>,
@@ -38,6 +41,10 @@ attr_struct! {
         pub(crate) offset_after: Option<SpannedValue<TokenStream>>,
         #[from(If)]
         pub(crate) if_cond: Option<Condition>,
+        #[from(Since)]
+        pub(crate) since: Option<TokenStream>,
+        #[from(Until)]
+        pub(crate) until: Option<TokenStream>,
         #[from(DerefNow, PostProcessNow)]
         pub(crate) deref_now: Option<SpannedValue<()>>,
         #[from(RestorePosition)]
@@ -62,6 +69,14 @@ attr_struct! {
         pub(crate) seek_before: Option<TokenStream>,
         #[from(PadSizeTo)]
         pub(crate) pad_size_to: Option<TokenStream>,
+        #[from(Checksum)]
+        pub(crate) checksum: Option<Checksum>,
+        #[from(Size)]
+        pub(crate) size: Option<TokenStream>,
+        #[from(Transform)]
+        pub(crate) transform: Option<Transform>,
+        // Whether `count` names a field with a variable-length integer type
+        pub(crate) count_is_varint: bool,
     }
 }
 
@@ -75,7 +90,7 @@ impl StructField {
     /// Returns true if the code generator should emit `BinRead::after_parse()`
     /// after all fields have been read.
     pub(crate) fn should_use_after_parse(&self) -> bool {
-        self.deref_now.is_none() && self.map.is_none()
+        self.deref_now.is_none() && self.map.is_none() && self.transform.is_none()
     }
 
     /// Returns true if this field is generated using a calculated value instead
@@ -84,9 +99,70 @@ impl StructField {
         matches!(self.read_mode, ReadMode::Calc(_) | ReadMode::Default)
     }
 
+    /// Returns true if this field is read from a bit run instead of whole
+    /// bytes.
+    pub(crate) fn is_bits(&self) -> bool {
+        matches!(self.read_mode, ReadMode::Bits(_))
+    }
+
+    /// Returns true if this field only exists in some versions of the format.
+    pub(crate) fn is_versioned(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+
     /// Returns true if the field needs `ReadOptions` to be parsed.
     pub(crate) fn needs_options(&self) -> bool {
-        !self.generated_value() || self.magic.is_some()
+        !(self.generated_value() || self.is_bits()) || self.magic.is_some()
+    }
+
+    /// Returns the name of the first directive on this field which only works
+    /// on whole bytes.
+    pub(crate) fn byte_directive(&self) -> Option<&'static str> {
+        if self.magic.is_some() {
+            Some("magic")
+        } else if self.args.is_some() {
+            Some("args")
+        } else if self.count.is_some() {
+            Some("count")
+        } else if self.offset.is_some() || self.offset_after.is_some() {
+            Some("offset")
+        } else if self.deref_now.is_some() {
+            Some("deref_now")
+        } else if self.pad_before.is_some() {
+            Some("pad_before")
+        } else if self.pad_after.is_some() {
+            Some("pad_after")
+        } else if self.pad_size_to.is_some() {
+            Some("pad_size_to")
+        } else if self.size.is_some() {
+            Some("size")
+        } else if self.transform.is_some() {
+            Some("transform")
+        } else {
+            self.seeking_directive()
+                .filter(|&directive| directive != "try")
+        }
+    }
+
+    /// Returns the name of the first directive on this field which needs to
+    /// seek the reader to somewhere other than forwards from the current
+    /// position.
+    pub(crate) fn seeking_directive(&self) -> Option<&'static str> {
+        if self.seek_before.is_some() {
+            Some("seek_before")
+        } else if self.restore_position.is_some() {
+            Some("restore_position")
+        } else if self.align_before.is_some() {
+            Some("align_before")
+        } else if self.align_after.is_some() {
+            Some("align_after")
+        } else if self.do_try.is_some() {
+            Some("try")
+        } else if self.checksum.is_some() {
+            Some("checksum")
+        } else {
+            None
+        }
     }
 
     /// Returns true if the only field-level attributes are asserts
@@ -110,6 +186,8 @@ impl StructField {
                 offset,
                 offset_after,
                 if_cond,
+                since,
+                until,
                 deref_now,
                 restore_position,
                 do_try,
@@ -120,7 +198,10 @@ impl StructField {
                 align_after,
                 seek_before,
                 pad_size_to,
-                magic
+                magic,
+                checksum,
+                size,
+                transform
             )
     }
 
@@ -134,6 +215,11 @@ impl StructField {
                 span,
                 "`deref_now` and `offset_after` are mutually exclusive",
             ))
+        } else if let (true, Some(directive)) = (self.is_bits(), self.byte_directive()) {
+            Err(syn::Error::new(
+                self.field.span(),
+                format!("`{directive}` cannot be used with `bits` because it works on whole bytes"),
+            ))
         } else if self.do_try.is_some() && self.generated_value() {
             //TODO: join with span of read mode somehow
             let span = self.do_try.as_ref().unwrap().span();
@@ -141,6 +227,26 @@ impl StructField {
                 span,
                 "`try` is incompatible with `default` and `calc`",
             ))
+        } else if self.checksum.is_some() && self.generated_value() {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`checksum` cannot be used with `default` or `calc` because the checksum must be read",
+            ))
+        } else if self.transform.is_some() && self.generated_value() {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`transform` cannot be used with `default` or `calc` because there is no data to decode",
+            ))
+        } else if self.size.is_some() && self.generated_value() {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`size` cannot be used with `default` or `calc` because no data is read",
+            ))
+        } else if let (Some(_), Some(_)) = (&self.size, &self.transform) {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`size` cannot be used with `transform`; use `transform(..., size = ...)` instead",
+            ))
         } else {
             Ok(())
         }
@@ -187,6 +293,8 @@ impl FromField for StructField {
                 offset: <_>::default(),
                 offset_after: <_>::default(),
                 if_cond: <_>::default(),
+                since: <_>::default(),
+                until: <_>::default(),
                 deref_now: <_>::default(),
                 restore_position: <_>::default(),
                 do_try: <_>::default(),
@@ -198,7 +306,11 @@ impl FromField for StructField {
                 align_after: <_>::default(),
                 seek_before: <_>::default(),
                 pad_size_to: <_>::default(),
+                checksum: <_>::default(),
+                size: <_>::default(),
+                transform: <_>::default(),
                 keyword_spans: <_>::default(),
+                count_is_varint: false,
                 err_context: <_>::default(),
             },
             &field.attrs,
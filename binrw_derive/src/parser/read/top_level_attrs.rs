@@ -1,4 +1,6 @@
 use super::super::{
+    is_varint_count,
+    meta_types::IdentPatType,
     read::FromInput,
     types::{Assert, CondEndian, EnumErrorMode, Imports, Magic, Map},
     ParseResult, SpannedValue, TempableField, TrySet,
@@ -83,6 +85,14 @@ impl Input {
         }
     }
 
+    pub(crate) fn context(&self) -> Option<&IdentPatType> {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.context.as_ref(),
+            Input::Enum(e) => e.context.as_ref(),
+            Input::UnitOnlyEnum(e) => e.context.as_ref(),
+        }
+    }
+
     pub(crate) fn is_temp_field(&self, variant_index: usize, index: usize) -> bool {
         match self {
             Input::Struct(s) => s.fields.get(index).map_or(false, TempableField::is_temp),
@@ -100,6 +110,30 @@ impl Input {
         }
     }
 
+    pub(crate) fn is_async(&self) -> bool {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.is_async.is_some(),
+            Input::Enum(e) => e.is_async.is_some(),
+            Input::UnitOnlyEnum(e) => e.is_async.is_some(),
+        }
+    }
+
+    pub(crate) fn is_stream(&self) -> bool {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.is_stream.is_some(),
+            Input::Enum(e) => e.is_stream.is_some(),
+            Input::UnitOnlyEnum(e) => e.is_stream.is_some(),
+        }
+    }
+
+    pub(crate) fn is_borrowed(&self) -> bool {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.is_borrowed.is_some(),
+            Input::Enum(e) => e.is_borrowed.is_some(),
+            Input::UnitOnlyEnum(e) => e.is_borrowed.is_some(),
+        }
+    }
+
     pub(crate) fn map(&self) -> &Map {
         match self {
             Input::Struct(s) | Input::UnitStruct(s) => &s.map,
@@ -140,7 +174,7 @@ attr_struct! {
     #[derive(Clone, Debug, Default)]
     pub(crate) struct Struct {
         pub(crate) temp_legal: bool,
-        #[from(Big, Little, IsBig, IsLittle)]
+        #[from(Big, Little, IsBig, IsLittle, MagicEndian)]
         pub(crate) endian: CondEndian,
         #[from(Map, TryMap)]
         pub(crate) map: Map,
@@ -148,6 +182,18 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(Import, ImportRaw)]
         pub(crate) imports: Imports,
+        #[from(Context)]
+        pub(crate) context: Option<IdentPatType>,
+        #[from(Version)]
+        pub(crate) version: Option<TokenStream>,
+        #[from(Async)]
+        pub(crate) is_async: Option<()>,
+        #[from(Stream)]
+        pub(crate) is_stream: Option<SpannedValue<()>>,
+        #[from(Borrow)]
+        pub(crate) is_borrowed: Option<SpannedValue<()>>,
+        #[from(BitOrder)]
+        pub(crate) bit_order: Option<TokenStream>,
         #[from(Assert)]
         pub(crate) assertions: Vec<Assert>,
         #[from(PreAssert)]
@@ -186,10 +232,47 @@ impl Struct {
 impl FromInput<StructAttr> for Struct {
     type Field = StructField;
 
-    fn push_field(&mut self, field: Self::Field) -> syn::Result<()> {
+    fn push_field(&mut self, mut field: Self::Field) -> syn::Result<()> {
+        if self.version.is_none() && field.is_versioned() {
+            return Err(syn::Error::new(
+                field.field.span(),
+                "`since` and `until` need a `version` directive on the struct or variant",
+            ));
+        }
+
+        if let (Some(_), Some(directive)) = (&self.is_stream, field.seeking_directive()) {
+            return Err(syn::Error::new(
+                field.field.span(),
+                format!("`{directive}` cannot be used with `stream` because it needs to seek the reader"),
+            ));
+        }
+
+        field.count_is_varint = is_varint_count(
+            field.count.as_ref(),
+            self.fields.iter().map(|field| (&field.ident, &field.ty)),
+        );
         self.fields.push(field);
         Ok(())
     }
+
+    fn validate(&self) -> syn::Result<()> {
+        let idents = self
+            .fields
+            .iter()
+            .map(|field| &field.ident)
+            .collect::<Vec<_>>();
+        for (index, field) in self.fields.iter().enumerate() {
+            if let Some(checksum) = &field.checksum {
+                checksum.validate(index, &idents)?;
+            }
+        }
+
+        validate_borrow(
+            self.is_borrowed.as_ref(),
+            self.is_async.is_some(),
+            self.is_stream.is_some(),
+        )
+    }
 }
 
 attr_struct! {
@@ -199,7 +282,7 @@ attr_struct! {
     #[derive(Clone, Debug, Default)]
     pub(crate) struct Enum {
         pub(crate) ident: Option<syn::Ident>,
-        #[from(Big, Little, IsBig, IsLittle)]
+        #[from(Big, Little, IsBig, IsLittle, MagicEndian)]
         pub(crate) endian: CondEndian,
         #[from(Map, TryMap)]
         pub(crate) map: Map,
@@ -207,6 +290,14 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(Import, ImportRaw)]
         pub(crate) imports: Imports,
+        #[from(Context)]
+        pub(crate) context: Option<IdentPatType>,
+        #[from(Async)]
+        pub(crate) is_async: Option<()>,
+        #[from(Stream)]
+        pub(crate) is_stream: Option<SpannedValue<()>>,
+        #[from(Borrow)]
+        pub(crate) is_borrowed: Option<SpannedValue<()>>,
         // TODO: Does this make sense? It is not known what properties will
         // exist in order to construct a valid variant. The assertions all get
         // copied and used as if they were applied to each variant in the enum,
@@ -266,6 +357,19 @@ impl FromInput<EnumAttr> for Enum {
     fn set_ident(&mut self, ident: &syn::Ident) {
         self.ident = Some(ident.clone());
     }
+
+    fn validate(&self) -> syn::Result<()> {
+        validate_borrow(self.is_borrowed.as_ref(), self.is_async.is_some(), false)?;
+
+        if let Some(stream) = &self.is_stream {
+            Err(syn::Error::new(
+                stream.span(),
+                "`stream` cannot be used on enums with data because the reader is rewound to try each variant",
+            ))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 attr_struct! {
@@ -274,7 +378,7 @@ attr_struct! {
     #[from(UnitEnumAttr)]
     #[derive(Clone, Debug, Default)]
     pub(crate) struct UnitOnlyEnum {
-        #[from(Big, Little, IsBig, IsLittle)]
+        #[from(Big, Little, IsBig, IsLittle, MagicEndian)]
         pub(crate) endian: CondEndian,
         #[from(Map, TryMap)]
         pub(crate) map: Map,
@@ -282,6 +386,14 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(Import, ImportRaw)]
         pub(crate) imports: Imports,
+        #[from(Context)]
+        pub(crate) context: Option<IdentPatType>,
+        #[from(Async)]
+        pub(crate) is_async: Option<()>,
+        #[from(Stream)]
+        pub(crate) is_stream: Option<SpannedValue<()>>,
+        #[from(Borrow)]
+        pub(crate) is_borrowed: Option<SpannedValue<()>>,
         #[from(Repr)]
         pub(crate) repr: Option<SpannedValue<TokenStream>>,
         pub(crate) fields: Vec<UnitEnumField>,
@@ -334,6 +446,12 @@ impl FromInput<UnitEnumAttr> for UnitOnlyEnum {
     }
 
     fn validate(&self) -> syn::Result<()> {
+        validate_borrow(
+            self.is_borrowed.as_ref(),
+            self.is_async.is_some(),
+            self.is_stream.is_some(),
+        )?;
+
         if self.repr.is_some() || self.is_magic_enum() {
             Ok(())
         } else {
@@ -341,3 +459,27 @@ impl FromInput<UnitEnumAttr> for UnitOnlyEnum {
         }
     }
 }
+
+/// Borrowed types are read from a byte slice instead of a generic reader, so
+/// they cannot also be read by the other kinds of reader.
+fn validate_borrow(
+    is_borrowed: Option<&SpannedValue<()>>,
+    is_async: bool,
+    is_stream: bool,
+) -> syn::Result<()> {
+    let conflict = if is_async {
+        "async"
+    } else if is_stream {
+        "stream"
+    } else {
+        return Ok(());
+    };
+
+    match is_borrowed {
+        Some(borrow) => Err(syn::Error::new(
+            borrow.span(),
+            format!("`borrow` cannot be used with `{conflict}`"),
+        )),
+        None => Ok(()),
+    }
+}
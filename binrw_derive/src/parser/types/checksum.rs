@@ -0,0 +1,97 @@
+use crate::parser::{keywords, read::attrs};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Token,
+};
+
+/// A checksum which covers the fields from `from` to `to`, inclusive.
+#[derive(Clone, Debug)]
+pub(crate) struct Checksum {
+    /// The type of the `Checksum` algorithm.
+    pub(crate) algorithm: syn::Type,
+    /// The first field covered by the checksum.
+    pub(crate) from: syn::Ident,
+    /// The last field covered by the checksum.
+    pub(crate) to: syn::Ident,
+}
+
+impl Checksum {
+    /// Checks that the range of the checksum on the field at `index` refers to
+    /// fields in `fields`, in order, and does not include the checksum itself.
+    pub(crate) fn validate(&self, index: usize, fields: &[&syn::Ident]) -> syn::Result<()> {
+        let position = |ident: &syn::Ident| {
+            fields
+                .iter()
+                .position(|&field| field == ident)
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        ident.span(),
+                        format!("no field named `{ident}` to checksum"),
+                    )
+                })
+        };
+
+        let from = position(&self.from)?;
+        let to = position(&self.to)?;
+        if from > to {
+            Err(syn::Error::new(
+                self.to.span(),
+                "the `to` field of a checksum must not come before its `from` field",
+            ))
+        } else if (from..=to).contains(&index) {
+            Err(syn::Error::new(
+                self.from.span(),
+                "a checksum cannot cover the field which stores it",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Parse for Checksum {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let algorithm = input.parse::<syn::Type>()?;
+        let mut from = None;
+        let mut to = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let lookahead = input.lookahead1();
+            let (keyword, span) = if lookahead.peek(keywords::from) {
+                (&mut from, input.parse::<keywords::from>()?.span())
+            } else if lookahead.peek(keywords::to) {
+                (&mut to, input.parse::<keywords::to>()?.span())
+            } else {
+                return Err(lookahead.error());
+            };
+            input.parse::<Token![=]>()?;
+            if keyword.replace(input.parse::<syn::Ident>()?).is_some() {
+                return Err(syn::Error::new(span, "conflicting checksum range keyword"));
+            }
+        }
+
+        let from = from.ok_or_else(|| {
+            syn::Error::new(
+                algorithm.span(),
+                "expected the first field of the checksum, like `from = field`",
+            )
+        })?;
+        let to = to.unwrap_or_else(|| from.clone());
+        Ok(Self {
+            algorithm,
+            from,
+            to,
+        })
+    }
+}
+
+impl From<attrs::Checksum> for Checksum {
+    fn from(checksum: attrs::Checksum) -> Self {
+        checksum.value
+    }
+}
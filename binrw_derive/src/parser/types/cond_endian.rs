@@ -1,6 +1,10 @@
 use crate::parser::{read::attrs, KeywordToken, TrySet};
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Lit, Token,
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum Endian {
@@ -13,6 +17,7 @@ pub(crate) enum CondEndian {
     Inherited,
     Fixed(Endian),
     Cond(Endian, TokenStream),
+    Magic(MagicEndian),
 }
 
 impl CondEndian {
@@ -51,6 +56,71 @@ impl From<attrs::IsLittle> for CondEndian {
     }
 }
 
+impl From<attrs::MagicEndian> for CondEndian {
+    fn from(magic: attrs::MagicEndian) -> Self {
+        Self::Magic(magic.value)
+    }
+}
+
+/// A magic number which is stored in the byte order of the data following it.
+#[derive(Clone, Debug)]
+pub(crate) struct MagicEndian {
+    /// An expression for the `[u8; N]` magic of little-endian data.
+    pub(crate) little: TokenStream,
+    /// An expression for the `[u8; N]` magic of big-endian data.
+    pub(crate) big: TokenStream,
+}
+
+impl Parse for MagicEndian {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let first = input.parse::<Lit>()?;
+        if input.peek(Token![,]) && input.peek2(Lit) {
+            input.parse::<Token![,]>()?;
+            let second = input.parse::<Lit>()?;
+            return match (&first, &second) {
+                (Lit::ByteStr(little), Lit::ByteStr(big))
+                    if little.value().len() == big.value().len() =>
+                {
+                    Ok(Self {
+                        little: quote! { *#little },
+                        big: quote! { *#big },
+                    })
+                }
+                (Lit::ByteStr(_), Lit::ByteStr(_)) => Err(syn::Error::new(
+                    second.span(),
+                    "little- and big-endian magic must have the same length",
+                )),
+                _ => Err(syn::Error::new(
+                    first.span(),
+                    "expected a byte string for both little- and big-endian magic",
+                )),
+            };
+        }
+
+        match &first {
+            Lit::Int(int) if !int.suffix().is_empty() => {}
+            Lit::Float(float) if !float.suffix().is_empty() => {}
+            Lit::Int(_) | Lit::Float(_) => {
+                return Err(syn::Error::new(
+                    first.span(),
+                    "magic number needs a type suffix, like `0xa1b2c3d4u32`",
+                ))
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    first.span(),
+                    "expected a number, or a byte string for both little- and big-endian magic",
+                ))
+            }
+        }
+
+        Ok(Self {
+            little: quote! { #first.to_le_bytes() },
+            big: quote! { #first.to_be_bytes() },
+        })
+    }
+}
+
 impl<T: Into<CondEndian> + KeywordToken> TrySet<CondEndian> for T {
     fn try_set(self, to: &mut CondEndian) -> syn::Result<()> {
         if matches!(*to, CondEndian::Inherited) {
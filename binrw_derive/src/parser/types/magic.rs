@@ -15,7 +15,8 @@ pub(crate) enum Kind {
 impl From<&Kind> for TokenStream {
     fn from(kind: &Kind) -> Self {
         match kind {
-            Kind::Char => quote! { char },
+            // Char magics are stored as a single byte
+            Kind::Char => quote! { u8 },
             Kind::ByteStr(ty) | Kind::Numeric(ty) => {
                 let ty: TokenStream = ty.parse().unwrap();
                 quote! { #ty }
@@ -60,6 +61,10 @@ impl Inner {
         }
     }
 
+    pub(crate) fn is_char(&self) -> bool {
+        self.0 == Kind::Char
+    }
+
     pub(crate) fn kind(&self) -> &Kind {
         &self.0
     }
@@ -78,25 +83,31 @@ impl TryFrom<attrs::Magic> for SpannedValue<Inner> {
     type Error = syn::Error;
 
     fn try_from(magic: attrs::Magic) -> Result<Self, Self::Error> {
-        let value = &magic.value;
-
-        let kind = match &value {
+        let mut value = magic.value.to_token_stream();
+        let kind = match &magic.value {
             Lit::ByteStr(bytes) => Kind::ByteStr(format!("[u8; {}]", bytes.value().len())),
             Lit::Byte(_) => Kind::Numeric("u8".to_owned()),
-            Lit::Char(_) => Kind::Char,
+            Lit::Char(c) => {
+                let ch = c.value();
+                if u32::from(ch) > 0xff {
+                    return Err(syn::Error::new(
+                        c.span(),
+                        "char magic must be at most '\\u{ff}'",
+                    ));
+                }
+                value = syn::LitByte::new(ch as u8, c.span()).into_token_stream();
+                Kind::Char
+            }
             Lit::Int(i) => Kind::Numeric(i.suffix().to_owned()),
             Lit::Float(f) => Kind::Numeric(f.suffix().to_owned()),
             Lit::Str(_) | Lit::Bool(_) | Lit::Verbatim(_) => {
                 return Err(syn::Error::new(
-                    value.span(),
+                    magic.value.span(),
                     "expected byte string, byte, char, float, or int",
                 ))
             }
         };
 
-        Ok(Self::new(
-            Inner(kind, value.to_token_stream()),
-            magic.keyword_span(),
-        ))
+        Ok(Self::new(Inner(kind, value), magic.keyword_span()))
     }
 }
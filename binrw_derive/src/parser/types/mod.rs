@@ -1,4 +1,5 @@
 mod assert;
+mod checksum;
 mod cond_endian;
 mod condition;
 mod enum_error_mode;
@@ -9,10 +10,12 @@ mod map;
 mod passed_args;
 mod read_mode;
 mod spanned_value;
+mod transform;
 mod write_mode;
 
 pub(crate) use assert::{Assert, Error as AssertionError};
-pub(crate) use cond_endian::{CondEndian, Endian};
+pub(crate) use checksum::Checksum;
+pub(crate) use cond_endian::{CondEndian, Endian, MagicEndian};
 pub(crate) use condition::Condition;
 pub(crate) use enum_error_mode::EnumErrorMode;
 pub(crate) use err_context::ErrContext;
@@ -22,6 +25,7 @@ pub(crate) use map::Map;
 pub(crate) use passed_args::PassedArgs;
 pub(crate) use read_mode::ReadMode;
 pub(crate) use spanned_value::SpannedValue;
+pub(crate) use transform::Transform;
 pub(crate) use write_mode::WriteMode;
 
 fn assert_all_args_consumed<Iter, IterItem>(
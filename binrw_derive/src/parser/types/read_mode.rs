@@ -8,6 +8,7 @@ pub(crate) enum ReadMode {
     Default,
     Calc(TokenStream),
     ParseWith(TokenStream),
+    Bits(TokenStream),
 }
 
 impl Default for ReadMode {
@@ -40,6 +41,12 @@ impl From<attrs::ParseWith> for ReadMode {
     }
 }
 
+impl From<attrs::Bits> for ReadMode {
+    fn from(bits: attrs::Bits) -> Self {
+        Self::Bits(bits.into_token_stream())
+    }
+}
+
 impl<T: Into<ReadMode> + KeywordToken> TrySet<ReadMode> for T {
     fn try_set(self, to: &mut ReadMode) -> syn::Result<()> {
         if matches!(*to, ReadMode::Normal) {
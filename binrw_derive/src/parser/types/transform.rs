@@ -0,0 +1,39 @@
+use crate::parser::{keywords, read::attrs};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+/// A transform which is applied to the bytes of a field.
+#[derive(Clone, Debug)]
+pub(crate) struct Transform {
+    /// An expression for the `Transform` value.
+    pub(crate) transform: TokenStream,
+    /// The number of encoded bytes to read, or `None` to read to the end of
+    /// the stream.
+    pub(crate) size: Option<TokenStream>,
+}
+
+impl Parse for Transform {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let transform = input.parse::<syn::Expr>()?.into_token_stream();
+        let size = if input.peek(Token![,]) && input.peek2(keywords::size) {
+            input.parse::<Token![,]>()?;
+            input.parse::<keywords::size>()?;
+            input.parse::<Token![=]>()?;
+            Some(input.parse::<syn::Expr>()?.into_token_stream())
+        } else {
+            None
+        };
+
+        Ok(Self { transform, size })
+    }
+}
+
+impl From<attrs::Transform> for Transform {
+    fn from(transform: attrs::Transform) -> Self {
+        transform.value
+    }
+}
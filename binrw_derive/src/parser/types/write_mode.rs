@@ -8,6 +8,7 @@ pub(crate) enum WriteMode {
     Ignore,
     Calc(TokenStream),
     WriteWith(TokenStream),
+    Bits(TokenStream),
 }
 
 impl Default for WriteMode {
@@ -34,6 +35,12 @@ impl From<attrs::WriteWith> for WriteMode {
     }
 }
 
+impl From<attrs::Bits> for WriteMode {
+    fn from(bits: attrs::Bits) -> Self {
+        Self::Bits(bits.into_token_stream())
+    }
+}
+
 impl<T: Into<WriteMode> + KeywordToken> TrySet<WriteMode> for T {
     fn try_set(self, to: &mut WriteMode) -> syn::Result<()> {
         if matches!(*to, WriteMode::Normal) {
@@ -1,6 +1,9 @@
 #![allow(dead_code)]
 use super::super::{
-    types::{Assert, CondEndian, Magic, Map, PassedArgs, WriteMode},
+    types::{
+        Assert, Checksum, CondEndian, Condition, ErrContext, Magic, Map, PassedArgs, Transform,
+        WriteMode,
+    },
     write::{FromAttrs, FromInput},
     FromField, ParseResult, TrySet,
 };
@@ -9,6 +12,7 @@ use super::Struct;
 
 use crate::parser::TempableField;
 use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
 
 attr_struct! {
     @write struct_field
@@ -28,14 +32,24 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(Args, ArgsRaw)]
         pub(crate) args: PassedArgs,
-        #[from(Calc, Ignore, WriteWith)]
+        #[from(Calc, Ignore, WriteWith, Bits)]
         pub(crate) write_mode: WriteMode,
         #[from(Count)]
         pub(crate) count: Option<TokenStream>,
+        #[from(Offset)]
+        pub(crate) offset: Option<TokenStream>,
+        #[from(If)]
+        pub(crate) if_cond: Option<Condition>,
+        #[from(Since)]
+        pub(crate) since: Option<TokenStream>,
+        #[from(Until)]
+        pub(crate) until: Option<TokenStream>,
         #[from(RestorePosition)]
         pub(crate) restore_position: Option<()>,
         #[from(Assert)]
         pub(crate) assertions: Vec<Assert>,
+        #[from(ErrContext)]
+        pub(crate) err_context: Option<ErrContext>,
         #[from(PadBefore)]
         pub(crate) pad_before: Option<TokenStream>,
         #[from(PadAfter)]
@@ -48,8 +62,20 @@ attr_struct! {
         pub(crate) seek_before: Option<TokenStream>,
         #[from(PadSizeTo)]
         pub(crate) pad_size_to: Option<TokenStream>,
+        #[from(Backpatch)]
+        pub(crate) backpatch: Option<TokenStream>,
+        #[from(ByteRange)]
+        pub(crate) byte_range: Option<TokenStream>,
+        #[from(Checksum)]
+        pub(crate) checksum: Option<Checksum>,
+        #[from(Size)]
+        pub(crate) size: Option<TokenStream>,
+        #[from(Transform)]
+        pub(crate) transform: Option<Transform>,
         // Marker for if binread has marked this field temporary
         pub(crate) binread_temp: bool,
+        // Whether `count` names a field with a variable-length integer type
+        pub(crate) count_is_varint: bool,
     }
 }
 
@@ -66,9 +92,119 @@ impl StructField {
         matches!(self.write_mode, WriteMode::Calc(_))
     }
 
+    /// Returns true if this field is written to a bit run instead of whole
+    /// bytes.
+    pub(crate) fn is_bits(&self) -> bool {
+        matches!(self.write_mode, WriteMode::Bits(_))
+    }
+
+    /// Returns true if this field only exists in some versions of the format.
+    pub(crate) fn is_versioned(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+
+    /// Returns true if whether this field is written depends on a condition.
+    pub(crate) fn is_conditional(&self) -> bool {
+        self.if_cond.is_some() || self.is_versioned()
+    }
+
     /// Returns true if the field needs `ReadOptions` to be parsed.
     pub(crate) fn needs_options(&self) -> bool {
-        !self.generated_value() || self.magic.is_some()
+        !(self.generated_value() || self.is_bits()) || self.magic.is_some()
+    }
+
+    /// Returns the name of the first directive on this field which only works
+    /// on whole bytes.
+    pub(crate) fn byte_directive(&self) -> Option<&'static str> {
+        if self.magic.is_some() {
+            Some("magic")
+        } else if self.args.is_some() {
+            Some("args")
+        } else if self.count.is_some() {
+            Some("count")
+        } else if self.offset.is_some() {
+            Some("offset")
+        } else if self.restore_position.is_some() {
+            Some("restore_position")
+        } else if self.seek_before.is_some() {
+            Some("seek_before")
+        } else if self.pad_before.is_some() {
+            Some("pad_before")
+        } else if self.pad_after.is_some() {
+            Some("pad_after")
+        } else if self.align_before.is_some() {
+            Some("align_before")
+        } else if self.align_after.is_some() {
+            Some("align_after")
+        } else if self.pad_size_to.is_some() {
+            Some("pad_size_to")
+        } else if self.backpatch.is_some() {
+            Some("backpatch")
+        } else if self.byte_range.is_some() {
+            Some("byte_range")
+        } else if self.checksum.is_some() {
+            Some("checksum")
+        } else if self.size.is_some() {
+            Some("size")
+        } else if self.transform.is_some() {
+            Some("transform")
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the range of bytes written for this field needs to be
+    /// recorded.
+    pub(crate) fn needs_byte_range(&self) -> bool {
+        self.is_backpatched() || self.byte_range.is_some()
+    }
+
+    /// Returns true if this field is overwritten once the rest of its struct
+    /// has been written.
+    pub(crate) fn is_backpatched(&self) -> bool {
+        self.backpatch.is_some() || self.checksum.is_some()
+    }
+
+    fn validate(&self) -> syn::Result<()> {
+        if let (true, Some(directive)) = (self.is_bits(), self.byte_directive()) {
+            Err(syn::Error::new(
+                self.field.span(),
+                format!("`{directive}` cannot be used with `bits` because it works on whole bytes"),
+            ))
+        } else if self.backpatch.is_some()
+            && (self.map.is_some()
+                || !matches!(self.write_mode, WriteMode::Normal | WriteMode::Calc(_)))
+        {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`backpatch` cannot be used with `map`, `try_map`, `write_with`, or `ignore`",
+            ))
+        } else if self.checksum.is_some()
+            && (self.map.is_some()
+                || !matches!(self.write_mode, WriteMode::Normal | WriteMode::Calc(_)))
+        {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`checksum` cannot be used with `map`, `try_map`, `write_with`, or `ignore`",
+            ))
+        } else if let (Some(_), Some(_)) = (&self.backpatch, &self.checksum) {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`backpatch` and `checksum` are mutually exclusive",
+            ))
+        } else if self.transform.is_some() && self.is_backpatched() {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`transform` cannot be used with `backpatch` or `checksum` because encoded data cannot be patched",
+            ))
+        } else if let (Some(_), Some(_)) = (&self.size, &self.transform) {
+            Err(syn::Error::new(
+                self.field.span(),
+                "`size` cannot be used with `transform`; use `transform(..., size = ...)` instead",
+            ))
+        } else {
+            Ok(())
+        }
     }
 
     /// Returns true if the field is actually written.
@@ -104,7 +240,7 @@ impl FromField for StructField {
     type In = syn::Field;
 
     fn from_field(field: &Self::In, index: usize) -> ParseResult<Self> {
-        Self::set_from_attrs(
+        let result = Self::set_from_attrs(
             Self {
                 ident: field
                     .ident
@@ -118,20 +254,48 @@ impl FromField for StructField {
                 magic: <_>::default(),
                 args: <_>::default(),
                 count: <_>::default(),
+                offset: <_>::default(),
+                if_cond: <_>::default(),
+                since: <_>::default(),
+                until: <_>::default(),
                 restore_position: <_>::default(),
                 write_mode: <_>::default(),
                 assertions: <_>::default(),
+                err_context: <_>::default(),
                 pad_before: <_>::default(),
                 pad_after: <_>::default(),
                 align_before: <_>::default(),
                 align_after: <_>::default(),
                 seek_before: <_>::default(),
                 pad_size_to: <_>::default(),
+                backpatch: <_>::default(),
+                byte_range: <_>::default(),
+                checksum: <_>::default(),
+                size: <_>::default(),
+                transform: <_>::default(),
                 keyword_spans: <_>::default(),
+                count_is_varint: false,
                 binread_temp: false,
             },
             &field.attrs,
-        )
+        );
+
+        match result {
+            ParseResult::Ok(this) => {
+                if let Err(error) = this.validate() {
+                    ParseResult::Partial(this, error)
+                } else {
+                    ParseResult::Ok(this)
+                }
+            }
+            ParseResult::Partial(this, mut parse_error) => {
+                if let Err(error) = this.validate() {
+                    parse_error.combine(error);
+                }
+                ParseResult::Partial(this, parse_error)
+            }
+            ParseResult::Err(error) => ParseResult::Err(error),
+        }
     }
 }
 
@@ -6,6 +6,8 @@ use syn::spanned::Spanned;
 use crate::parser::TempableField;
 
 use super::super::{
+    is_varint_count,
+    meta_types::IdentPatType,
     types::{Assert, CondEndian, EnumErrorMode, Imports, Magic, Map},
     write::FromInput,
     ParseResult, SpannedValue, TrySet,
@@ -87,6 +89,14 @@ impl Input {
         }
     }
 
+    pub(crate) fn context(&self) -> Option<&IdentPatType> {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.context.as_ref(),
+            Input::Enum(e) => e.context.as_ref(),
+            Input::UnitOnlyEnum(e) => e.context.as_ref(),
+        }
+    }
+
     pub(crate) fn is_temp_field(&self, variant_index: usize, index: usize) -> bool {
         match self {
             Input::Struct(s) => s.fields.get(index).map_or(false, TempableField::is_temp),
@@ -104,6 +114,22 @@ impl Input {
         }
     }
 
+    pub(crate) fn is_async(&self) -> bool {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.is_async.is_some(),
+            Input::Enum(e) => e.is_async.is_some(),
+            Input::UnitOnlyEnum(e) => e.is_async.is_some(),
+        }
+    }
+
+    pub(crate) fn has_data_section(&self) -> bool {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.data_section.is_some(),
+            Input::Enum(e) => e.data_section.is_some(),
+            Input::UnitOnlyEnum(_) => false,
+        }
+    }
+
     pub(crate) fn map(&self) -> &Map {
         match self {
             Input::Struct(s) | Input::UnitStruct(s) => &s.map,
@@ -136,7 +162,7 @@ attr_struct! {
     #[derive(Clone, Debug, Default)]
     pub(crate) struct Struct {
         pub(crate) temp_legal: bool,
-        #[from(Big, Little, IsBig, IsLittle)]
+        #[from(Big, Little, IsBig, IsLittle, MagicEndian)]
         pub(crate) endian: CondEndian,
         #[from(Map, TryMap)]
         pub(crate) map: Map,
@@ -144,6 +170,16 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(Import, ImportRaw)]
         pub(crate) imports: Imports,
+        #[from(Context)]
+        pub(crate) context: Option<IdentPatType>,
+        #[from(Version)]
+        pub(crate) version: Option<TokenStream>,
+        #[from(Async)]
+        pub(crate) is_async: Option<()>,
+        #[from(DataSection)]
+        pub(crate) data_section: Option<()>,
+        #[from(BitOrder)]
+        pub(crate) bit_order: Option<TokenStream>,
         #[from(Assert)]
         pub(crate) assertions: Vec<Assert>,
         #[from(PreAssert)]
@@ -188,10 +224,36 @@ impl Struct {
 impl FromInput<StructAttr> for Struct {
     type Field = StructField;
 
-    fn push_field(&mut self, field: Self::Field) -> syn::Result<()> {
+    fn push_field(&mut self, mut field: Self::Field) -> syn::Result<()> {
+        if self.version.is_none() && field.is_versioned() {
+            return Err(syn::Error::new(
+                field.field.span(),
+                "`since` and `until` need a `version` directive on the struct or variant",
+            ));
+        }
+
+        field.count_is_varint = is_varint_count(
+            field.count.as_ref(),
+            self.fields.iter().map(|field| (&field.ident, &field.ty)),
+        );
         self.fields.push(field);
         Ok(())
     }
+
+    fn validate(&self) -> syn::Result<()> {
+        let idents = self
+            .fields
+            .iter()
+            .map(|field| &field.ident)
+            .collect::<Vec<_>>();
+        for (index, field) in self.fields.iter().enumerate() {
+            if let Some(checksum) = &field.checksum {
+                checksum.validate(index, &idents)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 attr_struct! {
@@ -200,7 +262,7 @@ attr_struct! {
     #[from(EnumAttr)]
     #[derive(Clone, Debug, Default)]
     pub(crate) struct Enum {
-        #[from(Big, Little, IsBig, IsLittle)]
+        #[from(Big, Little, IsBig, IsLittle, MagicEndian)]
         pub(crate) endian: CondEndian,
         #[from(Map, TryMap)]
         pub(crate) map: Map,
@@ -208,6 +270,12 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(Import, ImportRaw)]
         pub(crate) imports: Imports,
+        #[from(Context)]
+        pub(crate) context: Option<IdentPatType>,
+        #[from(Async)]
+        pub(crate) is_async: Option<()>,
+        #[from(DataSection)]
+        pub(crate) data_section: Option<()>,
         // TODO: Does this make sense? It is not known what properties will
         // exist in order to construct a valid variant. The assertions all get
         // copied and used as if they were applied to each variant in the enum,
@@ -260,6 +328,15 @@ impl FromInput<EnumAttr> for Enum {
     type Field = EnumVariant;
 
     fn push_field(&mut self, field: Self::Field) -> syn::Result<()> {
+        if let EnumVariant::Variant { ident, options } = &field {
+            if options.data_section.is_some() {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "`data_section` can only be used on the enum itself",
+                ));
+            }
+        }
+
         self.variants.push(field);
         Ok(())
     }
@@ -271,7 +348,7 @@ attr_struct! {
     #[from(UnitEnumAttr)]
     #[derive(Clone, Debug, Default)]
     pub(crate) struct UnitOnlyEnum {
-        #[from(Big, Little, IsBig, IsLittle)]
+        #[from(Big, Little, IsBig, IsLittle, MagicEndian)]
         pub(crate) endian: CondEndian,
         #[from(Map, TryMap)]
         pub(crate) map: Map,
@@ -279,6 +356,10 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(Import, ImportRaw)]
         pub(crate) imports: Imports,
+        #[from(Context)]
+        pub(crate) context: Option<IdentPatType>,
+        #[from(Async)]
+        pub(crate) is_async: Option<()>,
         #[from(Repr)]
         pub(crate) repr: Option<SpannedValue<TokenStream>>,
         pub(crate) fields: Vec<UnitEnumField>,